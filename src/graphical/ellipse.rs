@@ -1,5 +1,7 @@
 use std::f64::consts::PI;
 
+use crate::matrix::matrix_2d::Matrix2D;
+
 /// 表示椭圆的结构体。
 ///
 /// # 字段
@@ -193,6 +195,8 @@ impl Ellipse {
     /// # 返回值
     ///
     /// 如果拟合成功，返回一个包含椭圆参数的 `Option<Ellipse>`；否则返回 None。
+    /// 当点数多于 5 个时，按最小二乘意义求解二次曲线系数，再换算为中心、半轴长与长轴的旋转角；
+    /// 点集退化（设计矩阵列不满秩）或拟合出的二次曲线不是椭圆时返回 None。
     ///
     /// # 注意
     ///
@@ -231,36 +235,61 @@ impl Ellipse {
             .collect();
 
         // 构建设计矩阵
-        let mut d_matrix: Vec<Vec<f64>> = Vec::with_capacity(n);
-        for i in 0..n {
-            let (x, y) = normalized_points[i];
-            d_matrix.push(vec![x * x, x * y, y * y, x, y]);
-        }
+        let d_matrix = Matrix2D::new(
+            normalized_points
+                .iter()
+                .map(|&(x, y)| vec![x * x, x * y, y * y, x, y])
+                .collect(),
+        )
+        .ok()?;
 
-        // 构建响应矩阵
-        let r_matrix: Vec<Vec<f64>> = vec![vec![1.0; 1]; n];
+        // 构建响应向量
+        let r_vector = vec![1.0; n];
 
-        // 解线性方程组
-        let result = gauss_elimination(&d_matrix, &r_matrix);
+        // 使用 QR 分解求解最小二乘问题
+        let result = d_matrix.least_squares(&r_vector);
 
         match result {
-            Some(parameters) => {
-                // 重建椭圆方程
+            Ok(parameters) => {
+                // 二次曲线 a·x² + b·xy + c·y² + d·x + e·y = 1（坐标已减去均值）
                 let a = parameters[0];
                 let b = parameters[1];
                 let c = parameters[2];
                 let d = parameters[3];
                 let e = parameters[4];
 
-                // 反归一化
-                let h = mean_x - (2.0 * c * d + b * e) / (4.0 * a * c - b.powi(2));
-                let k = mean_y - (b * d + 2.0 * a * e) / (4.0 * a * c - b.powi(2));
+                // 判别式不为正时不是椭圆
+                let det = 4.0 * a * c - b.powi(2);
+                if det <= 0.0 {
+                    return None;
+                }
+
+                // 中心为梯度为零的点，平移到中心后方程化为 a·u² + b·uv + c·v² = s
+                let x0 = (b * e - 2.0 * c * d) / det;
+                let y0 = (b * d - 2.0 * a * e) / det;
+                let s = 1.0 - (d * x0 + e * y0) / 2.0;
+                let (a, b, c, s) = if s < 0.0 { (-a, -b, -c, -s) } else { (a, b, c, s) };
+
+                // 二次型矩阵 [[a, b/2], [b/2, c]] 的特征值，较小者对应长轴
+                let mean = (a + c) / 2.0;
+                let radius = ((a - c) / 2.0).hypot(b / 2.0);
+                let (lambda_min, lambda_max) = (mean - radius, mean + radius);
+                if s == 0.0 || lambda_min <= 0.0 {
+                    return None;
+                }
 
-                let phi = 0.5 * (c - a).atan2(b);
+                // 较小特征值的特征向量方向即长轴方向
+                let phi = 0.5 * (-b).atan2(c - a);
 
-                Some(Ellipse::new(1.0 / a, 1.0 / c, h, k, phi))
+                Some(Ellipse::new(
+                    (s / lambda_min).sqrt(),
+                    (s / lambda_max).sqrt(),
+                    mean_x + x0,
+                    mean_y + y0,
+                    phi,
+                ))
             }
-            None => None,
+            Err(_) => None,
         }
     }
 }
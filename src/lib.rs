@@ -2,4 +2,5 @@ pub mod graphical;
 
 pub mod vector;
 pub mod matrix;
+pub mod number;
 pub mod statistics;
\ No newline at end of file
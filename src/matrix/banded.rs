@@ -0,0 +1,403 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 以带状形式存储的 n×n 矩阵，只保存主对角线附近 `lower` 条下对角线和 `upper` 条上对角线。
+///
+/// 每行存放 `width = lower + upper + 1` 个元素，第 `i` 行第 `j` 列（`-lower ≤ j - i ≤ upper`）的元素位于
+/// `data[i * width + (j + lower - i)]`。带外元素恒为零，存储量为 O(n · 带宽)。
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedMatrix {
+    n: usize,
+    lower: usize,
+    upper: usize,
+    data: Vec<f64>,
+}
+
+/// 带状矩阵的带部分主元 LU 分解结果。
+///
+/// 行交换会使 `U` 的上带宽增加到 `lower + upper`，因此因子按该带宽存储；
+/// `L` 的乘子按 LAPACK `gbtrf` 的约定保存，求解时与行交换交替作用于右端项。
+#[derive(Debug, Clone, PartialEq)]
+pub struct BandedLU {
+    /// 以带宽 `lower` / `lower + upper` 存储的 `L` 乘子与 `U` 因子。
+    factors: BandedMatrix,
+    /// 第 k 步消元时与第 k 行交换的行下标。
+    pivots: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl BandedMatrix {
+    /// 创建全零的带状矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `n` - 矩阵阶数。
+    /// * `lower` - 下带宽，即主对角线以下非零对角线的条数。
+    /// * `upper` - 上带宽，即主对角线以上非零对角线的条数。
+    pub fn zeros(n: usize, lower: usize, upper: usize) -> BandedMatrix {
+        BandedMatrix { n, lower, upper, data: vec![0.0; n * (lower + upper + 1)] }
+    }
+
+    /// 由三条对角线创建三对角矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `sub` - 下对角线，长度为 `n - 1`。
+    /// * `diag` - 主对角线，长度为 `n`。
+    /// * `sup` - 上对角线，长度为 `n - 1`。
+    ///
+    /// # 返回
+    ///
+    /// 返回三对角矩阵；如果对角线长度不匹配，返回 `MatrixError::DataLength`。
+    pub fn tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64]) -> Result<BandedMatrix, MatrixError> {
+        let n = diag.len();
+        check_tridiagonal_lengths(sub, sup, n.saturating_sub(1))?;
+        let mut band = BandedMatrix::zeros(n, 1, 1);
+        for i in 0..n {
+            band.set(i, i, diag[i])?;
+            if i > 0 {
+                band.set(i, i - 1, sub[i - 1])?;
+                band.set(i - 1, i, sup[i - 1])?;
+            }
+        }
+        Ok(band)
+    }
+
+    /// 从稠密方阵中截取带内元素，带外元素被忽略。
+    ///
+    /// # 返回
+    ///
+    /// 返回带状矩阵；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    pub fn from_matrix(matrix: &Matrix2D, lower: usize, upper: usize) -> Result<BandedMatrix, MatrixError> {
+        let (rows, cols) = matrix.shape();
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols });
+        }
+        let mut band = BandedMatrix::zeros(rows, lower, upper);
+        for i in 0..rows {
+            for j in i.saturating_sub(lower)..(i + upper + 1).min(rows) {
+                band.set(i, j, matrix[(i, j)])?;
+            }
+        }
+        Ok(band)
+    }
+
+    /// 返回矩阵阶数。
+    pub fn order(&self) -> usize {
+        self.n
+    }
+
+    /// 返回 `(下带宽, 上带宽)`。
+    pub fn bandwidth(&self) -> (usize, usize) {
+        (self.lower, self.upper)
+    }
+
+    fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.n && j < self.n && j + self.lower >= i && j <= i + self.upper
+    }
+
+    fn offset(&self, i: usize, j: usize) -> usize {
+        i * (self.lower + self.upper + 1) + (j + self.lower - i)
+    }
+
+    /// 获取第 `i` 行第 `j` 列的元素，带外元素为零。
+    ///
+    /// # 注意
+    ///
+    /// 如果下标超出矩阵阶数，将会引发 panic。
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.n && j < self.n, "Matrix index out of bounds.");
+        if self.in_band(i, j) {
+            self.data[self.offset(i, j)]
+        } else {
+            0.0
+        }
+    }
+
+    /// 设置第 `i` 行第 `j` 列的元素。
+    ///
+    /// # 返回
+    ///
+    /// 如果位置超出矩阵范围或不在带内，返回 `MatrixError::IndexOutOfBounds`。
+    pub fn set(&mut self, i: usize, j: usize, value: f64) -> Result<(), MatrixError> {
+        if !self.in_band(i, j) {
+            return Err(MatrixError::IndexOutOfBounds { index: (i, j), shape: (self.n, self.n) });
+        }
+        let offset = self.offset(i, j);
+        self.data[offset] = value;
+        Ok(())
+    }
+
+    /// 转换为稠密矩阵。
+    pub fn to_dense(&self) -> Matrix2D {
+        Matrix2D::from_fn(self.n, self.n, |i, j| self.get(i, j))
+    }
+
+    /// 计算带状矩阵与向量的乘积，复杂度为 O(n · 带宽)。
+    ///
+    /// # 返回
+    ///
+    /// 返回结果向量；如果向量长度与阶数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn mul_vector(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.n {
+            return Err(MatrixError::DimensionMismatch { left: (self.n, self.n), right: (x.len(), 1) });
+        }
+        Ok((0..self.n)
+            .map(|i| {
+                (i.saturating_sub(self.lower)..(i + self.upper + 1).min(self.n))
+                    .map(|j| self.data[self.offset(i, j)] * x[j])
+                    .sum()
+            })
+            .collect())
+    }
+
+    /// 对带状矩阵做带部分主元的 LU 分解，复杂度为 O(n · lower · (lower + upper))。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `BandedLU` 分解结果；如果某列找不到非零主元，返回 `MatrixError::Singular`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::banded::BandedMatrix;
+    ///
+    /// let a = BandedMatrix::tridiagonal(&[1.0, 1.0], &[2.0, 2.0, 2.0], &[1.0, 1.0]).unwrap();
+    /// let x = a.lu().unwrap().solve(&[3.0, 4.0, 3.0]).unwrap();
+    /// assert!(x.iter().all(|v| (v - 1.0).abs() < 1e-12));
+    /// ```
+    pub fn lu(&self) -> Result<BandedLU, MatrixError> {
+        let (n, kl) = (self.n, self.lower);
+        let ku = self.lower + self.upper;
+        let mut factors = BandedMatrix::zeros(n, kl, ku);
+        for i in 0..n {
+            for j in i.saturating_sub(kl)..(i + self.upper + 1).min(n) {
+                let offset = factors.offset(i, j);
+                factors.data[offset] = self.data[self.offset(i, j)];
+            }
+        }
+
+        let mut pivots = Vec::with_capacity(n);
+        for k in 0..n {
+            let last_row = (k + kl).min(n - 1);
+            let last_col = (k + ku).min(n - 1);
+
+            // 只需在带内查找主元
+            let p = (k + 1..=last_row).fold(k, |best, i| {
+                if factors.get(i, k).abs() > factors.get(best, k).abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+            let pivot = factors.get(p, k);
+            if pivot == 0.0 {
+                return Err(MatrixError::Singular { pivot: k });
+            }
+            if p != k {
+                for j in k..=last_col {
+                    let (a, b) = (factors.offset(k, j), factors.offset(p, j));
+                    factors.data.swap(a, b);
+                }
+            }
+            pivots.push(p);
+
+            for i in k + 1..=last_row {
+                let l_offset = factors.offset(i, k);
+                let l = factors.data[l_offset] / pivot;
+                factors.data[l_offset] = l;
+                if l == 0.0 {
+                    continue;
+                }
+                for j in k + 1..=last_col {
+                    let u_kj = factors.data[factors.offset(k, j)];
+                    let offset = factors.offset(i, j);
+                    factors.data[offset] -= l * u_kj;
+                }
+            }
+        }
+        Ok(BandedLU { factors, pivots })
+    }
+
+    /// 求解线性方程组 `A * x = b`，等价于 `self.lu()?.solve(b)`。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.lu()?.solve(b)
+    }
+}
+
+#[allow(dead_code)]
+impl BandedLU {
+    /// 利用分解结果求解 `A * x = b`，复杂度为 O(n · 带宽)。
+    ///
+    /// # 返回
+    ///
+    /// 返回解向量；如果 `b` 的长度与阶数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let f = &self.factors;
+        let (n, kl, ku) = (f.n, f.lower, f.upper);
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { left: (n, n), right: (b.len(), 1) });
+        }
+
+        // 交替应用行交换与 L 的消元
+        let mut x = b.to_vec();
+        for (k, &p) in self.pivots.iter().enumerate() {
+            x.swap(k, p);
+            for i in k + 1..=(k + kl).min(n - 1) {
+                x[i] -= f.data[f.offset(i, k)] * x[k];
+            }
+        }
+
+        // 回代求解 U * x = y
+        for i in (0..n).rev() {
+            for j in i + 1..=(i + ku).min(n - 1) {
+                x[i] -= f.data[f.offset(i, j)] * x[j];
+            }
+            x[i] /= f.data[f.offset(i, i)];
+        }
+        Ok(x)
+    }
+
+    /// 计算原矩阵的行列式。
+    pub fn determinant(&self) -> f64 {
+        let swaps = self.pivots.iter().enumerate().filter(|&(k, &p)| k != p).count();
+        let sign = if swaps % 2 == 0 { 1.0 } else { -1.0 };
+        (0..self.factors.n).fold(sign, |acc, i| acc * self.factors.get(i, i))
+    }
+}
+
+/// 使用 Thomas 算法求解三对角线性方程组，复杂度为 O(n)。
+///
+/// 第 `i` 个方程为 `sub[i - 1] * x[i - 1] + diag[i] * x[i] + sup[i] * x[i + 1] = rhs[i]`。
+/// 算法不做主元选取，适用于对角占优或对称正定的三对角矩阵（例如三次样条和一维扩散问题）。
+///
+/// # 参数
+///
+/// * `sub` - 下对角线，长度为 `n - 1`。
+/// * `diag` - 主对角线，长度为 `n`。
+/// * `sup` - 上对角线，长度为 `n - 1`。
+/// * `rhs` - 右端项，长度为 `n`。
+///
+/// # 返回
+///
+/// 返回解向量；对角线或右端项长度不匹配时返回 `MatrixError::DataLength`，
+/// 消元中出现零主元时返回 `MatrixError::Singular`。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::banded::solve_tridiagonal;
+///
+/// let x = solve_tridiagonal(&[-1.0, -1.0], &[2.0, 2.0, 2.0], &[-1.0, -1.0], &[1.0, 0.0, 1.0]).unwrap();
+/// assert!(x.iter().all(|v| (v - 1.0).abs() < 1e-12));
+/// ```
+pub fn solve_tridiagonal(sub: &[f64], diag: &[f64], sup: &[f64], rhs: &[f64]) -> Result<Vec<f64>, MatrixError> {
+    let n = diag.len();
+    check_tridiagonal_lengths(sub, sup, n.saturating_sub(1))?;
+    if rhs.len() != n {
+        return Err(MatrixError::DataLength { expected: n, found: rhs.len() });
+    }
+    if n == 0 {
+        return Ok(Vec::new());
+    }
+
+    // 前向消元：c' 为归一化后的上对角线，x 暂存 d'
+    let mut c = vec![0.0; n];
+    let mut x = vec![0.0; n];
+    let mut denominator = diag[0];
+    for i in 0..n {
+        if i > 0 {
+            denominator = diag[i] - sub[i - 1] * c[i - 1];
+        }
+        if denominator == 0.0 {
+            return Err(MatrixError::Singular { pivot: i });
+        }
+        if i + 1 < n {
+            c[i] = sup[i] / denominator;
+        }
+        let previous = if i > 0 { sub[i - 1] * x[i - 1] } else { 0.0 };
+        x[i] = (rhs[i] - previous) / denominator;
+    }
+
+    // 回代
+    for i in (0..n - 1).rev() {
+        x[i] -= c[i] * x[i + 1];
+    }
+    Ok(x)
+}
+
+/// 求解周期（循环）三对角线性方程组，复杂度为 O(n)。
+///
+/// 第 `i` 个方程为 `sub[i] * x[i - 1] + diag[i] * x[i] + sup[i] * x[i + 1] = rhs[i]`，下标按 `n` 取模，
+/// 即 `sub[0]` 为第 0 行与 `x[n - 1]` 的耦合系数，`sup[n - 1]` 为最后一行与 `x[0]` 的耦合系数。
+/// 使用 Sherman–Morrison 公式把问题化为两次 Thomas 求解，常见于周期边界条件下的样条与扩散问题。
+///
+/// # 参数
+///
+/// * `sub` - 下对角线，长度为 `n`。
+/// * `diag` - 主对角线，长度为 `n`。
+/// * `sup` - 上对角线，长度为 `n`。
+/// * `rhs` - 右端项，长度为 `n`。
+///
+/// # 返回
+///
+/// 返回解向量；长度不匹配时返回 `MatrixError::DataLength`，矩阵奇异时返回 `MatrixError::Singular`。
+pub fn solve_cyclic_tridiagonal(
+    sub: &[f64],
+    diag: &[f64],
+    sup: &[f64],
+    rhs: &[f64],
+) -> Result<Vec<f64>, MatrixError> {
+    let n = diag.len();
+    check_tridiagonal_lengths(sub, sup, n)?;
+    if rhs.len() != n {
+        return Err(MatrixError::DataLength { expected: n, found: rhs.len() });
+    }
+    if n < 3 {
+        // 阶数过小时环绕项与普通对角线重合，直接组装后求解
+        let dense = Matrix2D::from_fn(n, n, |i, j| {
+            let mut value = if i == j { diag[i] } else { 0.0 };
+            if j == (i + n - 1) % n {
+                value += sub[i];
+            }
+            if j == (i + 1) % n {
+                value += sup[i];
+            }
+            value
+        });
+        return BandedMatrix::from_matrix(&dense, n.saturating_sub(1), n.saturating_sub(1))?.solve(rhs);
+    }
+
+    let alpha = sup[n - 1];
+    let beta = sub[0];
+    let gamma = if diag[0] == 0.0 { 1.0 } else { -diag[0] };
+
+    // A = T + u * vᵀ，其中 u = [γ, 0, …, 0, α]，v = [1, 0, …, 0, β / γ]
+    let mut modified = diag.to_vec();
+    modified[0] -= gamma;
+    modified[n - 1] -= alpha * beta / gamma;
+    let (inner_sub, inner_sup) = (&sub[1..], &sup[..n - 1]);
+
+    let y = solve_tridiagonal(inner_sub, &modified, inner_sup, rhs)?;
+    let mut u = vec![0.0; n];
+    u[0] = gamma;
+    u[n - 1] = alpha;
+    let z = solve_tridiagonal(inner_sub, &modified, inner_sup, &u)?;
+
+    let denominator = 1.0 + z[0] + beta * z[n - 1] / gamma;
+    if denominator == 0.0 {
+        return Err(MatrixError::Singular { pivot: n - 1 });
+    }
+    let factor = (y[0] + beta * y[n - 1] / gamma) / denominator;
+    Ok(y.iter().zip(&z).map(|(y, z)| y - factor * z).collect())
+}
+
+/// 检查上下对角线的长度是否均为 `off_diagonal`。
+fn check_tridiagonal_lengths(sub: &[f64], sup: &[f64], off_diagonal: usize) -> Result<(), MatrixError> {
+    for band in [sub, sup] {
+        if band.len() != off_diagonal {
+            return Err(MatrixError::DataLength { expected: off_diagonal, found: band.len() });
+        }
+    }
+    Ok(())
+}
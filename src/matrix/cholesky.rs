@@ -0,0 +1,256 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 对称正定矩阵的 Cholesky 分解结果，满足 `A = L * Lᵀ`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cholesky {
+    /// 对角元为正的下三角因子。
+    l: Matrix2D,
+}
+
+/// 对称矩阵的 LDLᵀ 分解结果，满足 `A = L * D * Lᵀ`。
+///
+/// `L` 为单位下三角矩阵，`D` 为对角矩阵。与 Cholesky 分解不同，
+/// `D` 的对角元可以为负，因此适用于对称不定矩阵（分解过程不做主元交换）。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LDLT {
+    /// 单位下三角因子。
+    l: Matrix2D,
+    /// 对角因子的对角元。
+    d: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 对对称正定矩阵进行 Cholesky 分解。
+    ///
+    /// 只读取矩阵的下三角部分。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Cholesky` 分解结果；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；
+    /// 如果遇到非正主元，返回 `MatrixError::NotPositiveDefinite` 并指明该主元的下标。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![4.0, 2.0], vec![2.0, 3.0]]).unwrap();
+    /// let cholesky = a.cholesky().unwrap();
+    /// assert_eq!(cholesky.l().to_nested(), vec![vec![2.0, 0.0], vec![1.0, 2.0_f64.sqrt()]]);
+    /// ```
+    pub fn cholesky(&self) -> Result<Cholesky, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() });
+        }
+
+        let n = self.rows();
+        let mut l = Matrix2D::zeros(n, n);
+        for j in 0..n {
+            let diagonal = self[(j, j)] - (0..j).map(|k| l[(j, k)].powi(2)).sum::<f64>();
+            if diagonal <= 0.0 || diagonal.is_nan() {
+                return Err(MatrixError::NotPositiveDefinite { pivot: j });
+            }
+            let l_jj = diagonal.sqrt();
+            l[(j, j)] = l_jj;
+
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / l_jj;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// 对对称矩阵进行 LDLᵀ 分解。
+    ///
+    /// 只读取矩阵的下三角部分，分解过程不做主元交换。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `LDLT` 分解结果；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；
+    /// 如果某个对角主元为零或可忽略，返回 `MatrixError::Singular`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// // 对称不定矩阵
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+    /// let ldlt = a.ldlt().unwrap();
+    /// assert_eq!(ldlt.d(), &[1.0, -3.0]);
+    /// ```
+    pub fn ldlt(&self) -> Result<LDLT, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() });
+        }
+
+        let n = self.rows();
+        let max_abs = self
+            .as_slice()
+            .iter()
+            .fold(0.0_f64, |acc, &x| acc.max(x.abs()));
+        let tolerance = n as f64 * f64::EPSILON * max_abs;
+
+        let mut l = Matrix2D::eye(n);
+        let mut d = vec![0.0; n];
+        for j in 0..n {
+            d[j] = self[(j, j)] - (0..j).map(|k| l[(j, k)].powi(2) * d[k]).sum::<f64>();
+            if d[j].abs() <= tolerance {
+                return Err(MatrixError::Singular { pivot: j });
+            }
+
+            for i in j + 1..n {
+                let sum: f64 = (0..j).map(|k| l[(i, k)] * l[(j, k)] * d[k]).sum();
+                l[(i, j)] = (self[(i, j)] - sum) / d[j];
+            }
+        }
+
+        Ok(LDLT { l, d })
+    }
+}
+
+impl Cholesky {
+    /// 返回下三角因子 `L`。
+    pub fn l(&self) -> Matrix2D {
+        self.l.clone()
+    }
+
+    /// 求解线性方程组 `A * x = b`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 右端项，长度必须等于矩阵阶数。
+    ///
+    /// # 返回
+    ///
+    /// 返回解向量 `x`；如果 `b` 的长度与阶数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        check_rhs(&self.l, b)?;
+        Ok(self.substitute(b))
+    }
+
+    /// 依次做前代与回代，调用方保证 `b` 的长度等于矩阵阶数。
+    fn substitute(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.l.rows();
+
+        // 前代求解 L * y = b
+        let mut x = b.to_vec();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.l[(i, k)] * x[k];
+            }
+            x[i] /= self.l[(i, i)];
+        }
+        // 回代求解 Lᵀ * x = y
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] -= self.l[(k, i)] * x[k];
+            }
+            x[i] /= self.l[(i, i)];
+        }
+        x
+    }
+
+    /// 计算行列式的自然对数 `ln det(A) = 2 * Σ ln l_ii`，可避免大矩阵行列式的溢出。
+    pub fn log_determinant(&self) -> f64 {
+        2.0 * (0..self.l.rows()).map(|i| self.l[(i, i)].ln()).sum::<f64>()
+    }
+
+    /// 利用分解结果计算原矩阵的逆矩阵。
+    pub fn inverse(&self) -> Matrix2D {
+        inverse_by_columns(self.l.rows(), |e| self.substitute(e))
+    }
+}
+
+impl LDLT {
+    /// 返回单位下三角因子 `L`。
+    pub fn l(&self) -> Matrix2D {
+        self.l.clone()
+    }
+
+    /// 返回对角因子 `D` 的对角元。
+    pub fn d(&self) -> &[f64] {
+        &self.d
+    }
+
+    /// 求解线性方程组 `A * x = b`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 右端项，长度必须等于矩阵阶数。
+    ///
+    /// # 返回
+    ///
+    /// 返回解向量 `x`；如果 `b` 的长度与阶数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn solve(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        check_rhs(&self.l, b)?;
+        Ok(self.substitute(b))
+    }
+
+    /// 依次做前代与回代，调用方保证 `b` 的长度等于矩阵阶数。
+    fn substitute(&self, b: &[f64]) -> Vec<f64> {
+        let n = self.l.rows();
+
+        // 前代求解 L * y = b
+        let mut x = b.to_vec();
+        for i in 0..n {
+            for k in 0..i {
+                x[i] -= self.l[(i, k)] * x[k];
+            }
+        }
+        // 求解 D * z = y
+        for (value, d) in x.iter_mut().zip(&self.d) {
+            *value /= d;
+        }
+        // 回代求解 Lᵀ * x = z
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                x[i] -= self.l[(k, i)] * x[k];
+            }
+        }
+        x
+    }
+
+    /// 计算行列式的符号与绝对值的自然对数。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `(sign, ln|det(A)|)`，其中 `sign` 为 `1.0` 或 `-1.0`，`det(A) = sign * exp(ln|det(A)|)`。
+    pub fn log_determinant(&self) -> (f64, f64) {
+        self.d.iter().fold((1.0, 0.0), |(sign, log), &d| (sign * d.signum(), log + d.abs().ln()))
+    }
+
+    /// 利用分解结果计算原矩阵的逆矩阵。
+    pub fn inverse(&self) -> Matrix2D {
+        inverse_by_columns(self.l.rows(), |e| self.substitute(e))
+    }
+}
+
+/// 检查右端项长度是否等于矩阵阶数。
+fn check_rhs(l: &Matrix2D, b: &[f64]) -> Result<(), MatrixError> {
+    if b.len() != l.rows() {
+        return Err(MatrixError::DimensionMismatch { left: l.shape(), right: (b.len(), 1) });
+    }
+    Ok(())
+}
+
+/// 逐列求解 `A * x = e_j`，拼出 n×n 逆矩阵。
+fn inverse_by_columns<F>(n: usize, solve: F) -> Matrix2D
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    let mut inverse = Matrix2D::zeros(n, n);
+    let mut e = vec![0.0; n];
+    for j in 0..n {
+        e[j] = 1.0;
+        for (i, value) in solve(&e).into_iter().enumerate() {
+            inverse[(i, j)] = value;
+        }
+        e[j] = 0.0;
+    }
+    inverse
+}
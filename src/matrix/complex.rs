@@ -0,0 +1,73 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::number::complex::Complex;
+use crate::number::scalar::RealField;
+
+#[allow(dead_code)]
+impl<T: RealField> Matrix2D<Complex<T>> {
+    /// 由同形状的实部矩阵与虚部矩阵组合出复矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `re + i·im`；如果两个矩阵形状不同，返回 `MatrixError::DimensionMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let re = Matrix2D::new(vec![vec![1.0, 2.0]]).unwrap();
+    /// let im = Matrix2D::new(vec![vec![0.0, -1.0]]).unwrap();
+    /// let z = Matrix2D::from_parts(&re, &im).unwrap();
+    /// assert_eq!(z[(0, 1)], Complex::new(2.0, -1.0));
+    /// assert_eq!(z.real_part(), re);
+    /// assert_eq!(z.imag_part(), im);
+    /// ```
+    pub fn from_parts(re: &Matrix2D<T>, im: &Matrix2D<T>) -> Result<Matrix2D<Complex<T>>, MatrixError> {
+        if re.shape() != im.shape() {
+            return Err(MatrixError::DimensionMismatch { left: re.shape(), right: im.shape() });
+        }
+        Ok(Matrix2D::from_fn(re.rows(), re.cols(), |i, j| Complex::new(re[(i, j)], im[(i, j)])))
+    }
+
+    /// 返回由各元素实部组成的矩阵。
+    pub fn real_part(&self) -> Matrix2D<T> {
+        self.map(|z| z.re)
+    }
+
+    /// 返回由各元素虚部组成的矩阵。
+    pub fn imag_part(&self) -> Matrix2D<T> {
+        self.map(|z| z.im)
+    }
+
+    /// 基于带部分主元选取的 LU 分解计算复方阵的行列式。
+    ///
+    /// # 返回
+    ///
+    /// 如果矩阵为方阵，返回行列式的值；否则返回 `MatrixError::NotSquare`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let i = Complex::i();
+    /// let a = Matrix2D::new(vec![vec![i, Complex::new(2.0, 0.0)], vec![Complex::new(1.0, 0.0), i]]).unwrap();
+    /// assert!((a.determinant().unwrap() - Complex::new(-3.0, 0.0)).norm() < 1e-12);
+    /// ```
+    pub fn determinant(&self) -> Result<Complex<T>, MatrixError> {
+        Ok(self.lu()?.determinant())
+    }
+
+    /// 基于带部分主元选取的 LU 分解计算复方阵的逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 如果矩阵为非奇异方阵，返回逆矩阵；矩阵不是方阵时返回 `MatrixError::NotSquare`，
+    /// 奇异时返回带有失效主元下标的 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Matrix2D<Complex<T>>, MatrixError> {
+        self.lu()?.inverse()
+    }
+}
@@ -0,0 +1,752 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::number::complex::Complex;
+
+/// 对称特征值分解默认的收敛容差（相对于矩阵的 Frobenius 范数）。
+pub const DEFAULT_EIGEN_TOLERANCE: f64 = f64::EPSILON;
+
+/// 对称特征值分解默认允许的最大扫描次数。
+pub const DEFAULT_MAX_SWEEPS: usize = 100;
+
+/// 实对称矩阵的特征值分解结果，满足 `A = V * diag(λ) * Vᵀ`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SymmetricEigen {
+    /// 按升序排列的特征值。
+    pub eigenvalues: Vec<f64>,
+    /// 标准正交的特征向量矩阵，第 `i` 列对应第 `i` 个特征值。
+    pub eigenvectors: Matrix2D,
+    /// 达到收敛所用的扫描次数。
+    pub sweeps: usize,
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 使用循环 Jacobi 方法计算实对称矩阵的特征值和特征向量。
+    ///
+    /// 使用默认的收敛容差 [`DEFAULT_EIGEN_TOLERANCE`] 和最大扫描次数 [`DEFAULT_MAX_SWEEPS`]，
+    /// 详见 [`Matrix2D::symmetric_eigen_with`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let eigen = a.symmetric_eigen().unwrap();
+    /// assert!((eigen.eigenvalues[0] - 1.0).abs() < 1e-12);
+    /// assert!((eigen.eigenvalues[1] - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn symmetric_eigen(&self) -> Result<SymmetricEigen, MatrixError> {
+        self.symmetric_eigen_with(DEFAULT_EIGEN_TOLERANCE, DEFAULT_MAX_SWEEPS)
+    }
+
+    /// 使用循环 Jacobi 方法计算实对称矩阵的特征值和特征向量。
+    ///
+    /// 只读取矩阵的上三角部分。每次扫描依次对所有非对角元做 Jacobi 旋转，
+    /// 当非对角元的 Frobenius 范数不超过 `tolerance * ‖A‖_F` 时视为收敛。
+    ///
+    /// # 参数
+    ///
+    /// * `tolerance` - 相对收敛容差。
+    /// * `max_sweeps` - 允许的最大扫描次数。
+    ///
+    /// # 返回
+    ///
+    /// 返回按升序排列的特征值及对应的标准正交特征向量；
+    /// 如果矩阵不是方阵，返回 `MatrixError::NotSquare`；
+    /// 如果在 `max_sweeps` 次扫描内未收敛，返回 `MatrixError::NotConverged`。
+    pub fn symmetric_eigen_with(&self, tolerance: f64, max_sweeps: usize) -> Result<SymmetricEigen, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() });
+        }
+
+        let n = self.rows();
+        let mut a = self.clone();
+        for i in 0..n {
+            for j in 0..i {
+                a[(i, j)] = a[(j, i)];
+            }
+        }
+        let mut v = Matrix2D::eye(n);
+
+        let norm = a.as_slice().iter().map(|x| x * x).sum::<f64>().sqrt();
+        let threshold = tolerance * norm;
+
+        let mut sweeps = 0;
+        loop {
+            let off_diagonal = (0..n)
+                .flat_map(|p| (p + 1..n).map(move |q| (p, q)))
+                .map(|(p, q)| 2.0 * a[(p, q)].powi(2))
+                .sum::<f64>()
+                .sqrt();
+            if off_diagonal <= threshold {
+                break;
+            }
+            if sweeps == max_sweeps {
+                return Err(MatrixError::NotConverged { iterations: sweeps });
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if a[(p, q)] != 0.0 {
+                        jacobi_rotate(&mut a, &mut v, p, q);
+                    }
+                }
+            }
+            sweeps += 1;
+        }
+
+        // 按特征值升序重排特征向量
+        let diagonal: Vec<f64> = (0..n).map(|i| a[(i, i)]).collect();
+        let order = Matrix2D::argsort(&diagonal);
+        let eigenvalues = order.iter().map(|&i| diagonal[i]).collect();
+        let mut eigenvectors = Matrix2D::zeros(n, n);
+        for (new_col, &old_col) in order.iter().enumerate() {
+            for i in 0..n {
+                eigenvectors[(i, new_col)] = v[(i, old_col)];
+            }
+        }
+
+        Ok(SymmetricEigen { eigenvalues, eigenvectors, sweeps })
+    }
+}
+
+/// 对 (p, q) 位置做一次 Jacobi 旋转，使 `a[p][q]` 归零，并把旋转累积到 `v` 中。
+fn jacobi_rotate(a: &mut Matrix2D, v: &mut Matrix2D, p: usize, q: usize) {
+    let n = a.rows();
+    let theta = (a[(q, q)] - a[(p, p)]) / (2.0 * a[(p, q)]);
+    let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+    let c = 1.0 / (t * t + 1.0).sqrt();
+    let s = t * c;
+
+    // A = Jᵀ * A * J，先更新列再更新行
+    for k in 0..n {
+        let (akp, akq) = (a[(k, p)], a[(k, q)]);
+        a[(k, p)] = c * akp - s * akq;
+        a[(k, q)] = s * akp + c * akq;
+    }
+    for k in 0..n {
+        let (apk, aqk) = (a[(p, k)], a[(q, k)]);
+        a[(p, k)] = c * apk - s * aqk;
+        a[(q, k)] = s * apk + c * aqk;
+    }
+    a[(p, q)] = 0.0;
+    a[(q, p)] = 0.0;
+
+    for k in 0..n {
+        let (vkp, vkq) = (v[(k, p)], v[(k, q)]);
+        v[(k, p)] = c * vkp - s * vkq;
+        v[(k, q)] = s * vkp + c * vkq;
+    }
+}
+
+/// 一般实方阵的特征值分解结果。
+///
+/// 复特征值总是以共轭对的形式相邻出现，虚部为正的一个在前。
+/// 特征向量采用实数紧凑存储：若第 `j`、`j + 1` 个特征值为共轭复数对，
+/// 则第 `j` 个特征向量为 `v[:, j] + i * v[:, j + 1]`，第 `j + 1` 个为其共轭。
+#[derive(Debug, Clone, PartialEq)]
+pub struct GeneralEigen {
+    /// 特征值的实部。
+    pub real: Vec<f64>,
+    /// 特征值的虚部。
+    pub imag: Vec<f64>,
+    /// 紧凑存储的右特征向量，仅在请求计算时存在。
+    pub eigenvectors: Option<Matrix2D>,
+    /// QR 迭代的总次数。
+    pub iterations: usize,
+}
+
+/// 正交相似变换得到的上 Hessenberg 形式，满足 `A = Q * H * Qᵀ`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hessenberg {
+    /// 上 Hessenberg 矩阵，次对角线以下的元素均为零。
+    pub h: Matrix2D,
+    /// 累积的正交变换矩阵。
+    pub q: Matrix2D,
+}
+
+impl GeneralEigen {
+    /// 返回第 `k` 个右特征向量的实部与虚部。
+    ///
+    /// # 注意
+    ///
+    /// 如果分解时未计算特征向量，将会引发 panic。
+    pub fn eigenvector(&self, k: usize) -> (Vec<f64>, Vec<f64>) {
+        let v = self.eigenvectors.as_ref().expect("Eigenvectors were not computed.");
+        if self.imag[k] == 0.0 {
+            (v.column(k), vec![0.0; v.rows()])
+        } else if self.imag[k] > 0.0 {
+            (v.column(k), v.column(k + 1))
+        } else {
+            (v.column(k - 1), v.column(k).iter().map(|x| -x).collect())
+        }
+    }
+
+    /// 以复数形式返回全部特征值，顺序与 `real`、`imag` 相同。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let rotation = Matrix2D::new(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    /// let eigenvalues = rotation.general_eigen().unwrap().eigenvalues();
+    /// assert!((eigenvalues[0] - Complex::i()).norm() < 1e-12);
+    /// assert!((eigenvalues[1] + Complex::i()).norm() < 1e-12);
+    /// ```
+    pub fn eigenvalues(&self) -> Vec<Complex> {
+        self.real.iter().zip(&self.imag).map(|(&re, &im)| Complex::new(re, im)).collect()
+    }
+
+    /// 以复向量形式返回第 `k` 个右特征向量，即 [`GeneralEigen::eigenvector`] 的实部与虚部之和。
+    ///
+    /// # 注意
+    ///
+    /// 如果分解时未计算特征向量，将会引发 panic。
+    pub fn complex_eigenvector(&self, k: usize) -> Vec<Complex> {
+        let (re, im) = self.eigenvector(k);
+        re.into_iter().zip(im).map(|(re, im)| Complex::new(re, im)).collect()
+    }
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 使用 Householder 反射把方阵正交相似地约化为上 Hessenberg 形式。
+    ///
+    /// # 返回
+    ///
+    /// 返回满足 `A = Q * H * Qᵀ` 的 `Hessenberg` 结果；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    pub fn hessenberg(&self) -> Result<Hessenberg, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() });
+        }
+        let n = self.rows();
+        let mut h: Vec<f64> = self.as_slice().to_vec();
+        let mut v = vec![0.0; n * n];
+        orthes(n, &mut h, &mut v);
+        for i in 0..n {
+            for j in 0..i.saturating_sub(1) {
+                h[i * n + j] = 0.0;
+            }
+        }
+        Ok(Hessenberg { h: from_flat(n, &h), q: from_flat(n, &v) })
+    }
+
+    /// 计算一般实方阵的特征值与右特征向量。
+    ///
+    /// 先约化为上 Hessenberg 形式，再使用带隐式双重位移的 Francis QR 算法
+    /// 求出实 Schur 形式，最后回代得到特征向量。特征值不做排序。
+    ///
+    /// # 返回
+    ///
+    /// 返回包含特征值与特征向量的 `GeneralEigen`；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；
+    /// 如果 QR 迭代未能收敛，返回 `MatrixError::NotConverged`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// // 逆时针旋转 90° 的特征值为 ±i
+    /// let rotation = Matrix2D::new(vec![vec![0.0, -1.0], vec![1.0, 0.0]]).unwrap();
+    /// let eigen = rotation.general_eigen().unwrap();
+    /// assert!(eigen.real.iter().all(|re| re.abs() < 1e-12));
+    /// assert!((eigen.imag[0] - 1.0).abs() < 1e-12 && (eigen.imag[1] + 1.0).abs() < 1e-12);
+    /// ```
+    pub fn general_eigen(&self) -> Result<GeneralEigen, MatrixError> {
+        self.general_eigen_impl(true)
+    }
+
+    /// 计算一般实方阵的特征值，不计算特征向量。
+    ///
+    /// 算法与 [`Matrix2D::general_eigen`] 相同，返回结果中的 `eigenvectors` 为 `None`。
+    pub fn general_eigenvalues(&self) -> Result<GeneralEigen, MatrixError> {
+        self.general_eigen_impl(false)
+    }
+
+    fn general_eigen_impl(&self, compute_vectors: bool) -> Result<GeneralEigen, MatrixError> {
+        if self.rows() != self.cols() {
+            return Err(MatrixError::NotSquare { rows: self.rows(), cols: self.cols() });
+        }
+        let n = self.rows();
+        let mut h: Vec<f64> = self.as_slice().to_vec();
+        let mut v = vec![0.0; n * n];
+        orthes(n, &mut h, &mut v);
+
+        let mut real = vec![0.0; n];
+        let mut imag = vec![0.0; n];
+        let iterations = hqr2(n, &mut h, &mut v, &mut real, &mut imag, compute_vectors)?;
+
+        Ok(GeneralEigen {
+            real,
+            imag,
+            eigenvectors: if compute_vectors { Some(from_flat(n, &v)) } else { None },
+            iterations,
+        })
+    }
+}
+
+/// 由按行展开的 n×n 数据构造矩阵。
+fn from_flat(n: usize, data: &[f64]) -> Matrix2D {
+    Matrix2D::from_fn(n, n, |i, j| data[i * n + j])
+}
+
+/// Householder 约化为上 Hessenberg 形式，`v` 中返回累积的正交变换。
+///
+/// 移植自 EISPACK 的 orthes 与 ortran 过程。
+fn orthes(n: usize, h: &mut [f64], v: &mut [f64]) {
+    let at = |i: usize, j: usize| i * n + j;
+    let mut ort = vec![0.0; n];
+    let high = n.saturating_sub(1);
+
+    for m in 1..high {
+        let scale: f64 = (m..=high).map(|i| h[at(i, m - 1)].abs()).sum();
+        if scale == 0.0 {
+            continue;
+        }
+
+        let mut hh = 0.0;
+        for i in (m..=high).rev() {
+            ort[i] = h[at(i, m - 1)] / scale;
+            hh += ort[i] * ort[i];
+        }
+        let g = if ort[m] > 0.0 { -hh.sqrt() } else { hh.sqrt() };
+        hh -= ort[m] * g;
+        ort[m] -= g;
+
+        // H = (I - u * uᵀ / h) * H * (I - u * uᵀ / h)
+        for j in m..n {
+            let f = (m..=high).rev().map(|i| ort[i] * h[at(i, j)]).sum::<f64>() / hh;
+            for i in m..=high {
+                h[at(i, j)] -= f * ort[i];
+            }
+        }
+        for i in 0..=high {
+            let f = (m..=high).rev().map(|j| ort[j] * h[at(i, j)]).sum::<f64>() / hh;
+            for j in m..=high {
+                h[at(i, j)] -= f * ort[j];
+            }
+        }
+        ort[m] *= scale;
+        h[at(m, m - 1)] = scale * g;
+    }
+
+    // 累积变换矩阵
+    for i in 0..n {
+        for j in 0..n {
+            v[at(i, j)] = if i == j { 1.0 } else { 0.0 };
+        }
+    }
+    for m in (1..high).rev() {
+        if h[at(m, m - 1)] == 0.0 {
+            continue;
+        }
+        for i in m + 1..=high {
+            ort[i] = h[at(i, m - 1)];
+        }
+        for j in m..=high {
+            let g: f64 = (m..=high).map(|i| ort[i] * v[at(i, j)]).sum();
+            // 两次除法以避免下溢
+            let g = (g / ort[m]) / h[at(m, m - 1)];
+            for i in m..=high {
+                v[at(i, j)] += g * ort[i];
+            }
+        }
+    }
+}
+
+/// 复数除法 `(xr + i * xi) / (yr + i * yi)`。
+fn cdiv(xr: f64, xi: f64, yr: f64, yi: f64) -> (f64, f64) {
+    if yr.abs() > yi.abs() {
+        let r = yi / yr;
+        let d = yr + r * yi;
+        ((xr + r * xi) / d, (xi - r * xr) / d)
+    } else {
+        let r = yr / yi;
+        let d = yi + r * yr;
+        ((r * xr + xi) / d, (r * xi - xr) / d)
+    }
+}
+
+/// 对上 Hessenberg 矩阵执行带双重位移的 Francis QR 迭代，并在需要时回代求特征向量。
+///
+/// 移植自 EISPACK 的 hqr2 过程。返回 QR 迭代的总次数。
+fn hqr2(
+    nn: usize,
+    h: &mut [f64],
+    v: &mut [f64],
+    d: &mut [f64],
+    e: &mut [f64],
+    compute_vectors: bool,
+) -> Result<usize, MatrixError> {
+    let at = |i: isize, j: isize| i as usize * nn + j as usize;
+    let max_iterations = 30 * nn.max(10);
+    let low: isize = 0;
+    let high = nn as isize - 1;
+    let eps = f64::EPSILON;
+    let mut exshift = 0.0;
+    let (mut p, mut q, mut r, mut s, mut z) = (0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64, 0.0_f64);
+    let (mut t, mut w, mut x, mut y): (f64, f64, f64, f64);
+
+    // 计算矩阵范数
+    let mut norm = 0.0;
+    for i in 0..nn as isize {
+        for j in (i - 1).max(0)..nn as isize {
+            norm += h[at(i, j)].abs();
+        }
+    }
+
+    let mut n = nn as isize - 1;
+    let mut iter = 0;
+    let mut total_iterations = 0;
+    while n >= low {
+        // 寻找可忽略的次对角元
+        let mut l = n;
+        while l > low {
+            s = h[at(l - 1, l - 1)].abs() + h[at(l, l)].abs();
+            if s == 0.0 {
+                s = norm;
+            }
+            if h[at(l, l - 1)].abs() < eps * s {
+                break;
+            }
+            l -= 1;
+        }
+
+        if l == n {
+            // 分离出一个实根
+            h[at(n, n)] += exshift;
+            d[n as usize] = h[at(n, n)];
+            e[n as usize] = 0.0;
+            n -= 1;
+            iter = 0;
+        } else if l == n - 1 {
+            // 分离出两个根
+            w = h[at(n, n - 1)] * h[at(n - 1, n)];
+            p = (h[at(n - 1, n - 1)] - h[at(n, n)]) / 2.0;
+            q = p * p + w;
+            z = q.abs().sqrt();
+            h[at(n, n)] += exshift;
+            h[at(n - 1, n - 1)] += exshift;
+            x = h[at(n, n)];
+
+            if q >= 0.0 {
+                // 实根对
+                z = if p >= 0.0 { p + z } else { p - z };
+                d[n as usize - 1] = x + z;
+                d[n as usize] = d[n as usize - 1];
+                if z != 0.0 {
+                    d[n as usize] = x - w / z;
+                }
+                e[n as usize - 1] = 0.0;
+                e[n as usize] = 0.0;
+                x = h[at(n, n - 1)];
+                s = x.abs() + z.abs();
+                p = x / s;
+                q = z / s;
+                r = (p * p + q * q).sqrt();
+                p /= r;
+                q /= r;
+
+                // 行变换
+                for j in n - 1..nn as isize {
+                    z = h[at(n - 1, j)];
+                    h[at(n - 1, j)] = q * z + p * h[at(n, j)];
+                    h[at(n, j)] = q * h[at(n, j)] - p * z;
+                }
+                // 列变换
+                for i in 0..=n {
+                    z = h[at(i, n - 1)];
+                    h[at(i, n - 1)] = q * z + p * h[at(i, n)];
+                    h[at(i, n)] = q * h[at(i, n)] - p * z;
+                }
+                // 累积变换
+                if compute_vectors {
+                    for i in low..=high {
+                        z = v[at(i, n - 1)];
+                        v[at(i, n - 1)] = q * z + p * v[at(i, n)];
+                        v[at(i, n)] = q * v[at(i, n)] - p * z;
+                    }
+                }
+            } else {
+                // 共轭复根对
+                d[n as usize - 1] = x + p;
+                d[n as usize] = x + p;
+                e[n as usize - 1] = z;
+                e[n as usize] = -z;
+            }
+            n -= 2;
+            iter = 0;
+        } else {
+            if total_iterations == max_iterations {
+                return Err(MatrixError::NotConverged { iterations: total_iterations });
+            }
+
+            // 构造位移
+            x = h[at(n, n)];
+            y = 0.0;
+            w = 0.0;
+            if l < n {
+                y = h[at(n - 1, n - 1)];
+                w = h[at(n, n - 1)] * h[at(n - 1, n)];
+            }
+
+            // Wilkinson 的特设位移
+            if iter == 10 {
+                exshift += x;
+                for i in low..=n {
+                    h[at(i, i)] -= x;
+                }
+                s = h[at(n, n - 1)].abs() + h[at(n - 1, n - 2)].abs();
+                x = 0.75 * s;
+                y = x;
+                w = -0.4375 * s * s;
+            }
+
+            // MATLAB 的特设位移
+            if iter == 30 {
+                s = (y - x) / 2.0;
+                s = s * s + w;
+                if s > 0.0 {
+                    s = s.sqrt();
+                    if y < x {
+                        s = -s;
+                    }
+                    s = x - w / ((y - x) / 2.0 + s);
+                    for i in low..=n {
+                        h[at(i, i)] -= s;
+                    }
+                    exshift += s;
+                    x = 0.964;
+                    y = x;
+                    w = x;
+                }
+            }
+
+            iter += 1;
+            total_iterations += 1;
+
+            // 寻找两个相邻的可忽略次对角元
+            let mut m = n - 2;
+            while m >= l {
+                z = h[at(m, m)];
+                r = x - z;
+                s = y - z;
+                p = (r * s - w) / h[at(m + 1, m)] + h[at(m, m + 1)];
+                q = h[at(m + 1, m + 1)] - z - r - s;
+                r = h[at(m + 2, m + 1)];
+                s = p.abs() + q.abs() + r.abs();
+                p /= s;
+                q /= s;
+                r /= s;
+                if m == l {
+                    break;
+                }
+                if h[at(m, m - 1)].abs() * (q.abs() + r.abs())
+                    < eps * (p.abs() * (h[at(m - 1, m - 1)].abs() + z.abs() + h[at(m + 1, m + 1)].abs()))
+                {
+                    break;
+                }
+                m -= 1;
+            }
+
+            for i in m + 2..=n {
+                h[at(i, i - 2)] = 0.0;
+                if i > m + 2 {
+                    h[at(i, i - 3)] = 0.0;
+                }
+            }
+
+            // 作用于第 l..n 行、第 m..n 列的双重 QR 步
+            let mut k = m;
+            while k < n {
+                let notlast = k != n - 1;
+                if k != m {
+                    p = h[at(k, k - 1)];
+                    q = h[at(k + 1, k - 1)];
+                    r = if notlast { h[at(k + 2, k - 1)] } else { 0.0 };
+                    x = p.abs() + q.abs() + r.abs();
+                    if x == 0.0 {
+                        k += 1;
+                        continue;
+                    }
+                    p /= x;
+                    q /= x;
+                    r /= x;
+                }
+
+                s = (p * p + q * q + r * r).sqrt();
+                if p < 0.0 {
+                    s = -s;
+                }
+                if s != 0.0 {
+                    if k != m {
+                        h[at(k, k - 1)] = -s * x;
+                    } else if l != m {
+                        h[at(k, k - 1)] = -h[at(k, k - 1)];
+                    }
+                    p += s;
+                    x = p / s;
+                    y = q / s;
+                    z = r / s;
+                    q /= p;
+                    r /= p;
+
+                    // 行变换
+                    for j in k..nn as isize {
+                        p = h[at(k, j)] + q * h[at(k + 1, j)];
+                        if notlast {
+                            p += r * h[at(k + 2, j)];
+                            h[at(k + 2, j)] -= p * z;
+                        }
+                        h[at(k, j)] -= p * x;
+                        h[at(k + 1, j)] -= p * y;
+                    }
+                    // 列变换
+                    for i in 0..=n.min(k + 3) {
+                        p = x * h[at(i, k)] + y * h[at(i, k + 1)];
+                        if notlast {
+                            p += z * h[at(i, k + 2)];
+                            h[at(i, k + 2)] -= p * r;
+                        }
+                        h[at(i, k)] -= p;
+                        h[at(i, k + 1)] -= p * q;
+                    }
+                    // 累积变换
+                    if compute_vectors {
+                        for i in low..=high {
+                            p = x * v[at(i, k)] + y * v[at(i, k + 1)];
+                            if notlast {
+                                p += z * v[at(i, k + 2)];
+                                v[at(i, k + 2)] -= p * r;
+                            }
+                            v[at(i, k)] -= p;
+                            v[at(i, k + 1)] -= p * q;
+                        }
+                    }
+                }
+                k += 1;
+            }
+        }
+    }
+
+    if !compute_vectors || norm == 0.0 {
+        return Ok(total_iterations);
+    }
+
+    // 回代求上三角形式的特征向量
+    for n in (0..nn as isize).rev() {
+        p = d[n as usize];
+        q = e[n as usize];
+
+        if q == 0.0 {
+            // 实特征向量
+            let mut l = n;
+            h[at(n, n)] = 1.0;
+            for i in (0..n).rev() {
+                w = h[at(i, i)] - p;
+                r = (l..=n).map(|j| h[at(i, j)] * h[at(j, n)]).sum();
+                if e[i as usize] < 0.0 {
+                    z = w;
+                    s = r;
+                } else {
+                    l = i;
+                    if e[i as usize] == 0.0 {
+                        h[at(i, n)] = if w != 0.0 { -r / w } else { -r / (eps * norm) };
+                    } else {
+                        // 求解实方程组
+                        x = h[at(i, i + 1)];
+                        y = h[at(i + 1, i)];
+                        q = (d[i as usize] - p) * (d[i as usize] - p) + e[i as usize] * e[i as usize];
+                        t = (x * s - z * r) / q;
+                        h[at(i, n)] = t;
+                        h[at(i + 1, n)] = if x.abs() > z.abs() { (-r - w * t) / x } else { (-s - y * t) / z };
+                    }
+
+                    // 溢出控制
+                    t = h[at(i, n)].abs();
+                    if (eps * t) * t > 1.0 {
+                        for j in i..=n {
+                            h[at(j, n)] /= t;
+                        }
+                    }
+                }
+            }
+        } else if q < 0.0 {
+            // 复特征向量
+            let mut l = n - 1;
+
+            // 最后一个分量取为纯虚数，使方程组为三角形式
+            if h[at(n, n - 1)].abs() > h[at(n - 1, n)].abs() {
+                h[at(n - 1, n - 1)] = q / h[at(n, n - 1)];
+                h[at(n - 1, n)] = -(h[at(n, n)] - p) / h[at(n, n - 1)];
+            } else {
+                let (cr, ci) = cdiv(0.0, -h[at(n - 1, n)], h[at(n - 1, n - 1)] - p, q);
+                h[at(n - 1, n - 1)] = cr;
+                h[at(n - 1, n)] = ci;
+            }
+            h[at(n, n - 1)] = 0.0;
+            h[at(n, n)] = 1.0;
+            for i in (0..n - 1).rev() {
+                let ra: f64 = (l..=n).map(|j| h[at(i, j)] * h[at(j, n - 1)]).sum();
+                let sa: f64 = (l..=n).map(|j| h[at(i, j)] * h[at(j, n)]).sum();
+                w = h[at(i, i)] - p;
+
+                if e[i as usize] < 0.0 {
+                    z = w;
+                    r = ra;
+                    s = sa;
+                } else {
+                    l = i;
+                    if e[i as usize] == 0.0 {
+                        let (cr, ci) = cdiv(-ra, -sa, w, q);
+                        h[at(i, n - 1)] = cr;
+                        h[at(i, n)] = ci;
+                    } else {
+                        // 求解复方程组
+                        x = h[at(i, i + 1)];
+                        y = h[at(i + 1, i)];
+                        let di = d[i as usize] - p;
+                        let mut vr = di * di + e[i as usize] * e[i as usize] - q * q;
+                        let vi = di * 2.0 * q;
+                        if vr == 0.0 && vi == 0.0 {
+                            vr = eps * norm * (w.abs() + q.abs() + x.abs() + y.abs() + z.abs());
+                        }
+                        let (cr, ci) = cdiv(x * r - z * ra + q * sa, x * s - z * sa - q * ra, vr, vi);
+                        h[at(i, n - 1)] = cr;
+                        h[at(i, n)] = ci;
+                        if x.abs() > z.abs() + q.abs() {
+                            h[at(i + 1, n - 1)] = (-ra - w * h[at(i, n - 1)] + q * h[at(i, n)]) / x;
+                            h[at(i + 1, n)] = (-sa - w * h[at(i, n)] - q * h[at(i, n - 1)]) / x;
+                        } else {
+                            let (cr, ci) = cdiv(-r - y * h[at(i, n - 1)], -s - y * h[at(i, n)], z, q);
+                            h[at(i + 1, n - 1)] = cr;
+                            h[at(i + 1, n)] = ci;
+                        }
+                    }
+
+                    // 溢出控制
+                    t = h[at(i, n - 1)].abs().max(h[at(i, n)].abs());
+                    if (eps * t) * t > 1.0 {
+                        for j in i..=n {
+                            h[at(j, n - 1)] /= t;
+                            h[at(j, n)] /= t;
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    // 变换回原矩阵的特征向量
+    for j in (low..nn as isize).rev() {
+        for i in low..=high {
+            z = (low..=j.min(high)).map(|k| v[at(i, k)] * h[at(k, j)]).sum();
+            v[at(i, j)] = z;
+        }
+    }
+
+    Ok(total_iterations)
+}
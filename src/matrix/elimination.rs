@@ -0,0 +1,148 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::number::field::Field;
+
+#[allow(dead_code)]
+impl<T: Field> Matrix2D<T> {
+    /// 使用 Gauss–Jordan 消元计算简化行阶梯形（RREF）。
+    ///
+    /// 每列选取绝对值最大的非零元素作为主元，主元化为 1 并消去所在列的其余元素。
+    /// 对 [`Rational`](crate::number::rational::Rational) 等精确类型，结果没有舍入误差。
+    ///
+    /// # 返回
+    ///
+    /// 返回简化行阶梯形矩阵，以及按升序排列的主元列下标；主元列的个数即矩阵的秩。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::rational::Rational;
+    ///
+    /// let r = |n| Rational::from_integer(n);
+    /// let a = Matrix2D::new(vec![vec![r(1), r(2), r(3)], vec![r(2), r(4), r(7)]]).unwrap();
+    /// let (rref, pivots) = a.rref();
+    /// assert_eq!(pivots, vec![0, 2]);
+    /// assert_eq!(rref.to_nested(), vec![vec![r(1), r(2), r(0)], vec![r(0), r(0), r(1)]]);
+    /// ```
+    pub fn rref(&self) -> (Matrix2D<T>, Vec<usize>) {
+        let mut a = self.clone();
+        let (rows, cols) = a.shape();
+        let mut pivots = Vec::new();
+        for col in 0..cols {
+            let row = pivots.len();
+            if row == rows {
+                break;
+            }
+            let Some(pivot_row) = (row..rows)
+                .filter(|&i| !a[(i, col)].is_zero())
+                .max_by(|&i, &k| a[(i, col)].magnitude().total_cmp(&a[(k, col)].magnitude()))
+            else {
+                continue;
+            };
+            a.swap_rows(row, pivot_row);
+
+            let pivot = a[(row, col)].clone();
+            for j in col..cols {
+                a[(row, j)] = a[(row, j)].clone() / pivot.clone();
+            }
+            for i in (0..rows).filter(|&i| i != row) {
+                let factor = a[(i, col)].clone();
+                if factor.is_zero() {
+                    continue;
+                }
+                for j in col..cols {
+                    a[(i, j)] = a[(i, j)].clone() - factor.clone() * a[(row, j)].clone();
+                }
+            }
+            pivots.push(col);
+        }
+        (a, pivots)
+    }
+
+    /// 使用 Bareiss 无分式消元计算行列式。
+    ///
+    /// 第 `k` 步的更新 `a_ij ← (a_ij * a_kk - a_ik * a_kj) / a_{k-1,k-1}` 中的除法恒为整除，
+    /// 因此整数元素的有理数矩阵在整个过程中都不会产生分数，中间结果也不会无谓地膨胀。
+    ///
+    /// # 返回
+    ///
+    /// 返回行列式；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::rational::Rational;
+    ///
+    /// let a = Matrix2D::from_fn(3, 3, |i, j| Rational::new(1, (i + j + 1) as i64));
+    /// assert_eq!(a.bareiss_determinant().unwrap(), Rational::new(1, 2160));
+    /// ```
+    pub fn bareiss_determinant(&self) -> Result<T, MatrixError> {
+        let (rows, cols) = self.shape();
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols });
+        }
+
+        let n = rows;
+        let mut a = self.clone();
+        let mut previous = T::one();
+        let mut negate = false;
+        for k in 0..n {
+            if a[(k, k)].is_zero() {
+                match (k + 1..n).find(|&i| !a[(i, k)].is_zero()) {
+                    Some(i) => {
+                        a.swap_rows(k, i);
+                        negate = !negate;
+                    }
+                    None => return Ok(T::zero()),
+                }
+            }
+            for i in k + 1..n {
+                for j in k + 1..n {
+                    a[(i, j)] = (a[(i, j)].clone() * a[(k, k)].clone() - a[(i, k)].clone() * a[(k, j)].clone())
+                        / previous.clone();
+                }
+            }
+            previous = a[(k, k)].clone();
+        }
+        Ok(if negate { -previous } else { previous })
+    }
+
+    /// 对增广矩阵 `[A | I]` 做 Gauss–Jordan 消元求逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回逆矩阵；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；如果矩阵奇异，
+    /// 返回 `MatrixError::Singular`，其中 `pivot` 为第一个没有主元的列。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::rational::Rational;
+    ///
+    /// let r = |n| Rational::from_integer(n);
+    /// let a = Matrix2D::new(vec![vec![r(2), r(1)], vec![r(1), r(1)]]).unwrap();
+    /// let inverse = a.gauss_jordan_inverse().unwrap();
+    /// assert_eq!(inverse.to_nested(), vec![vec![r(1), r(-1)], vec![r(-1), r(2)]]);
+    /// ```
+    pub fn gauss_jordan_inverse(&self) -> Result<Matrix2D<T>, MatrixError> {
+        let (rows, cols) = self.shape();
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols });
+        }
+
+        let n = rows;
+        let augmented = Matrix2D::from_fn(n, 2 * n, |i, j| match j < n {
+            true => self[(i, j)].clone(),
+            false if j - n == i => T::one(),
+            false => T::zero(),
+        });
+        let (reduced, pivots) = augmented.rref();
+        if let Some(pivot) = (0..n).find(|&k| pivots.get(k) != Some(&k)) {
+            return Err(MatrixError::Singular { pivot });
+        }
+        Ok(Matrix2D::from_fn(n, n, |i, j| reduced[(i, n + j)].clone()))
+    }
+}
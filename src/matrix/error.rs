@@ -0,0 +1,108 @@
+use std::error::Error;
+use std::fmt;
+
+/// 矩阵运算中可能出现的错误。
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixError {
+    /// 按行构造矩阵时，某一行的长度与第一行不同。
+    RaggedRows {
+        /// 长度不一致的行的下标。
+        row: usize,
+        /// 期望的列数。
+        expected: usize,
+        /// 该行实际的长度。
+        found: usize,
+    },
+    /// 按行优先数据构造矩阵时，数据长度与 `行数 * 列数` 不符。
+    DataLength {
+        /// 期望的数据长度。
+        expected: usize,
+        /// 实际的数据长度。
+        found: usize,
+    },
+    /// 两个操作数的形状不满足运算要求，例如相加的矩阵形状不同或相乘的内维不一致。
+    DimensionMismatch {
+        /// 左操作数的形状 `(行数, 列数)`。
+        left: (usize, usize),
+        /// 右操作数的形状 `(行数, 列数)`，向量视为 `(长度, 1)`。
+        right: (usize, usize),
+    },
+    /// 三维张量逐元素运算时，两个操作数的形状无法广播到同一形状。
+    BroadcastMismatch {
+        /// 左操作数的形状。
+        left: [usize; 3],
+        /// 右操作数的形状。
+        right: [usize; 3],
+    },
+    /// 元素下标超出矩阵的范围。
+    IndexOutOfBounds {
+        /// 越界的下标 `(行, 列)`。
+        index: (usize, usize),
+        /// 矩阵的形状 `(行数, 列数)`。
+        shape: (usize, usize),
+    },
+    /// 运算要求方阵，但输入矩阵的行数与列数不相等。
+    NotSquare {
+        /// 输入矩阵的行数。
+        rows: usize,
+        /// 输入矩阵的列数。
+        cols: usize,
+    },
+    /// 矩阵奇异或接近奇异，`pivot` 为第一个失效主元所在的列。
+    Singular {
+        /// 第一个失效主元的下标。
+        pivot: usize,
+    },
+    /// 迭代算法在允许的迭代次数内未能收敛。
+    NotConverged {
+        /// 已执行的迭代次数。
+        iterations: usize,
+    },
+    /// 矩阵不是正定的，`pivot` 为第一个非正主元所在的列。
+    NotPositiveDefinite {
+        /// 第一个非正主元的下标。
+        pivot: usize,
+    },
+}
+
+impl fmt::Display for MatrixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MatrixError::RaggedRows { row, expected, found } => {
+                write!(f, "row {} has {} columns, expected {}", row, found, expected)
+            }
+            MatrixError::DataLength { expected, found } => {
+                write!(f, "data has {} elements, expected {}", found, expected)
+            }
+            MatrixError::DimensionMismatch { left, right } => write!(
+                f,
+                "dimension mismatch: {}x{} and {}x{}",
+                left.0, left.1, right.0, right.1
+            ),
+            MatrixError::BroadcastMismatch { left, right } => write!(
+                f,
+                "shapes {}x{}x{} and {}x{}x{} cannot be broadcast together",
+                left[0], left[1], left[2], right[0], right[1], right[2]
+            ),
+            MatrixError::IndexOutOfBounds { index, shape } => write!(
+                f,
+                "index ({}, {}) is out of bounds for a {}x{} matrix",
+                index.0, index.1, shape.0, shape.1
+            ),
+            MatrixError::NotSquare { rows, cols } => {
+                write!(f, "matrix must be square, got {}x{}", rows, cols)
+            }
+            MatrixError::Singular { pivot } => {
+                write!(f, "matrix is singular (pivot {} is zero or negligible)", pivot)
+            }
+            MatrixError::NotConverged { iterations } => {
+                write!(f, "iteration did not converge after {} iterations", iterations)
+            }
+            MatrixError::NotPositiveDefinite { pivot } => {
+                write!(f, "matrix is not positive definite (pivot {} is not positive)", pivot)
+            }
+        }
+    }
+}
+
+impl Error for MatrixError {}
@@ -0,0 +1,236 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::matrix::io::ParseError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// `Display` 完整显示的最大行数，超过时只显示首尾各 [`DISPLAY_EDGE_ITEMS`] 行。
+pub const DISPLAY_MAX_ROWS: usize = 10;
+/// `Display` 完整显示的最大列数，超过时只显示首尾各 [`DISPLAY_EDGE_ITEMS`] 列。
+pub const DISPLAY_MAX_COLS: usize = 10;
+/// 截断显示时首尾各保留的行数或列数。
+pub const DISPLAY_EDGE_ITEMS: usize = 4;
+
+impl<T: Display + Clone> Display for Matrix2D<T> {
+    /// 每行一对方括号，各列右对齐。
+    ///
+    /// 格式说明中的精度（如 `{:.3}`）作用于每个元素；超过 [`DISPLAY_MAX_ROWS`] 行或
+    /// [`DISPLAY_MAX_COLS`] 列时用 `...` 省略中间部分，使用 `{:#}` 可以显示全部元素。
+    /// 空矩阵显示为 `[]`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::new(vec![vec![1.0, -2.5], vec![10.0, 0.125]]).unwrap();
+    /// assert_eq!(format!("{}", m), "[ 1   -2.5]\n[10  0.125]");
+    /// assert_eq!(format!("{:.2}", m), "[ 1.00  -2.50]\n[10.00   0.12]");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.rows() == 0 || self.cols() == 0 {
+            return write!(f, "[]");
+        }
+
+        let rows = visible(self.rows(), DISPLAY_MAX_ROWS, f.alternate());
+        let cols = visible(self.cols(), DISPLAY_MAX_COLS, f.alternate());
+        let cells: Vec<Vec<String>> = rows
+            .iter()
+            .map(|&i| {
+                cols.iter()
+                    .map(|&j| match (i, j) {
+                        (Some(i), Some(j)) => format_element(&self[(i, j)], f.precision()),
+                        _ => "...".to_string(),
+                    })
+                    .collect()
+            })
+            .collect();
+        let widths: Vec<usize> = (0..cols.len())
+            .map(|j| cells.iter().map(|row| row[j].chars().count()).max().unwrap_or(0))
+            .collect();
+
+        for (k, row) in cells.iter().enumerate() {
+            if k > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "[")?;
+            for (j, (cell, &width)) in row.iter().zip(&widths).enumerate() {
+                let separator = if j == 0 { "" } else { "  " };
+                write!(f, "{}{:>width$}", separator, cell, width = width)?;
+            }
+            write!(f, "]")?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Display + Clone> Matrix2D<T> {
+    /// 渲染为 LaTeX 的 `bmatrix` 环境。
+    ///
+    /// # 参数
+    ///
+    /// * `precision` - 每个元素的小数位数，为 `None` 时使用元素的默认格式。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::new(vec![vec![1.0, 0.5], vec![-3.0, 4.0]]).unwrap();
+    /// assert_eq!(m.to_latex(None), "\\begin{bmatrix}\n1 & 0.5 \\\\\n-3 & 4\n\\end{bmatrix}");
+    /// assert_eq!(m.to_latex(Some(1)), "\\begin{bmatrix}\n1.0 & 0.5 \\\\\n-3.0 & 4.0\n\\end{bmatrix}");
+    /// ```
+    pub fn to_latex(&self, precision: Option<usize>) -> String {
+        let rows: Vec<String> = (0..self.rows())
+            .map(|i| {
+                let row: Vec<String> = (0..self.cols()).map(|j| format_element(&self[(i, j)], precision)).collect();
+                row.join(" & ")
+            })
+            .collect();
+        if rows.is_empty() {
+            return "\\begin{bmatrix}\n\\end{bmatrix}".to_string();
+        }
+        format!("\\begin{{bmatrix}}\n{}\n\\end{{bmatrix}}", rows.join(" \\\\\n"))
+    }
+}
+
+impl<T: FromStr + Clone> FromStr for Matrix2D<T> {
+    type Err = ParseError;
+
+    /// 解析 MATLAB 风格的矩阵字面量，如 `"[1 2; 3 4]"`。
+    ///
+    /// 同一行的元素以空白或逗号分隔，行与行之间以分号或换行分隔，空行被忽略；
+    /// 外层方括号可以省略。`"[]"` 与空字符串得到 0×0 矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回解析出的矩阵；元素无法解析、各行元素个数不一致或方括号不匹配时，
+    /// 返回指明行号与列号的 `ParseError`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m: Matrix2D = "[1 2; 3, -4.5]".parse().unwrap();
+    /// assert_eq!(m.to_nested(), vec![vec![1.0, 2.0], vec![3.0, -4.5]]);
+    ///
+    /// let error = "[1 2; 3]".parse::<Matrix2D>().unwrap_err();
+    /// assert_eq!((error.line, error.column), (1, 8));
+    /// ```
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut parser = LiteralParser::default();
+        let mut chars = text.chars().peekable();
+        let (mut line, mut column) = (1, 0);
+        let mut opened = None;
+        let mut closed = false;
+        while let Some(ch) = chars.next() {
+            column += 1;
+            if closed && !ch.is_whitespace() {
+                return Err(ParseError::new(line, column, "unexpected character after `]`"));
+            }
+            match ch {
+                '[' if opened.is_none() && parser.is_empty() => opened = Some((line, column)),
+                ']' if opened.is_some() => {
+                    parser.end_row(line, column)?;
+                    closed = true;
+                }
+                '[' | ']' => return Err(ParseError::new(line, column, format!("unexpected `{}`", ch))),
+                ';' => parser.end_row(line, column)?,
+                '\n' => {
+                    parser.end_row(line, column)?;
+                    (line, column) = (line + 1, 0);
+                }
+                ',' => {}
+                _ if ch.is_whitespace() => {}
+                _ => {
+                    let start = column;
+                    let mut token = ch.to_string();
+                    while let Some(&next) = chars.peek() {
+                        if next.is_whitespace() || matches!(next, ',' | ';' | '[' | ']') {
+                            break;
+                        }
+                        token.push(next);
+                        chars.next();
+                        column += 1;
+                    }
+                    let value = token
+                        .parse()
+                        .map_err(|_| ParseError::new(line, start, format!("invalid element `{}`", token)))?;
+                    parser.push(value, line, start);
+                }
+            }
+        }
+        if let (Some((open_line, open_column)), false) = (opened, closed) {
+            return Err(ParseError::new(open_line, open_column, "missing closing `]`"));
+        }
+        parser.end_row(line, column + 1)?;
+        Ok(parser.finish())
+    }
+}
+
+/// 逐个接收元素并按行组装矩阵，同时检查各行长度一致。
+struct LiteralParser<T> {
+    data: Vec<T>,
+    rows: usize,
+    width: Option<usize>,
+    /// 当前行中各元素的位置 `(行号, 列号)`，用于报告多余元素。
+    current: Vec<(usize, usize)>,
+}
+
+impl<T> Default for LiteralParser<T> {
+    fn default() -> Self {
+        LiteralParser { data: Vec::new(), rows: 0, width: None, current: Vec::new() }
+    }
+}
+
+impl<T: Clone> LiteralParser<T> {
+    fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    fn push(&mut self, value: T, line: usize, column: usize) {
+        self.data.push(value);
+        self.current.push((line, column));
+    }
+
+    /// 结束当前行；空行被忽略，`(line, column)` 为行结束的位置，用于报告缺少的元素。
+    fn end_row(&mut self, line: usize, column: usize) -> Result<(), ParseError> {
+        let len = self.current.len();
+        if len == 0 {
+            return Ok(());
+        }
+        let expected = *self.width.get_or_insert(len);
+        if len != expected {
+            let (line, column) = self.current.get(expected).copied().unwrap_or((line, column));
+            return Err(ParseError::new(line, column, format!("row has {} elements, expected {}", len, expected)));
+        }
+        self.rows += 1;
+        self.current.clear();
+        Ok(())
+    }
+
+    fn finish(self) -> Matrix2D<T> {
+        Matrix2D::from_vec(self.rows, self.width.unwrap_or(0), self.data).expect("every row was checked against the width")
+    }
+}
+
+/// 返回需要显示的下标，`None` 表示省略号所在的位置。
+fn visible(len: usize, max: usize, full: bool) -> Vec<Option<usize>> {
+    if full || len <= max {
+        return (0..len).map(Some).collect();
+    }
+    (0..DISPLAY_EDGE_ITEMS)
+        .map(Some)
+        .chain(std::iter::once(None))
+        .chain((len - DISPLAY_EDGE_ITEMS..len).map(Some))
+        .collect()
+}
+
+fn format_element<T: Display>(value: &T, precision: Option<usize>) -> String {
+    match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => value.to_string(),
+    }
+}
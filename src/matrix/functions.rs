@@ -0,0 +1,267 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// Denman–Beavers 平方根迭代允许的最大迭代次数。
+pub const DEFAULT_SQRTM_MAX_ITERATIONS: usize = 100;
+/// 逆缩放平方法求对数时允许的最大开方次数。
+pub const DEFAULT_LOGM_MAX_SQUARE_ROOTS: usize = 64;
+
+/// 各阶 Padé 近似在双精度下可直接使用的 1-范数上界（Higham, 2005）。
+const PADE_THETA: [(usize, f64); 5] = [
+    (3, 1.495585217958292e-2),
+    (5, 2.53939833006323e-1),
+    (7, 9.504178996162932e-1),
+    (9, 2.097847961257068e0),
+    (13, 5.371920351148152e0),
+];
+
+/// 13 阶 Padé 近似的系数 `b_0..b_13`。
+const PADE_13: [f64; 14] = [
+    64764752532480000.0,
+    32382376266240000.0,
+    7771770303897600.0,
+    1187353796428800.0,
+    129060195264000.0,
+    10559470521600.0,
+    670442572800.0,
+    33522128640.0,
+    1323241920.0,
+    40840800.0,
+    960960.0,
+    16380.0,
+    182.0,
+    1.0,
+];
+
+/// [0, 1] 上 8 点 Gauss–Legendre 求积的节点与权重，用于 `log(I + X)` 的 Padé 近似。
+const GAUSS_LEGENDRE_8: [(f64, f64); 8] = [
+    (0.019855071751231856, 0.05061426814518813),
+    (0.10166676129318664, 0.11119051722668724),
+    (0.2372337950418355, 0.15685332293894363),
+    (0.4082826787521751, 0.181341891689181),
+    (0.591717321247825, 0.181341891689181),
+    (0.7627662049581645, 0.15685332293894363),
+    (0.8983332387068134, 0.11119051722668724),
+    (0.9801449282487681, 0.05061426814518813),
+];
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 计算矩阵指数 `exp(A) = Σ A^k / k!`。
+    ///
+    /// 使用 Higham (2005) 的缩放与平方法：根据 1-范数选取 3、5、7、9 或 13 阶 Padé 近似，
+    /// 范数过大时先将矩阵缩小 `2^s` 倍，再把结果平方 `s` 次。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `exp(A)`；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// // 旋转生成元的指数是旋转矩阵
+    /// let theta = 0.5_f64;
+    /// let generator = Matrix2D::new(vec![vec![0.0, -theta], vec![theta, 0.0]]).unwrap();
+    /// let rotation = generator.expm().unwrap();
+    /// assert!((rotation[(0, 0)] - theta.cos()).abs() < 1e-14);
+    /// assert!((rotation[(1, 0)] - theta.sin()).abs() < 1e-14);
+    /// ```
+    pub fn expm(&self) -> Result<Matrix2D, MatrixError> {
+        let n = self.square_order()?;
+        let norm = self.norm_1();
+
+        for &(m, theta) in &PADE_THETA[..4] {
+            if norm <= theta {
+                let (u, v) = pade_low_order(self, m);
+                return pade_quotient(&u, &v);
+            }
+        }
+
+        let s = if norm > PADE_THETA[4].1 { (norm / PADE_THETA[4].1).log2().ceil() as i32 } else { 0 };
+        let a = self * 0.5_f64.powi(s);
+        let b = &PADE_13;
+        let identity = Matrix2D::eye(n);
+        let a2 = &a * &a;
+        let a4 = &a2 * &a2;
+        let a6 = &a2 * &a4;
+
+        let u_inner = &a6 * &(&a6 * b[13] + &a4 * b[11] + &a2 * b[9]) + &a6 * b[7] + &a4 * b[5] + &a2 * b[3] + &identity * b[1];
+        let u = &a * &u_inner;
+        let v = &a6 * &(&a6 * b[12] + &a4 * b[10] + &a2 * b[8]) + &a6 * b[6] + &a4 * b[4] + &a2 * b[2] + &identity * b[0];
+
+        let mut result = pade_quotient(&u, &v)?;
+        for _ in 0..s {
+            result = &result * &result;
+        }
+        Ok(result)
+    }
+
+    /// 使用带行列式缩放的 Denman–Beavers 迭代计算主平方根 `A^{1/2}`。
+    ///
+    /// 迭代 `Y ← (μY + (μZ)⁻¹) / 2`、`Z ← (μZ + (μY)⁻¹) / 2`，`Y₀ = A`、`Z₀ = I`，
+    /// `Y` 二次收敛到 `A^{1/2}`，`Z` 收敛到 `A^{-1/2}`。
+    ///
+    /// # 返回
+    ///
+    /// 返回满足 `X * X = A` 且特征值实部为正的主平方根；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；
+    /// 迭代中出现奇异矩阵时返回 `MatrixError::Singular`；矩阵有负实特征值（不存在实主平方根）
+    /// 或迭代未收敛时返回 `MatrixError::NotConverged`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![5.0, 4.0], vec![4.0, 5.0]]).unwrap();
+    /// let root = a.sqrtm().unwrap();
+    /// assert!((root[(0, 0)] - 2.0).abs() < 1e-12 && (root[(0, 1)] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn sqrtm(&self) -> Result<Matrix2D, MatrixError> {
+        let n = self.square_order()?;
+        let mut y = self.clone();
+        let mut z = Matrix2D::eye(n);
+
+        for _ in 0..DEFAULT_SQRTM_MAX_ITERATIONS {
+            let y_lu = y.lu()?;
+            let z_lu = z.lu()?;
+            let det = (y_lu.determinant() * z_lu.determinant()).abs();
+            let mu = if det.is_finite() && det > 0.0 { det.powf(-0.5 / n as f64) } else { 1.0 };
+
+            let y_inverse = y_lu.inverse()?;
+            let z_inverse = z_lu.inverse()?;
+            let y_next = (&y * mu + &z_inverse * (1.0 / mu)) * 0.5;
+            let z_next = (&z * mu + &y_inverse * (1.0 / mu)) * 0.5;
+
+            // 二次收敛：步长降到 sqrt(ε) 量级时，新迭代值的误差已在 ε 量级
+            let change = (&y_next - &y).norm_frobenius();
+            let scale = y_next.norm_frobenius();
+            if !scale.is_finite() {
+                break;
+            }
+            y = y_next;
+            z = z_next;
+            if change <= f64::EPSILON.sqrt() * scale {
+                return Ok(y);
+            }
+        }
+        Err(MatrixError::NotConverged { iterations: DEFAULT_SQRTM_MAX_ITERATIONS })
+    }
+
+    /// 使用逆缩放与平方法计算主对数 `log(A)`。
+    ///
+    /// 反复开平方直到 `‖A^{1/2^k} - I‖₁ ≤ 1/4`，再用 8 点 Gauss–Legendre 求积给出的 Padé 近似
+    /// 计算 `log(I + X)`，最后乘以 `2^k`。
+    ///
+    /// # 返回
+    ///
+    /// 返回满足 `exp(X) = A` 且特征值虚部位于 (-π, π) 的主对数；如果矩阵不是方阵，返回
+    /// `MatrixError::NotSquare`；矩阵奇异时返回 `MatrixError::Singular`；矩阵有负实特征值
+    /// （不存在实主对数）或开方次数超过上限时返回 `MatrixError::NotConverged`。
+    pub fn logm(&self) -> Result<Matrix2D, MatrixError> {
+        let n = self.square_order()?;
+        let identity = Matrix2D::eye(n);
+        let mut root = self.clone();
+        let mut square_roots = 0;
+        while (&root - &identity).norm_1() > 0.25 {
+            if square_roots == DEFAULT_LOGM_MAX_SQUARE_ROOTS {
+                return Err(MatrixError::NotConverged { iterations: square_roots });
+            }
+            root = root.sqrtm()?;
+            square_roots += 1;
+        }
+
+        // log(I + X) ≈ Σ w_j * X * (I + x_j * X)⁻¹
+        let x = &root - &identity;
+        let mut log = Matrix2D::zeros(n, n);
+        for &(node, weight) in &GAUSS_LEGENDRE_8 {
+            let term = (&identity + &x * node).lu()?.solve_matrix(&x)?;
+            log += term * weight;
+        }
+        Ok(log * 2.0_f64.powi(square_roots as i32))
+    }
+
+    /// 使用反复平方法计算整数次幂 `A^n`，只需 O(log |n|) 次矩阵乘法。
+    ///
+    /// # 参数
+    ///
+    /// * `n` - 幂次；为负数时先求逆，`n = 0` 时返回单位矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `A^n`；如果矩阵不是方阵，返回 `MatrixError::NotSquare`；`n < 0` 且矩阵奇异时返回
+    /// `MatrixError::Singular`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// // Fibonacci 矩阵
+    /// let fib = Matrix2D::new(vec![vec![1.0, 1.0], vec![1.0, 0.0]]).unwrap();
+    /// assert_eq!(fib.powi(10).unwrap()[(0, 1)], 55.0);
+    /// ```
+    pub fn powi(&self, n: i32) -> Result<Matrix2D, MatrixError> {
+        let order = self.square_order()?;
+        let mut base = if n < 0 { self.inverse()? } else { self.clone() };
+        let mut exponent = n.unsigned_abs();
+        let mut result = Matrix2D::eye(order);
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = &base * &base;
+            }
+        }
+        Ok(result)
+    }
+
+    /// 检查矩阵为方阵并返回其阶数。
+    fn square_order(&self) -> Result<usize, MatrixError> {
+        let (rows, cols) = self.shape();
+        if rows != cols {
+            return Err(MatrixError::NotSquare { rows, cols });
+        }
+        Ok(rows)
+    }
+}
+
+/// 计算 3、5、7、9 阶 Padé 近似的奇部 `U` 与偶部 `V`，`exp(A) ≈ (V - U)⁻¹ (V + U)`。
+fn pade_low_order(a: &Matrix2D, m: usize) -> (Matrix2D, Matrix2D) {
+    let b: &[f64] = match m {
+        3 => &[120.0, 60.0, 12.0, 1.0],
+        5 => &[30240.0, 15120.0, 3360.0, 420.0, 30.0, 1.0],
+        7 => &[17297280.0, 8648640.0, 1995840.0, 277200.0, 25200.0, 1512.0, 56.0, 1.0],
+        _ => &[
+            17643225600.0,
+            8821612800.0,
+            2075673600.0,
+            302702400.0,
+            30270240.0,
+            2162160.0,
+            110880.0,
+            3960.0,
+            90.0,
+            1.0,
+        ],
+    };
+    let n = a.rows();
+    let a2 = a * a;
+    let mut power = Matrix2D::eye(n);
+    let mut odd = &power * b[1];
+    let mut even = &power * b[0];
+    for k in (2..=m).step_by(2) {
+        power = &power * &a2;
+        even += &power * b[k];
+        odd += &power * b[k + 1];
+    }
+    (a * &odd, even)
+}
+
+/// 求解 `(V - U) * R = V + U`。
+fn pade_quotient(u: &Matrix2D, v: &Matrix2D) -> Result<Matrix2D, MatrixError> {
+    (v - u).lu()?.solve_matrix(&(v + u))
+}
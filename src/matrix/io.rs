@@ -0,0 +1,505 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::matrix::sparse::coo::CooMatrix;
+use crate::matrix::sparse::csr::CsrMatrix;
+
+/// 读取坐标格式时按声明的条目数预分配的容量上限。
+const PREALLOCATED_ENTRIES: usize = 1 << 20;
+
+/// 解析文本格式的矩阵时出现的错误，行号与列号均从 1 开始计数。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// 出错的行号。
+    pub line: usize,
+    /// 出错字段在该行中的起始列号（按字符计）。
+    pub column: usize,
+    /// 错误描述。
+    pub message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError { line, column, message: message.into() }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}, column {}: {}", self.line, self.column, self.message)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for io::Error {
+    /// 转换为 `io::ErrorKind::InvalidData`，可通过 `get_ref` 取回原始的 `ParseError`。
+    fn from(error: ParseError) -> Self {
+        io::Error::new(io::ErrorKind::InvalidData, error)
+    }
+}
+
+/// 从 Matrix Market 文件读出的矩阵：坐标格式得到稀疏矩阵，数组格式得到稠密矩阵。
+#[derive(Debug, Clone, PartialEq)]
+pub enum MatrixMarketData {
+    /// `array` 格式的稠密矩阵。
+    Dense(Matrix2D),
+    /// `coordinate` 格式的稀疏矩阵，已转换为 CSR 存储。
+    Sparse(CsrMatrix),
+}
+
+#[allow(dead_code)]
+impl MatrixMarketData {
+    /// 返回矩阵的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        match self {
+            MatrixMarketData::Dense(matrix) => matrix.shape(),
+            MatrixMarketData::Sparse(matrix) => matrix.shape(),
+        }
+    }
+
+    /// 转换为稠密矩阵。
+    pub fn to_dense(&self) -> Matrix2D {
+        match self {
+            MatrixMarketData::Dense(matrix) => matrix.clone(),
+            MatrixMarketData::Sparse(matrix) => matrix.to_dense(),
+        }
+    }
+}
+
+/// 分隔文本（CSV/TSV）的读写选项。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DelimitedOptions {
+    /// 字段分隔符。
+    pub delimiter: char,
+    /// 读取时是否把第一个非空行作为表头。
+    pub has_header: bool,
+}
+
+#[allow(dead_code)]
+impl DelimitedOptions {
+    /// 逗号分隔、无表头。
+    pub const fn csv() -> DelimitedOptions {
+        DelimitedOptions { delimiter: ',', has_header: false }
+    }
+
+    /// 制表符分隔、无表头。
+    pub const fn tsv() -> DelimitedOptions {
+        DelimitedOptions { delimiter: '\t', has_header: false }
+    }
+}
+
+impl Default for DelimitedOptions {
+    fn default() -> Self {
+        DelimitedOptions::csv()
+    }
+}
+
+/// 从分隔文本读出的表格。
+#[derive(Debug, Clone, PartialEq)]
+pub struct DelimitedTable {
+    /// 表头中的列名，仅当 `has_header` 为真时存在。
+    pub header: Option<Vec<String>>,
+    /// 数据部分。
+    pub matrix: Matrix2D,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Field {
+    Real,
+    Integer,
+    Pattern,
+}
+
+/// 解析 Matrix Market（`.mtx`）格式的文本。
+///
+/// 支持 `coordinate` 与 `array` 两种格式、`real`、`integer` 与 `pattern` 三种元素类型，
+/// 以及 `general` 与 `symmetric` 两种对称性。对称矩阵只存储下三角部分，读取时会补全上三角；
+/// `pattern` 矩阵的非零元素取值为 1。坐标格式自动转换为 CSR 稀疏存储，重复坐标的值相加。
+///
+/// # 返回
+///
+/// 返回读出的矩阵；格式不合法时返回指明行号与列号的 `ParseError`。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::io::{parse_matrix_market, MatrixMarketData};
+///
+/// let text = "%%MatrixMarket matrix coordinate real symmetric\n% 注释\n3 3 2\n1 1 4.0\n3 1 -1.5\n";
+/// let MatrixMarketData::Sparse(csr) = parse_matrix_market(text).unwrap() else { panic!() };
+/// assert_eq!(csr.get(0, 2), -1.5);
+/// assert_eq!(csr.nnz(), 3);
+///
+/// let error = parse_matrix_market("%%MatrixMarket matrix array real general\n2 1\n1.0\nx\n").unwrap_err();
+/// assert_eq!((error.line, error.column), (4, 1));
+/// ```
+pub fn parse_matrix_market(text: &str) -> Result<MatrixMarketData, ParseError> {
+    let mut lines = text.lines().enumerate().map(|(i, line)| (i + 1, line));
+    let (format, field, symmetric) = match lines.next() {
+        Some((_, header)) => parse_header(header)?,
+        None => return Err(ParseError::new(1, 1, "missing %%MatrixMarket header")),
+    };
+
+    let last_line = text.lines().count();
+    let mut data = lines.filter(|(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('%'));
+    let Some((size_line, size_text)) = data.next() else {
+        return Err(ParseError::new(last_line + 1, 1, "missing size line"));
+    };
+    let size_fields = tokenize(size_text);
+    let expected = if format == Format::Coordinate { 3 } else { 2 };
+    check_count(size_line, size_text, &size_fields, expected, "size")?;
+    let mut size = Vec::with_capacity(expected);
+    for &(column, token) in &size_fields {
+        size.push(parse_index(token, size_line, column, "size")?);
+    }
+    let (rows, cols) = (size[0], size[1]);
+    if symmetric && rows != cols {
+        return Err(ParseError::new(size_line, 1, format!("symmetric matrix must be square, got {}x{}", rows, cols)));
+    }
+
+    let Some(cells) = rows.checked_mul(cols) else {
+        return Err(ParseError::new(size_line, 1, format!("matrix size {}x{} is too large", rows, cols)));
+    };
+
+    match format {
+        Format::Coordinate => {
+            let nnz = size[2];
+            if nnz > cells {
+                let message = format!("{} entries do not fit in a {}x{} matrix", nnz, rows, cols);
+                return Err(ParseError::new(size_line, size_fields[2].0, message));
+            }
+            // 声明的条目数不可信，预分配的容量有上限，超出部分由 push 按需扩容
+            let capacity = if symmetric { nnz.saturating_mul(2) } else { nnz };
+            let mut coo = CooMatrix::with_capacity(rows, cols, capacity.min(PREALLOCATED_ENTRIES));
+            let mut count = 0;
+            for (line, text) in data {
+                if count == nnz {
+                    return Err(ParseError::new(line, 1, format!("more than the declared {} entries", nnz)));
+                }
+                let fields = tokenize(text);
+                check_count(line, text, &fields, if field == Field::Pattern { 2 } else { 3 }, "entry")?;
+                let i = parse_position(fields[0], rows, line, "row")?;
+                let j = parse_position(fields[1], cols, line, "column")?;
+                if symmetric && j > i {
+                    return Err(ParseError::new(line, fields[1].0, "entry above the diagonal in a symmetric matrix"));
+                }
+                let value = match field {
+                    Field::Pattern => 1.0,
+                    _ => parse_value(fields[2].1, field, line, fields[2].0)?,
+                };
+                coo.push(i, j, value).expect("position was checked against the shape");
+                if symmetric && i != j {
+                    coo.push(j, i, value).expect("position was checked against the shape");
+                }
+                count += 1;
+            }
+            if count < nnz {
+                return Err(ParseError::new(last_line + 1, 1, format!("expected {} entries, found {}", nnz, count)));
+            }
+            Ok(MatrixMarketData::Sparse(coo.to_csr()))
+        }
+        Format::Array => {
+            // 按列优先顺序给出；对称矩阵只给出每列主对角线及以下的部分，共 n(n+1)/2 个值
+            let total = if symmetric { cells / 2 + rows.div_ceil(2) } else { cells };
+            let mut matrix = Matrix2D::zeros(rows, cols);
+            let (mut i, mut j) = (0, 0);
+            let mut count = 0;
+            for (line, text) in data {
+                for (column, token) in tokenize(text) {
+                    if count == total {
+                        return Err(ParseError::new(line, column, format!("more than the declared {} values", total)));
+                    }
+                    let value = parse_value(token, field, line, column)?;
+                    matrix[(i, j)] = value;
+                    if symmetric {
+                        matrix[(j, i)] = value;
+                    }
+                    count += 1;
+                    i += 1;
+                    if i == rows {
+                        j += 1;
+                        i = if symmetric { j } else { 0 };
+                    }
+                }
+            }
+            if count < total {
+                return Err(ParseError::new(last_line + 1, 1, format!("expected {} values, found {}", total, count)));
+            }
+            Ok(MatrixMarketData::Dense(matrix))
+        }
+    }
+}
+
+/// 从实现了 `Read` 的数据源读取 Matrix Market 格式的矩阵，规则同 [`parse_matrix_market`]。
+///
+/// # 返回
+///
+/// 返回读出的矩阵；读取失败时返回底层的 I/O 错误，格式不合法时返回
+/// `io::ErrorKind::InvalidData`，其内部错误为 `ParseError`。
+pub fn read_matrix_market<R: Read>(mut reader: R) -> io::Result<MatrixMarketData> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    Ok(parse_matrix_market(&text)?)
+}
+
+/// 解析 CSV、TSV 等分隔文本，每个非空行为矩阵的一行。
+///
+/// 字段两侧的空白会被忽略；表头中的列名可以用双引号包围。
+///
+/// # 返回
+///
+/// 返回表头与数据矩阵；各行字段数不一致、字段为空或不是数字时，返回指明行号与列号的 `ParseError`。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::io::{parse_delimited, DelimitedOptions};
+///
+/// let options = DelimitedOptions { has_header: true, ..DelimitedOptions::csv() };
+/// let table = parse_delimited("x, y\n1, 2\n3, 4.5\n", &options).unwrap();
+/// assert_eq!(table.header.unwrap(), vec!["x", "y"]);
+/// assert_eq!(table.matrix.to_nested(), vec![vec![1.0, 2.0], vec![3.0, 4.5]]);
+///
+/// let error = parse_delimited("1,2\n3,abc\n", &DelimitedOptions::csv()).unwrap_err();
+/// assert_eq!((error.line, error.column), (2, 3));
+/// ```
+pub fn parse_delimited(text: &str, options: &DelimitedOptions) -> Result<DelimitedTable, ParseError> {
+    let mut header = None;
+    let mut width = None;
+    let mut rows = 0;
+    let mut data = Vec::new();
+    for (index, text) in text.lines().enumerate().filter(|(_, line)| !line.trim().is_empty()) {
+        let line = index + 1;
+        let fields = split_delimited(text, options.delimiter);
+        if options.has_header && header.is_none() {
+            let names = fields.iter().map(|&(_, name)| name.trim_matches('"').to_string()).collect::<Vec<_>>();
+            width = Some(names.len());
+            header = Some(names);
+            continue;
+        }
+
+        let expected = *width.get_or_insert(fields.len());
+        check_count(line, text, &fields, expected, "row")?;
+        for &(column, token) in &fields {
+            if token.is_empty() {
+                return Err(ParseError::new(line, column, "empty field"));
+            }
+            data.push(parse_value(token, Field::Real, line, column)?);
+        }
+        rows += 1;
+    }
+    let matrix = Matrix2D::from_vec(rows, width.unwrap_or(0), data).expect("every row was checked against the width");
+    Ok(DelimitedTable { header, matrix })
+}
+
+/// 从实现了 `Read` 的数据源读取分隔文本，规则同 [`parse_delimited`]。
+///
+/// # 返回
+///
+/// 读取失败时返回底层的 I/O 错误，格式不合法时返回 `io::ErrorKind::InvalidData`，
+/// 其内部错误为 `ParseError`。
+pub fn read_delimited<R: Read>(mut reader: R, options: &DelimitedOptions) -> io::Result<DelimitedTable> {
+    let mut text = String::new();
+    reader.read_to_string(&mut text)?;
+    Ok(parse_delimited(&text, options)?)
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 以 Matrix Market `array real general` 格式写出矩阵，元素按列优先顺序排列。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::io::{parse_matrix_market, MatrixMarketData};
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 0.1]]).unwrap();
+    /// let mut buffer = Vec::new();
+    /// m.write_matrix_market(&mut buffer).unwrap();
+    /// let text = String::from_utf8(buffer).unwrap();
+    /// assert_eq!(text, "%%MatrixMarket matrix array real general\n2 2\n1\n3\n2\n0.1\n");
+    /// assert_eq!(parse_matrix_market(&text).unwrap(), MatrixMarketData::Dense(m));
+    /// ```
+    pub fn write_matrix_market<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        writeln!(writer, "%%MatrixMarket matrix array real general")?;
+        writeln!(writer, "{} {}", self.rows(), self.cols())?;
+        for j in 0..self.cols() {
+            for i in 0..self.rows() {
+                writeln!(writer, "{}", format_value(self[(i, j)]))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 以分隔文本格式写出矩阵，每行一条记录。
+    ///
+    /// # 参数
+    ///
+    /// * `writer` - 输出目标。
+    /// * `options` - 使用其中的分隔符；`has_header` 只影响读取。
+    /// * `header` - 可选的列名，给出时先写出一行表头。
+    ///
+    /// # 返回
+    ///
+    /// 写入失败时返回 I/O 错误；列名个数与列数不符时返回 `io::ErrorKind::InvalidInput`。
+    pub fn write_delimited<W: Write>(&self, mut writer: W, options: &DelimitedOptions, header: Option<&[&str]>) -> io::Result<()> {
+        let delimiter = options.delimiter.to_string();
+        if let Some(names) = header {
+            if names.len() != self.cols() {
+                let message = format!("header has {} names but the matrix has {} columns", names.len(), self.cols());
+                return Err(io::Error::new(io::ErrorKind::InvalidInput, message));
+            }
+            writeln!(writer, "{}", names.join(&delimiter))?;
+        }
+        for i in 0..self.rows() {
+            let row: Vec<String> = (0..self.cols()).map(|j| format_value(self[(i, j)])).collect();
+            writeln!(writer, "{}", row.join(&delimiter))?;
+        }
+        Ok(())
+    }
+}
+
+#[allow(dead_code)]
+impl CsrMatrix {
+    /// 以 Matrix Market `coordinate real general` 格式写出稀疏矩阵，只写出已存储的元素。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::sparse::coo::CooMatrix;
+    ///
+    /// let csr = CooMatrix::from_triplets(2, 3, &[(0, 2, 5.0), (1, 0, -1.0)]).unwrap().to_csr();
+    /// let mut buffer = Vec::new();
+    /// csr.write_matrix_market(&mut buffer).unwrap();
+    /// assert_eq!(
+    ///     String::from_utf8(buffer).unwrap(),
+    ///     "%%MatrixMarket matrix coordinate real general\n2 3 2\n1 3 5\n2 1 -1\n"
+    /// );
+    /// ```
+    pub fn write_matrix_market<W: Write>(&self, mut writer: W) -> io::Result<()> {
+        let (rows, cols) = self.shape();
+        writeln!(writer, "%%MatrixMarket matrix coordinate real general")?;
+        writeln!(writer, "{} {} {}", rows, cols, self.nnz())?;
+        for i in 0..rows {
+            let (indices, values) = self.row(i);
+            for (&j, &value) in indices.iter().zip(values) {
+                writeln!(writer, "{} {} {}", i + 1, j + 1, format_value(value))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 解析 `%%MatrixMarket matrix <格式> <元素类型> <对称性>` 头部，返回格式、元素类型与是否对称。
+fn parse_header(text: &str) -> Result<(Format, Field, bool), ParseError> {
+    let fields = tokenize(text);
+    if fields.first().map(|&(_, banner)| banner) != Some("%%MatrixMarket") {
+        return Err(ParseError::new(1, 1, "missing %%MatrixMarket header"));
+    }
+    check_count(1, text, &fields, 5, "header")?;
+
+    let keyword = |k: usize| fields[k].1.to_ascii_lowercase();
+    let unsupported = |k: usize, what: &str| ParseError::new(1, fields[k].0, format!("unsupported {} `{}`", what, fields[k].1));
+    if keyword(1) != "matrix" {
+        return Err(unsupported(1, "object"));
+    }
+    let format = match keyword(2).as_str() {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        _ => return Err(unsupported(2, "format")),
+    };
+    let field = match keyword(3).as_str() {
+        "real" => Field::Real,
+        "integer" => Field::Integer,
+        "pattern" if format == Format::Coordinate => Field::Pattern,
+        _ => return Err(unsupported(3, "field")),
+    };
+    let symmetric = match keyword(4).as_str() {
+        "general" => false,
+        "symmetric" => true,
+        _ => return Err(unsupported(4, "symmetry")),
+    };
+    Ok((format, field, symmetric))
+}
+
+/// 按空白切分一行，返回每个字段的起始列号（从 1 开始，按字符计）与内容。
+fn tokenize(line: &str) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut start = None;
+    for (column, (offset, ch)) in line.char_indices().chain(std::iter::once((line.len(), ' '))).enumerate() {
+        match (ch.is_whitespace(), start) {
+            (false, None) => start = Some((column + 1, offset)),
+            (true, Some((start_column, start_offset))) => {
+                fields.push((start_column, &line[start_offset..offset]));
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    fields
+}
+
+/// 按分隔符切分一行，返回每个字段去除两侧空白后的起始列号与内容；空白字段的列号指向分隔符之后。
+fn split_delimited(line: &str, delimiter: char) -> Vec<(usize, &str)> {
+    let mut fields = Vec::new();
+    let mut column = 1;
+    for field in line.split(delimiter) {
+        let trimmed = field.trim_start();
+        let leading = if trimmed.is_empty() { 0 } else { field.len() - trimmed.len() };
+        fields.push((column + field[..leading].chars().count(), trimmed.trim_end()));
+        column += field.chars().count() + 1;
+    }
+    fields
+}
+
+/// 检查字段个数，多出时指向第一个多余字段，缺少时指向行尾之后。
+fn check_count(line: usize, text: &str, fields: &[(usize, &str)], expected: usize, what: &str) -> Result<(), ParseError> {
+    if fields.len() == expected {
+        return Ok(());
+    }
+    let column = fields.get(expected).map_or(text.chars().count() + 1, |&(column, _)| column);
+    Err(ParseError::new(line, column, format!("{} expects {} fields, found {}", what, expected, fields.len())))
+}
+
+fn parse_index(token: &str, line: usize, column: usize, what: &str) -> Result<usize, ParseError> {
+    token
+        .parse()
+        .map_err(|_| ParseError::new(line, column, format!("invalid {} `{}`", what, token)))
+}
+
+/// 解析从 1 开始的行号或列号，并转换为从 0 开始的下标。
+fn parse_position((column, token): (usize, &str), len: usize, line: usize, what: &str) -> Result<usize, ParseError> {
+    match parse_index(token, line, column, what)? {
+        index @ 1.. if index <= len => Ok(index - 1),
+        index => Err(ParseError::new(line, column, format!("{} index {} is outside 1..={}", what, index, len))),
+    }
+}
+
+fn parse_value(token: &str, field: Field, line: usize, column: usize) -> Result<f64, ParseError> {
+    let value = match field {
+        Field::Integer => token.parse::<i64>().map(|value| value as f64).ok(),
+        _ => token.parse::<f64>().ok(),
+    };
+    let kind = if field == Field::Integer { "integer" } else { "real" };
+    value.ok_or_else(|| ParseError::new(line, column, format!("invalid {} value `{}`", kind, token)))
+}
+
+/// 格式化元素：常见量级使用十进制写法，过大或过小的数使用科学计数法；两种写法都能精确还原。
+fn format_value(value: f64) -> String {
+    let magnitude = value.abs();
+    if value == 0.0 || (1e-4..1e16).contains(&magnitude) || !value.is_finite() {
+        format!("{}", value)
+    } else {
+        format!("{:e}", value)
+    }
+}
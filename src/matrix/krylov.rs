@@ -0,0 +1,599 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::matrix::sparse::csc::CscMatrix;
+use crate::matrix::sparse::csr::CsrMatrix;
+
+/// Krylov 迭代默认的相对残差容差。
+pub const DEFAULT_KRYLOV_TOLERANCE: f64 = 1e-10;
+/// Krylov 迭代默认的最大迭代次数。
+pub const DEFAULT_KRYLOV_MAX_ITERATIONS: usize = 1000;
+/// GMRES 默认的重启长度。
+pub const DEFAULT_GMRES_RESTART: usize = 30;
+
+/// 抽象线性算子 `x ↦ A * x`。
+///
+/// 迭代法只需要矩阵与向量的乘积，因此稠密矩阵、稀疏矩阵以及以闭包表示的无矩阵算子
+/// 都可以通过实现该 trait 交给求解器使用。
+pub trait LinearOperator {
+    /// 返回算子的形状 `(行数, 列数)`。
+    fn shape(&self) -> (usize, usize);
+
+    /// 计算 `A * x`，调用方保证 `x` 的长度等于列数。
+    fn apply(&self, x: &[f64]) -> Vec<f64>;
+}
+
+impl LinearOperator for Matrix2D {
+    fn shape(&self) -> (usize, usize) {
+        Matrix2D::shape(self)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.try_mul_vector(x).expect("Vector length must match the number of columns.")
+    }
+}
+
+impl LinearOperator for CsrMatrix {
+    fn shape(&self) -> (usize, usize) {
+        CsrMatrix::shape(self)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vector(x).expect("Vector length must match the number of columns.")
+    }
+}
+
+impl LinearOperator for CscMatrix {
+    fn shape(&self) -> (usize, usize) {
+        CscMatrix::shape(self)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        self.mul_vector(x).expect("Vector length must match the number of columns.")
+    }
+}
+
+impl<T: LinearOperator + ?Sized> LinearOperator for &T {
+    fn shape(&self) -> (usize, usize) {
+        (**self).shape()
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        (**self).apply(x)
+    }
+}
+
+/// 以闭包表示的 n×n 无矩阵线性算子。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::krylov::{conjugate_gradient, FnOperator, KrylovOptions};
+///
+/// // 一维 Laplace 算子 tridiag(-1, 2, -1)，无需显式构造矩阵
+/// let n = 50;
+/// let laplace = FnOperator::new(n, |x: &[f64]| {
+///     (0..x.len())
+///         .map(|i| {
+///             let left = if i > 0 { x[i - 1] } else { 0.0 };
+///             let right = if i + 1 < x.len() { x[i + 1] } else { 0.0 };
+///             2.0 * x[i] - left - right
+///         })
+///         .collect()
+/// });
+/// let report = conjugate_gradient(&laplace, &vec![1.0; n], &KrylovOptions::default(), None).unwrap();
+/// assert!(report.converged);
+/// ```
+pub struct FnOperator<F> {
+    size: usize,
+    f: F,
+}
+
+impl<F> FnOperator<F>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    /// 创建一个 `size`×`size` 的算子，`f(x)` 返回 `A * x`。
+    pub fn new(size: usize, f: F) -> Self {
+        FnOperator { size, f }
+    }
+}
+
+impl<F> LinearOperator for FnOperator<F>
+where
+    F: Fn(&[f64]) -> Vec<f64>,
+{
+    fn shape(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply(&self, x: &[f64]) -> Vec<f64> {
+        (self.f)(x)
+    }
+}
+
+/// 预条件子 `M ≈ A`，迭代中用 `M⁻¹ * r` 代替残差以加速收敛。
+pub trait Preconditioner {
+    /// 计算 `M⁻¹ * r`。
+    fn apply(&self, r: &[f64]) -> Vec<f64>;
+}
+
+/// Jacobi（对角）预条件子，`M = diag(A)`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct JacobiPreconditioner {
+    inverse_diagonal: Vec<f64>,
+}
+
+impl JacobiPreconditioner {
+    /// 由矩阵的对角元创建 Jacobi 预条件子。
+    ///
+    /// # 返回
+    ///
+    /// 如果某个对角元为零，返回 `MatrixError::Singular`，`pivot` 为该对角元的下标。
+    pub fn new(diagonal: &[f64]) -> Result<Self, MatrixError> {
+        if let Some(pivot) = diagonal.iter().position(|&d| d == 0.0) {
+            return Err(MatrixError::Singular { pivot });
+        }
+        Ok(JacobiPreconditioner { inverse_diagonal: diagonal.iter().map(|d| 1.0 / d).collect() })
+    }
+
+    /// 由稠密方阵的对角元创建 Jacobi 预条件子。
+    pub fn from_matrix(a: &Matrix2D) -> Result<Self, MatrixError> {
+        let n = square_order(a.shape())?;
+        JacobiPreconditioner::new(&(0..n).map(|i| a[(i, i)]).collect::<Vec<f64>>())
+    }
+
+    /// 由 CSR 方阵的对角元创建 Jacobi 预条件子。
+    pub fn from_csr(a: &CsrMatrix) -> Result<Self, MatrixError> {
+        let n = square_order(a.shape())?;
+        JacobiPreconditioner::new(&(0..n).map(|i| a.get(i, i)).collect::<Vec<f64>>())
+    }
+}
+
+impl Preconditioner for JacobiPreconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        r.iter().zip(&self.inverse_diagonal).map(|(r, d)| r * d).collect()
+    }
+}
+
+/// 零填充不完全 LU 分解（ILU(0)）预条件子。
+///
+/// 在原矩阵的稀疏模式上做 LU 分解，舍弃模式之外的填充元，`M = L * U`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ilu0Preconditioner {
+    /// 原矩阵的 CSR 行指针。
+    indptr: Vec<usize>,
+    /// 原矩阵的 CSR 列下标。
+    indices: Vec<usize>,
+    /// 按原稀疏模式排列的因子值，严格下三角部分为单位下三角 `L`，其余为 `U`。
+    values: Vec<f64>,
+    /// 每行对角元在 `values` 中的位置。
+    diagonal: Vec<usize>,
+}
+
+impl Ilu0Preconditioner {
+    /// 对 CSR 方阵做 ILU(0) 分解。
+    ///
+    /// # 返回
+    ///
+    /// 如果矩阵不是方阵，返回 `MatrixError::NotSquare`；如果某行缺少对角元或消元中出现零主元，
+    /// 返回 `MatrixError::Singular`，`pivot` 为该行的下标。
+    pub fn new(a: &CsrMatrix) -> Result<Self, MatrixError> {
+        let n = square_order(a.shape())?;
+        let (indptr, indices) = (a.indptr(), a.indices());
+        let mut values = a.values().to_vec();
+
+        let mut diagonal = Vec::with_capacity(n);
+        for i in 0..n {
+            let row = &indices[indptr[i]..indptr[i + 1]];
+            match row.binary_search(&i) {
+                Ok(k) => diagonal.push(indptr[i] + k),
+                Err(_) => return Err(MatrixError::Singular { pivot: i }),
+            }
+        }
+
+        // IKJ 形式的消元，只更新原稀疏模式中已存在的位置
+        for i in 0..n {
+            for p in indptr[i]..diagonal[i] {
+                let k = indices[p];
+                let pivot = values[diagonal[k]];
+                if pivot == 0.0 {
+                    return Err(MatrixError::Singular { pivot: k });
+                }
+                values[p] /= pivot;
+                let l_ik = values[p];
+                for q in diagonal[k] + 1..indptr[k + 1] {
+                    let j = indices[q];
+                    if let Ok(offset) = indices[indptr[i]..indptr[i + 1]].binary_search(&j) {
+                        values[indptr[i] + offset] -= l_ik * values[q];
+                    }
+                }
+            }
+            if values[diagonal[i]] == 0.0 {
+                return Err(MatrixError::Singular { pivot: i });
+            }
+        }
+
+        Ok(Ilu0Preconditioner { indptr: indptr.to_vec(), indices: indices.to_vec(), values, diagonal })
+    }
+}
+
+impl Preconditioner for Ilu0Preconditioner {
+    fn apply(&self, r: &[f64]) -> Vec<f64> {
+        let (indptr, indices) = (&self.indptr, &self.indices);
+        let n = self.diagonal.len();
+        let mut z = r.to_vec();
+        // 前代求解 L * y = r
+        for i in 0..n {
+            for p in indptr[i]..self.diagonal[i] {
+                z[i] -= self.values[p] * z[indices[p]];
+            }
+        }
+        // 回代求解 U * z = y
+        for i in (0..n).rev() {
+            for p in self.diagonal[i] + 1..indptr[i + 1] {
+                z[i] -= self.values[p] * z[indices[p]];
+            }
+            z[i] /= self.values[self.diagonal[i]];
+        }
+        z
+    }
+}
+
+/// Krylov 迭代法的参数。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KrylovOptions {
+    /// 相对残差容差，`‖b - A * x‖₂ ≤ tolerance * ‖b‖₂` 时视为收敛。
+    pub tolerance: f64,
+    /// 最大迭代次数，GMRES 按内层迭代累计。
+    pub max_iterations: usize,
+    /// GMRES 的重启长度，其他方法忽略该参数。
+    pub restart: usize,
+}
+
+impl Default for KrylovOptions {
+    fn default() -> Self {
+        KrylovOptions {
+            tolerance: DEFAULT_KRYLOV_TOLERANCE,
+            max_iterations: DEFAULT_KRYLOV_MAX_ITERATIONS,
+            restart: DEFAULT_GMRES_RESTART,
+        }
+    }
+}
+
+/// Krylov 迭代法的收敛报告。
+#[derive(Debug, Clone, PartialEq)]
+pub struct KrylovReport {
+    /// 最后一次迭代得到的近似解。
+    pub solution: Vec<f64>,
+    /// 是否在最大迭代次数内达到容差。
+    pub converged: bool,
+    /// 实际执行的迭代次数。
+    pub iterations: usize,
+    /// 每次迭代后的相对残差 `‖b - A * x‖₂ / ‖b‖₂`，第一个元素对应初始解 `x = 0`。
+    pub residual_history: Vec<f64>,
+}
+
+/// 使用（预条件）共轭梯度法求解对称正定线性方程组 `A * x = b`。
+///
+/// # 参数
+///
+/// * `a` - 对称正定的线性算子。
+/// * `b` - 右端项。
+/// * `options` - 容差与最大迭代次数。
+/// * `preconditioner` - 可选的对称正定预条件子。
+///
+/// # 返回
+///
+/// 返回收敛报告；未收敛时 `converged` 为 `false`，仍返回最后的近似解。
+/// 如果算子不是方阵，返回 `MatrixError::NotSquare`；`b` 的长度不符时返回 `MatrixError::DimensionMismatch`。
+///
+/// # 注意
+///
+/// 如果检测到非正曲率 `pᵀ * A * p ≤ 0`（算子不是正定的），迭代提前终止并报告未收敛。
+pub fn conjugate_gradient<A>(
+    a: &A,
+    b: &[f64],
+    options: &KrylovOptions,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> Result<KrylovReport, MatrixError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let n = check_system(a, b)?;
+    let b_norm = norm(b);
+    let mut report = KrylovReport::start(n, b_norm);
+    if report.converged {
+        return Ok(report);
+    }
+
+    let precondition = |r: &[f64]| preconditioner.map_or_else(|| r.to_vec(), |m| m.apply(r));
+    let x = &mut report.solution;
+    let mut r = b.to_vec();
+    let mut z = precondition(&r);
+    let mut p = z.clone();
+    let mut rz = dot(&r, &z);
+
+    while report.iterations < options.max_iterations {
+        let q = a.apply(&p);
+        let curvature = dot(&p, &q);
+        if curvature <= 0.0 {
+            break;
+        }
+        let alpha = rz / curvature;
+        axpy(alpha, &p, x);
+        axpy(-alpha, &q, &mut r);
+        report.iterations += 1;
+        let relative = norm(&r) / b_norm;
+        report.residual_history.push(relative);
+        if relative <= options.tolerance {
+            report.converged = true;
+            break;
+        }
+
+        z = precondition(&r);
+        let rz_next = dot(&r, &z);
+        let beta = rz_next / rz;
+        rz = rz_next;
+        for (p_i, z_i) in p.iter_mut().zip(&z) {
+            *p_i = z_i + beta * *p_i;
+        }
+    }
+    Ok(report)
+}
+
+/// 使用右预条件的稳定双共轭梯度法（BiCGSTAB）求解一般线性方程组 `A * x = b`。
+///
+/// # 参数
+///
+/// * `a` - 非奇异的线性算子，无需对称。
+/// * `b` - 右端项。
+/// * `options` - 容差与最大迭代次数。
+/// * `preconditioner` - 可选的预条件子，以右预条件方式使用，残差历史为原方程组的真实残差。
+///
+/// # 返回
+///
+/// 返回收敛报告；出现数值崩溃（`ρ = 0`、`(r̂, v) = 0` 或 `ω = 0`）或达到最大迭代次数时 `converged` 为 `false`。
+/// 如果算子不是方阵，返回 `MatrixError::NotSquare`；`b` 的长度不符时返回 `MatrixError::DimensionMismatch`。
+pub fn bicgstab<A>(
+    a: &A,
+    b: &[f64],
+    options: &KrylovOptions,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> Result<KrylovReport, MatrixError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let n = check_system(a, b)?;
+    let b_norm = norm(b);
+    let mut report = KrylovReport::start(n, b_norm);
+    if report.converged {
+        return Ok(report);
+    }
+
+    let precondition = |r: &[f64]| preconditioner.map_or_else(|| r.to_vec(), |m| m.apply(r));
+    let x = &mut report.solution;
+    let mut r = b.to_vec();
+    let r_hat = r.clone();
+    let (mut rho, mut alpha, mut omega) = (1.0, 1.0, 1.0);
+    let mut v = vec![0.0; n];
+    let mut p = vec![0.0; n];
+
+    while report.iterations < options.max_iterations {
+        let rho_next = dot(&r_hat, &r);
+        if rho_next == 0.0 {
+            break;
+        }
+        let beta = (rho_next / rho) * (alpha / omega);
+        rho = rho_next;
+        for ((p_i, r_i), v_i) in p.iter_mut().zip(&r).zip(&v) {
+            *p_i = r_i + beta * (*p_i - omega * v_i);
+        }
+
+        let p_hat = precondition(&p);
+        v = a.apply(&p_hat);
+        let r_hat_v = dot(&r_hat, &v);
+        if r_hat_v == 0.0 {
+            break;
+        }
+        alpha = rho / r_hat_v;
+        let mut s = r.clone();
+        axpy(-alpha, &v, &mut s);
+        report.iterations += 1;
+
+        let s_relative = norm(&s) / b_norm;
+        if s_relative <= options.tolerance {
+            axpy(alpha, &p_hat, x);
+            report.residual_history.push(s_relative);
+            report.converged = true;
+            break;
+        }
+
+        let s_hat = precondition(&s);
+        let t = a.apply(&s_hat);
+        let tt = dot(&t, &t);
+        omega = if tt == 0.0 { 0.0 } else { dot(&t, &s) / tt };
+        axpy(alpha, &p_hat, x);
+        axpy(omega, &s_hat, x);
+        r = s;
+        axpy(-omega, &t, &mut r);
+
+        let relative = norm(&r) / b_norm;
+        report.residual_history.push(relative);
+        if relative <= options.tolerance {
+            report.converged = true;
+            break;
+        }
+        if omega == 0.0 {
+            break;
+        }
+    }
+    Ok(report)
+}
+
+/// 使用右预条件的重启 GMRES(m) 求解一般线性方程组 `A * x = b`。
+///
+/// 每个重启周期内用修正 Gram–Schmidt 构造 Arnoldi 基，并用 Givens 旋转增量地求解最小二乘问题，
+/// 因此每步的残差范数无需显式计算。
+///
+/// # 参数
+///
+/// * `a` - 非奇异的线性算子，无需对称。
+/// * `b` - 右端项。
+/// * `options` - 容差、最大迭代次数以及重启长度 `restart`。
+/// * `preconditioner` - 可选的预条件子，以右预条件方式使用，残差历史为原方程组的真实残差。
+///
+/// # 返回
+///
+/// 返回收敛报告，`iterations` 为累计的内层迭代次数。
+/// 如果算子不是方阵，返回 `MatrixError::NotSquare`；`b` 的长度不符时返回 `MatrixError::DimensionMismatch`；
+/// 回代时旋转后的 Hessenberg 矩阵出现零对角元（通常意味着 `A` 奇异）时返回 `MatrixError::Singular`。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::krylov::{gmres, KrylovOptions};
+/// use rs_math::matrix::matrix_2d::Matrix2D;
+///
+/// let a = Matrix2D::new(vec![vec![4.0, 1.0, 0.0], vec![2.0, 5.0, 1.0], vec![0.0, -1.0, 3.0]]).unwrap();
+/// let report = gmres(&a, &[5.0, 8.0, 2.0], &KrylovOptions::default(), None).unwrap();
+/// assert!(report.converged);
+/// assert!(report.solution.iter().all(|x| (x - 1.0).abs() < 1e-8));
+/// ```
+pub fn gmres<A>(
+    a: &A,
+    b: &[f64],
+    options: &KrylovOptions,
+    preconditioner: Option<&dyn Preconditioner>,
+) -> Result<KrylovReport, MatrixError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let n = check_system(a, b)?;
+    let b_norm = norm(b);
+    let mut report = KrylovReport::start(n, b_norm);
+    if report.converged {
+        return Ok(report);
+    }
+
+    let precondition = |r: &[f64]| preconditioner.map_or_else(|| r.to_vec(), |m| m.apply(r));
+    let m = options.restart.clamp(1, n.max(1));
+
+    while report.iterations < options.max_iterations {
+        // 每个周期从真实残差重新开始
+        let mut r = b.to_vec();
+        axpy(-1.0, &a.apply(&report.solution), &mut r);
+        let beta = norm(&r);
+        if beta / b_norm <= options.tolerance {
+            report.converged = true;
+            break;
+        }
+
+        let mut basis = vec![r.iter().map(|x| x / beta).collect::<Vec<f64>>()];
+        let mut h = vec![vec![0.0; m]; m + 1];
+        let mut rotations: Vec<(f64, f64)> = Vec::with_capacity(m);
+        let mut g = vec![0.0; m + 1];
+        g[0] = beta;
+
+        let mut k = 0;
+        while k < m && report.iterations < options.max_iterations {
+            let mut w = a.apply(&precondition(&basis[k]));
+            for (i, v) in basis.iter().enumerate() {
+                h[i][k] = dot(&w, v);
+                axpy(-h[i][k], v, &mut w);
+            }
+            h[k + 1][k] = norm(&w);
+
+            for (i, &(c, s)) in rotations.iter().enumerate() {
+                let (upper, lower) = (h[i][k], h[i + 1][k]);
+                h[i][k] = c * upper + s * lower;
+                h[i + 1][k] = -s * upper + c * lower;
+            }
+            let radius = h[k][k].hypot(h[k + 1][k]);
+            let (c, s) = if radius == 0.0 { (1.0, 0.0) } else { (h[k][k] / radius, h[k + 1][k] / radius) };
+            let next_norm = h[k + 1][k];
+            h[k][k] = radius;
+            h[k + 1][k] = 0.0;
+            rotations.push((c, s));
+            g[k + 1] = -s * g[k];
+            g[k] *= c;
+
+            k += 1;
+            report.iterations += 1;
+            let relative = g[k].abs() / b_norm;
+            report.residual_history.push(relative);
+            if relative <= options.tolerance || next_norm == 0.0 {
+                break;
+            }
+            basis.push(w.iter().map(|x| x / next_norm).collect());
+        }
+
+        // 回代求解 H[0..k, 0..k] * y = g[0..k]，并更新 x += M⁻¹ * V * y
+        let mut y = g[..k].to_vec();
+        for i in (0..k).rev() {
+            for j in i + 1..k {
+                y[i] -= h[i][j] * y[j];
+            }
+            if h[i][i] == 0.0 {
+                return Err(MatrixError::Singular { pivot: i });
+            }
+            y[i] /= h[i][i];
+        }
+        let mut update = vec![0.0; n];
+        for (v, &y_j) in basis.iter().zip(&y) {
+            axpy(y_j, v, &mut update);
+        }
+        axpy(1.0, &precondition(&update), &mut report.solution);
+
+        if report.residual_history.last().is_some_and(|&r| r <= options.tolerance) {
+            report.converged = true;
+            break;
+        }
+    }
+    Ok(report)
+}
+
+impl KrylovReport {
+    /// 以零向量为初始解创建报告；右端项为零时零向量即为精确解。
+    fn start(n: usize, b_norm: f64) -> KrylovReport {
+        KrylovReport {
+            solution: vec![0.0; n],
+            converged: b_norm == 0.0,
+            iterations: 0,
+            residual_history: vec![if b_norm == 0.0 { 0.0 } else { 1.0 }],
+        }
+    }
+}
+
+/// 检查算子为方阵且右端项长度匹配，返回方程组的阶数。
+fn check_system<A>(a: &A, b: &[f64]) -> Result<usize, MatrixError>
+where
+    A: LinearOperator + ?Sized,
+{
+    let n = square_order(a.shape())?;
+    if b.len() != n {
+        return Err(MatrixError::DimensionMismatch { left: a.shape(), right: (b.len(), 1) });
+    }
+    Ok(n)
+}
+
+fn square_order((rows, cols): (usize, usize)) -> Result<usize, MatrixError> {
+    if rows != cols {
+        return Err(MatrixError::NotSquare { rows, cols });
+    }
+    Ok(rows)
+}
+
+fn dot(x: &[f64], y: &[f64]) -> f64 {
+    x.iter().zip(y).map(|(a, b)| a * b).sum()
+}
+
+fn norm(x: &[f64]) -> f64 {
+    dot(x, x).sqrt()
+}
+
+/// `y += alpha * x`
+fn axpy(alpha: f64, x: &[f64], y: &mut [f64]) {
+    for (y_i, x_i) in y.iter_mut().zip(x) {
+        *y_i += alpha * x_i;
+    }
+}
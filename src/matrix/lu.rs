@@ -0,0 +1,297 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::number::complex::Complex;
+use crate::number::field::Field;
+use crate::number::scalar::{RealField, Scalar};
+
+/// 带部分主元选取的 LU 分解结果，满足 `P * A = L * U`。
+///
+/// `L` 为单位下三角矩阵，`U` 为上三角矩阵，二者紧凑地存放在同一个矩阵中；
+/// `P` 为行置换矩阵，以置换向量的形式保存。
+/// 同一个分解可以重复用于多个右端项的求解。`f64` 矩阵的分解为 `LU`（即 `LU<f64>`），
+/// 复矩阵的分解为 `LU<Complex<T>>`，`f32` 矩阵可通过 [`LuDecomposition`] 得到 `LU<f32>`。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LU<T = f64> {
+    /// 紧凑存储的 L（严格下三角部分）与 U（上三角部分）。
+    factors: Matrix2D<T>,
+    /// 置换向量：`P * A` 的第 `i` 行是 `A` 的第 `permutation[i]` 行。
+    permutation: Vec<usize>,
+    /// 置换的奇偶性，偶置换为 `1`，奇置换为 `-1`。
+    sign: T,
+    /// 第一个为零或可忽略的主元所在的列，矩阵非奇异时为 `None`。
+    singular_pivot: Option<usize>,
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 对方阵进行带部分主元选取的 LU 分解。
+    ///
+    /// 每一步都选取当前列中绝对值最大的元素作为主元并交换行，
+    /// 绝对值不超过 `n * EPSILON * max|a_ij|` 的主元被视为可忽略，矩阵记为奇异。
+    /// 分解本身对奇异矩阵也会完成，奇异性在求解或求逆时以错误形式报告。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `LU` 分解结果；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![4.0, 3.0], vec![6.0, 3.0]]).unwrap();
+    /// let lu = a.lu().unwrap();
+    /// assert!((lu.determinant() - (-6.0)).abs() < 1e-12);
+    /// let x = lu.solve(&[10.0, 12.0]).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn lu(&self) -> Result<LU, MatrixError> {
+        lu_factor(self)
+    }
+}
+
+#[allow(dead_code)]
+impl<T: RealField> Matrix2D<Complex<T>> {
+    /// 对复方阵进行带部分主元选取的 LU 分解，主元按模选取，其余规则与实矩阵的 `lu` 相同。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `LU` 分解结果；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let i = Complex::i();
+    /// let one = Complex::new(1.0, 0.0);
+    /// let a = Matrix2D::new(vec![vec![one, i], vec![i, one]]).unwrap();
+    /// let x = a.lu().unwrap().solve(&[one + i, one + i]).unwrap();
+    /// assert!((x[0] - one).norm() < 1e-12 && (x[1] - one).norm() < 1e-12);
+    /// ```
+    pub fn lu(&self) -> Result<LU<Complex<T>>, MatrixError> {
+        lu_factor(self)
+    }
+}
+
+/// 对任意 [`Scalar`] 元素类型（`f32`、`f64`、`Complex<T>`）的方阵提供 LU 分解、行列式与逆矩阵。
+///
+/// `Matrix2D<f64>` 与复矩阵上同名的固有方法会优先被调用，使 `Matrix2D::new(vec![vec![2.0]])`
+/// 这类未标注元素类型的矩阵仍推断为 `f64`；对 `f32` 矩阵或在泛型代码中使用时需要引入该 trait。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::lu::LuDecomposition;
+/// use rs_math::matrix::matrix_2d::Matrix2D;
+/// use rs_math::number::scalar::RealField;
+///
+/// fn condition_product<T: RealField>(a: &Matrix2D<T>) -> T {
+///     let inverse = LuDecomposition::inverse(a).unwrap();
+///     a.norm_1() * inverse.norm_1()
+/// }
+///
+/// let a = Matrix2D::new(vec![vec![4.0f32, 3.0], vec![6.0, 3.0]]).unwrap();
+/// assert!((a.determinant().unwrap() + 6.0).abs() < 1e-5);
+/// assert!((condition_product(&a) - 15.0).abs() < 1e-4);
+/// ```
+pub trait LuDecomposition<T> {
+    /// 对方阵进行带部分主元选取的 LU 分解，规则同 [`Matrix2D::lu`]。
+    fn lu(&self) -> Result<LU<T>, MatrixError>;
+
+    /// 基于 LU 分解计算行列式；矩阵不是方阵时返回 `MatrixError::NotSquare`。
+    fn determinant(&self) -> Result<T, MatrixError>;
+
+    /// 基于 LU 分解计算逆矩阵；矩阵不是方阵或奇异时返回相应的 `MatrixError`。
+    fn inverse(&self) -> Result<Matrix2D<T>, MatrixError>;
+}
+
+impl<T: Scalar> LuDecomposition<T> for Matrix2D<T> {
+    fn lu(&self) -> Result<LU<T>, MatrixError> {
+        lu_factor(self)
+    }
+
+    fn determinant(&self) -> Result<T, MatrixError> {
+        Ok(lu_factor(self)?.determinant())
+    }
+
+    fn inverse(&self) -> Result<Matrix2D<T>, MatrixError> {
+        lu_factor(self)?.inverse()
+    }
+}
+
+/// 实矩阵与复矩阵共用的分解过程，主元按模选取，`EPSILON` 取 [`Scalar::Real`] 的机器精度。
+fn lu_factor<T: Scalar>(matrix: &Matrix2D<T>) -> Result<LU<T>, MatrixError> {
+    if matrix.rows() != matrix.cols() {
+        return Err(MatrixError::NotSquare { rows: matrix.rows(), cols: matrix.cols() });
+    }
+
+    let n = matrix.rows();
+    let mut a = matrix.clone();
+    let mut permutation: Vec<usize> = (0..n).collect();
+    let mut sign = T::one();
+    let mut singular_pivot = None;
+
+    let max_abs = matrix
+        .as_slice()
+        .iter()
+        .fold(T::Real::zero(), |acc, &x| acc.max(x.modulus()));
+    let tolerance = T::Real::from_f64(n as f64) * T::Real::epsilon() * max_abs;
+
+    for k in 0..n {
+        // 选取第 k 列中模最大的元素作为主元
+        let mut pivot_row = k;
+        for i in k + 1..n {
+            if a[(i, k)].modulus() > a[(pivot_row, k)].modulus() {
+                pivot_row = i;
+            }
+        }
+
+        if pivot_row != k {
+            a.swap_rows(k, pivot_row);
+            permutation.swap(k, pivot_row);
+            sign = -sign;
+        }
+
+        let pivot = a[(k, k)];
+        if pivot.modulus() <= tolerance && singular_pivot.is_none() {
+            singular_pivot = Some(k);
+        }
+        if pivot.is_zero() {
+            // 该列已全为零，无需消元
+            continue;
+        }
+
+        for i in k + 1..n {
+            let factor = a[(i, k)] / pivot;
+            a[(i, k)] = factor;
+            for j in k + 1..n {
+                let update = factor * a[(k, j)];
+                a[(i, j)] -= update;
+            }
+        }
+    }
+
+    Ok(LU { factors: a, permutation, sign, singular_pivot })
+}
+
+impl<T: Scalar> LU<T> {
+    /// 返回单位下三角因子 `L`。
+    pub fn l(&self) -> Matrix2D<T> {
+        let n = self.factors.rows();
+        let mut l = Matrix2D::identity(n);
+        for i in 0..n {
+            for j in 0..i {
+                l[(i, j)] = self.factors[(i, j)];
+            }
+        }
+        l
+    }
+
+    /// 返回上三角因子 `U`。
+    pub fn u(&self) -> Matrix2D<T> {
+        let n = self.factors.rows();
+        let mut u = Matrix2D::filled(n, n, T::zero());
+        for i in 0..n {
+            for j in i..n {
+                u[(i, j)] = self.factors[(i, j)];
+            }
+        }
+        u
+    }
+
+    /// 返回行置换矩阵 `P`，满足 `P * A = L * U`。
+    pub fn p(&self) -> Matrix2D<T> {
+        let n = self.factors.rows();
+        let mut p = Matrix2D::filled(n, n, T::zero());
+        for (i, &row) in self.permutation.iter().enumerate() {
+            p[(i, row)] = T::one();
+        }
+        p
+    }
+
+    /// 返回置换向量：`P * A` 的第 `i` 行是 `A` 的第 `permutation[i]` 行。
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// 判断分解过程中是否遇到了为零或可忽略的主元。
+    pub fn is_singular(&self) -> bool {
+        self.singular_pivot.is_some()
+    }
+
+    /// 利用分解结果计算原矩阵的行列式。
+    pub fn determinant(&self) -> T {
+        (0..self.factors.rows()).fold(self.sign, |det, i| det * self.factors[(i, i)])
+    }
+
+    /// 求解线性方程组 `A * x = b`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 右端项，长度必须等于矩阵阶数。
+    ///
+    /// # 返回
+    ///
+    /// 返回解向量 `x`；如果 `b` 的长度与阶数不符，返回 `MatrixError::DimensionMismatch`；
+    /// 如果矩阵奇异，返回 `MatrixError::Singular`。
+    pub fn solve(&self, b: &[T]) -> Result<Vec<T>, MatrixError> {
+        let n = self.factors.rows();
+        if b.len() != n {
+            return Err(MatrixError::DimensionMismatch { left: self.factors.shape(), right: (b.len(), 1) });
+        }
+        if let Some(pivot) = self.singular_pivot {
+            return Err(MatrixError::Singular { pivot });
+        }
+
+        // 前代求解 L * y = P * b
+        let mut x: Vec<T> = self.permutation.iter().map(|&row| b[row]).collect();
+        for i in 0..n {
+            for j in 0..i {
+                let update = self.factors[(i, j)] * x[j];
+                x[i] -= update;
+            }
+        }
+
+        // 回代求解 U * x = y
+        for i in (0..n).rev() {
+            for j in i + 1..n {
+                let update = self.factors[(i, j)] * x[j];
+                x[i] -= update;
+            }
+            x[i] /= self.factors[(i, i)];
+        }
+
+        Ok(x)
+    }
+
+    /// 对多个右端项同时求解 `A * X = B`，`B` 的每一列为一个右端项。
+    ///
+    /// # 返回
+    ///
+    /// 返回解矩阵 `X`；如果 `B` 的行数与阶数不符，返回 `MatrixError::DimensionMismatch`；
+    /// 如果矩阵奇异，返回 `MatrixError::Singular`。
+    pub fn solve_matrix(&self, b: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        if b.rows() != self.factors.rows() {
+            return Err(MatrixError::DimensionMismatch { left: self.factors.shape(), right: b.shape() });
+        }
+        let mut x = Matrix2D::filled(b.rows(), b.cols(), T::zero());
+        for j in 0..b.cols() {
+            let column = self.solve(&b.column(j))?;
+            for (i, value) in column.into_iter().enumerate() {
+                x[(i, j)] = value;
+            }
+        }
+        Ok(x)
+    }
+
+    /// 利用分解结果计算原矩阵的逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回逆矩阵；如果矩阵奇异，返回 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Matrix2D<T>, MatrixError> {
+        self.solve_matrix(&Matrix2D::identity(self.factors.rows()))
+    }
+}
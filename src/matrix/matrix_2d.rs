@@ -1,34 +1,45 @@
-use std::f64::EPSILON;
+use std::ops::{Index, IndexMut};
+
+use crate::matrix::error::MatrixError;
+use crate::number::scalar::{RealField, Scalar};
 
 /// 表示具有指定行数和列数的二维矩阵。
-#[derive(Debug, PartialEq)]
-pub struct Matrix2D {
+///
+/// 元素类型 `T` 默认为 `f64`。LU 分解、行列式与求逆对 `f64` 与
+/// [`Complex`](crate::number::complex::Complex) 元素是固有方法，对 `f32` 等其他 [`Scalar`] 元素由
+/// [`LuDecomposition`](crate::matrix::lu::LuDecomposition) trait 提供；QR、特征值等其余数值分解只对 `f64` 提供；
+/// 加减乘、运算符、共轭转置、拼接等运算对任意 [`Scalar`] 元素（如 `f32`）可用，范数与容差判断需要 [`RealField`]；
+/// 构造、访问、转置以及 [`Matrix2D::rref`] 等精确消元方法对任意满足
+/// [`Field`](crate::number::field::Field) 的元素类型（如 [`Rational`](crate::number::rational::Rational)）可用。
+///
+/// # 构造函数的选择
+///
+/// 部分构造函数成对提供，两者结果相同，区别只在于元素类型：
+///
+/// | `f64` 专用 | 任意元素类型 |
+/// | --- | --- |
+/// | [`Matrix2D::zeros`] | [`Matrix2D::filled`]`(rows, cols, T::zero())` |
+/// | [`Matrix2D::eye`] | [`Matrix2D::identity`] |
+/// | [`Matrix2D::hstack`] / [`Matrix2D::vstack`] | [`Matrix2D::concat_horizontal`] / [`Matrix2D::concat_vertical`] |
+/// | [`Matrix2D::block_diag`] | [`Matrix2D::block_diagonal`] |
+///
+/// 处理 `f64` 矩阵时使用左列即可，`Matrix2D::eye(3)`、`Matrix2D::vstack(&[])` 这类不带类型标注的调用
+/// 会推断为 `f64`；`f32`、复数等其他元素类型以及泛型代码请使用右列。
+///
+/// 元素按行优先顺序连续存放在一个 `Vec<T>` 中，第 `i` 行第 `j` 列的元素位于
+/// `data[i * stride + j]`，其中行跨度 `stride` 等于列数。通过 `matrix[(i, j)]` 访问元素。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix2D<T = f64> {
     /// 矩阵的行数。
-    pub rows: usize,
-    /// 矩阵的列数。
-    pub cols: usize,
-    /// 以二维向量形式存储在矩阵中的数据。
-    pub data: Vec<Vec<f64>>,
+    rows: usize,
+    /// 矩阵的列数，同时也是相邻两行之间的跨度。
+    cols: usize,
+    /// 按行优先顺序连续存储的矩阵数据。
+    data: Vec<T>,
 }
 
-impl Clone for Matrix2D {
-    fn clone(&self) -> Self {
-        let mut cloned_data = Vec::with_capacity(self.rows);
-
-        for row in &self.data {
-            let cloned_row: Vec<f64> = row.clone();
-            cloned_data.push(cloned_row);
-        }
-
-        Matrix2D {
-            rows: self.rows,
-            cols: self.cols,
-            data: cloned_data,
-        }
-    }
-}
 #[allow(dead_code)]
-impl Matrix2D {
+impl<T: Clone> Matrix2D<T> {
     /// 创建一个新的矩阵。
     ///
     /// # 参数
@@ -37,38 +48,194 @@ impl Matrix2D {
     ///
     /// # 返回
     ///
-    /// 返回具有指定数据的 `Matrix2D` 实例。
+    /// 返回具有指定数据的 `Matrix2D` 实例；如果各行的列数不一致，
+    /// 返回 `MatrixError::RaggedRows`。与 [`Matrix2D::from_rows`] 等价。
     ///
-    /// # 错误
+    /// # 示例
     ///
-    /// 如果输入数据不合法，将引发断言错误：
-    /// - 矩阵必须至少有一行
-    /// - 矩阵必须至少有一列
-    /// - 所有行必须具有相同的列数
-    pub fn new(data: Vec<Vec<f64>>) -> Matrix2D {
-        // 检查输入数据的合法性
-        let rows = data.len();
-        assert!(rows > 0, "Matrix must have at least one row");
-
-        let cols = data[0].len();
-        assert!(cols > 0, "Matrix must have at least one column");
+    /// ```
+    /// use rs_math::matrix::error::MatrixError;
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(m.shape(), (2, 2));
+    /// assert_eq!(
+    ///     Matrix2D::new(vec![vec![1.0], vec![2.0, 3.0]]),
+    ///     Err(MatrixError::RaggedRows { row: 1, expected: 1, found: 2 })
+    /// );
+    /// ```
+    pub fn new(data: Vec<Vec<T>>) -> Result<Matrix2D<T>, MatrixError> {
+        Matrix2D::from_rows(data)
+    }
 
-        for row in &data {
-            assert_eq!(row.len(), cols, "All rows must have the same number of columns");
+    /// 由嵌套向量形式的行数据创建矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `rows` - 各行数据，允许为空（得到 0×0 矩阵）。
+    ///
+    /// # 返回
+    ///
+    /// 返回创建的矩阵；如果各行长度不一致，返回 `MatrixError::RaggedRows`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::from_rows(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(m[(1, 0)], 3.0);
+    /// assert!(Matrix2D::from_rows(vec![vec![1.0, 2.0], vec![3.0]]).is_err());
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Matrix2D<T>, MatrixError> {
+        let cols = rows.first().map_or(0, |row| row.len());
+        let mut data = Vec::with_capacity(rows.len() * cols);
+        for (i, row) in rows.iter().enumerate() {
+            if row.len() != cols {
+                return Err(MatrixError::RaggedRows { row: i, expected: cols, found: row.len() });
+            }
+            data.extend_from_slice(row);
         }
+        Ok(Matrix2D { rows: rows.len(), cols, data })
+    }
 
-        Matrix2D { rows, cols, data }
+    /// 由按行优先顺序排列的数据创建矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `rows` - 行数。
+    /// * `cols` - 列数。
+    /// * `data` - 长度为 `rows * cols` 的数据。
+    ///
+    /// # 返回
+    ///
+    /// 返回创建的矩阵；如果数据长度与形状不符，返回 `MatrixError::DataLength`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+    /// assert_eq!(m[(1, 2)], 6.0);
+    /// ```
+    pub fn from_vec(rows: usize, cols: usize, data: Vec<T>) -> Result<Matrix2D<T>, MatrixError> {
+        if data.len() != rows * cols {
+            return Err(MatrixError::DataLength { expected: rows * cols, found: data.len() });
+        }
+        Ok(Matrix2D { rows, cols, data })
     }
 
-    // 打印矩阵的方法
-    pub fn print(&self) {
-        for row in &self.data {
-            for &elem in row {
-                print!("{} ", elem);
+    /// 由元素生成函数创建矩阵，`f(i, j)` 给出第 `i` 行第 `j` 列的元素。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let hilbert = Matrix2D::from_fn(3, 3, |i, j| 1.0 / (i + j + 1) as f64);
+    /// assert_eq!(hilbert[(2, 2)], 0.2);
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Matrix2D<T>
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut data = Vec::with_capacity(rows * cols);
+        for i in 0..rows {
+            for j in 0..cols {
+                data.push(f(i, j));
             }
-            println!();
         }
+        Matrix2D { rows, cols, data }
+    }
+
+    /// 返回矩阵的行数。
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 返回矩阵的列数。
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 返回矩阵的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 以按行优先顺序排列的切片形式访问全部元素。
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// 以按行优先顺序排列的可变切片形式访问全部元素。
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// 取出按行优先顺序排列的底层数据。
+    pub fn into_vec(self) -> Vec<T> {
+        self.data
+    }
+
+    /// 转换为旧版的嵌套向量形式，便于迁移仍依赖 `Vec<Vec<f64>>` 的代码。
+    pub fn to_nested(&self) -> Vec<Vec<T>> {
+        (0..self.rows)
+            .map(|i| self.data[i * self.cols..(i + 1) * self.cols].to_vec())
+            .collect()
+    }
+
+    /// 对每个元素应用 `f`，得到同形状的新矩阵，元素类型可以改变。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::rational::Rational;
+    ///
+    /// let exact = Matrix2D::from_fn(1, 2, |_, j| Rational::new(1, 2 << j));
+    /// assert_eq!(exact.map(|x| x.to_f64()).as_slice(), &[0.5, 0.25]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Matrix2D<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Matrix2D { rows: self.rows, cols: self.cols, data: self.data.iter().map(f).collect() }
+    }
+
+    /// 获取当前矩阵的转置。
+    ///
+    /// # 返回
+    ///
+    /// 包含转置结果的 `Matrix2D` 实例。
+    pub fn transpose(&self) -> Matrix2D<T> {
+        Matrix2D::from_fn(self.cols, self.rows, |i, j| self[(j, i)].clone())
+    }
+
+    /// 原地交换矩阵的第 `i` 行与第 `j` 行。
+    ///
+    /// # 注意
+    ///
+    /// 如果行下标越界，将会引发 panic。
+    pub fn swap_rows(&mut self, i: usize, j: usize) {
+        assert!(i < self.rows && j < self.rows, "Row index out of bounds.");
+        if i == j {
+            return;
+        }
+        let (first, second) = (i.min(j), i.max(j));
+        let (head, tail) = self.data.split_at_mut(second * self.cols);
+        head[first * self.cols..(first + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+    }
+}
+
+#[allow(dead_code)]
+impl<T: Scalar> Matrix2D<T> {
+    /// 将矩阵的全部元素按列对齐输出到标准输出，等价于 `println!("{:#}", matrix)`。
+    pub fn print(&self) {
+        println!("{:#}", self);
     }
+
     /// 执行矩阵加法运算。
     ///
     /// # 参数
@@ -77,25 +244,10 @@ impl Matrix2D {
     ///
     /// # 返回
     ///
-    /// 如果矩阵维度相同，返回包含相加结果的 `Matrix2D` 实例；如果维度不同，返回 `None`。
-    pub fn add(&self, other: &Matrix2D) -> Option<Matrix2D> {
-        if self.rows == other.rows && self.cols == other.cols {
-            let mut result_data = Vec::with_capacity(self.rows);
-            for i in 0..self.rows {
-                let mut row = Vec::with_capacity(self.cols);
-                for j in 0..self.cols {
-                    row.push(self.data[i][j] + other.data[i][j]);
-                }
-                result_data.push(row);
-            }
-            Some(Matrix2D {
-                rows: self.rows,
-                cols: self.cols,
-                data: result_data,
-            })
-        } else {
-            None
-        }
+    /// 如果矩阵维度相同，返回包含相加结果的 `Matrix2D` 实例；
+    /// 如果维度不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn add(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.zip_with(other, |a, b| a + b)
     }
 
     /// 执行矩阵减法运算。
@@ -106,25 +258,22 @@ impl Matrix2D {
     ///
     /// # 返回
     ///
-    /// 如果矩阵维度相同，返回包含相减结果的 `Matrix2D` 实例；如果维度不同，返回 `None`。
-    pub fn subtract(&self, other: &Matrix2D) -> Option<Matrix2D> {
-        if self.rows == other.rows && self.cols == other.cols {
-            let mut result_data = Vec::with_capacity(self.rows);
-            for i in 0..self.rows {
-                let mut row = Vec::with_capacity(self.cols);
-                for j in 0..self.cols {
-                    row.push(self.data[i][j] - other.data[i][j]);
-                }
-                result_data.push(row);
-            }
-            Some(Matrix2D {
-                rows: self.rows,
-                cols: self.cols,
-                data: result_data,
-            })
-        } else {
-            None
+    /// 如果矩阵维度相同，返回包含相减结果的 `Matrix2D` 实例；
+    /// 如果维度不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn subtract(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.zip_with(other, |a, b| a - b)
+    }
+
+    /// 逐元素组合两个同形状矩阵。
+    fn zip_with<F>(&self, other: &Matrix2D<T>, f: F) -> Result<Matrix2D<T>, MatrixError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if self.shape() != other.shape() {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: other.shape() });
         }
+        let data = self.data.iter().zip(&other.data).map(|(&a, &b)| f(a, b)).collect();
+        Ok(Matrix2D { rows: self.rows, cols: self.cols, data })
     }
 
     /// 执行矩阵乘法运算。
@@ -135,475 +284,509 @@ impl Matrix2D {
     ///
     /// # 返回
     ///
-    /// 包含相乘结果的 `Matrix2D` 实例。
+    /// 包含相乘结果的 `Matrix2D` 实例；如果当前矩阵的列数不等于 `other` 的行数，
+    /// 返回 `MatrixError::DimensionMismatch`。
     ///
     /// # 注意
     ///
-    /// 如果矩阵维度不符合相乘规则，将会引发 panic。
-    pub fn multiply(&self, other: &Matrix2D) -> Matrix2D {
+    /// 小矩阵直接使用 [`Matrix2D::multiply_naive`]；乘法规模达到 [`GEMM_BLOCKED_THRESHOLD`]
+    /// 时改用分块的 [`Matrix2D::multiply_blocked`]。启用 `parallel` 特性后，达到
+    /// [`GEMM_PARALLEL_THRESHOLD`] 的乘法会使用多线程的 `multiply_parallel`。
+    /// 不同路径的累加顺序不同，结果可能在末位上有差异。
+    ///
+    /// [`GEMM_BLOCKED_THRESHOLD`]: crate::matrix::gemm::GEMM_BLOCKED_THRESHOLD
+    /// [`GEMM_PARALLEL_THRESHOLD`]: crate::matrix::gemm::GEMM_PARALLEL_THRESHOLD
+    pub fn multiply(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        let work = self.rows * self.cols * other.cols;
+        #[cfg(feature = "parallel")]
+        if work >= crate::matrix::gemm::GEMM_PARALLEL_THRESHOLD {
+            return self.multiply_parallel(other);
+        }
+        if work >= crate::matrix::gemm::GEMM_BLOCKED_THRESHOLD {
+            return self.multiply_blocked(other);
+        }
+        self.multiply_naive(other)
+    }
+
+    /// 使用 i-k-j 三重循环执行矩阵乘法，不做分块，适合小矩阵，也作为其他实现的参照。
+    ///
+    /// # 返回
+    ///
+    /// 与 [`Matrix2D::multiply`] 相同。
+    pub fn multiply_naive(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
         // 检查矩阵维度是否允许相乘
         if self.cols != other.rows {
-            panic!("Matrix dimensions do not match for multiplication.")
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: other.shape() });
         }
 
-        let mut result_data = Vec::with_capacity(self.rows);
+        // 按 i-k-j 顺序遍历，使内层循环连续访问两个矩阵的行
+        let mut result = Matrix2D::filled(self.rows, other.cols, T::zero());
         for i in 0..self.rows {
-            let mut row = Vec::with_capacity(other.cols);
-            for j in 0..other.cols {
-                let mut sum = 0.0;
-                for k in 0..self.cols {
-                    sum += self.data[i][k] * other.data[k][j];
+            let out = &mut result.data[i * other.cols..(i + 1) * other.cols];
+            for k in 0..self.cols {
+                let a_ik = self.data[i * self.cols + k];
+                let other_row = &other.data[k * other.cols..(k + 1) * other.cols];
+                for (value, &b_kj) in out.iter_mut().zip(other_row) {
+                    *value += a_ik * b_kj;
                 }
-                row.push(sum);
             }
-            result_data.push(row);
         }
+        Ok(result)
+    }
 
-        Matrix2D {
-            rows: self.rows,
-            cols: other.cols,
-            data: result_data,
-        }
+    /// 逐元素取共轭，实数矩阵返回其副本。
+    pub fn conjugate(&self) -> Matrix2D<T> {
+        self.map(|&x| x.conj())
     }
 
-    /// 获取当前矩阵的转置。
+    /// 共轭转置（Hermitian 转置）`Aᴴ`，实数矩阵即为转置。
     ///
-    /// # 返回
+    /// # 示例
     ///
-    /// 包含转置结果的 `Matrix2D` 实例。
-    pub fn transpose(&self) -> Matrix2D {
-        let mut result_data = Vec::with_capacity(self.cols);
-        for j in 0..self.cols {
-            let mut row = Vec::with_capacity(self.rows);
-            for i in 0..self.rows {
-                row.push(self.data[i][j]);
-            }
-            result_data.push(row);
-        }
-
-        Matrix2D {
-            rows: self.cols,
-            cols: self.rows,
-            data: result_data,
-        }
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let a = Matrix2D::new(vec![vec![Complex::new(1.0, 2.0), Complex::new(0.0, -1.0)]]).unwrap();
+    /// let h = a.conjugate_transpose();
+    /// assert_eq!(h.shape(), (2, 1));
+    /// assert_eq!(h[(0, 0)], Complex::new(1.0, -2.0));
+    /// assert_eq!(h[(1, 0)], Complex::new(0.0, 1.0));
+    /// ```
+    pub fn conjugate_transpose(&self) -> Matrix2D<T> {
+        Matrix2D::from_fn(self.cols, self.rows, |i, j| self.data[j * self.cols + i].conj())
     }
 
-    // 计算矩阵的行列式
-    pub fn determinant(&self) -> Option<f64> {
-        // 检查矩阵是否为方阵
-        if self.rows != self.cols {
-            return None;
-        }
-
-        // 递归计算行列式
-        self.calculate_determinant(&self.data)
-    }
-
-    // 递归计算行列式的辅助函数
-    fn calculate_determinant(&self, matrix: &Vec<Vec<f64>>) -> Option<f64> {
-        match self.rows {
-            1 => Some(matrix[0][0]), // 1x1 矩阵的行列式为其唯一元素的值
-            2 => Some(matrix[0][0] * matrix[1][1] - matrix[0][1] * matrix[1][0]), // 2x2 矩阵的行列式计算公式
-            _ => {
-                let mut det = 0.0;
-
-                // 计算代数余子式
-                for col in 0..self.cols {
-                    // 计算代数余子式
-                    let submatrix_data: Vec<Vec<f64>> = matrix
-                        .iter()
-                        .enumerate()
-                        .filter(|&(i, _)| i != 0)
-                        .map(|(_, row)| row.iter().enumerate().filter(|&(j, _)| j != col).map(|(_, &val)| val).collect())
-                        .collect();
-
-                    let submatrix = Matrix2D {
-                        rows: self.rows - 1,
-                        cols: self.cols - 1,
-                        data: submatrix_data,
-                    };
-
-                    let cofactor = if col % 2 == 0 { 1.0 } else { -1.0 };
-
-                    match self.calculate_determinant(&submatrix.data) {
-                        Some(sub_det) => det += cofactor * matrix[0][col] * sub_det,
-                        None => return None,
-                    }
-                }
+    // 计算矩阵乘以向量，结果为单列矩阵；向量长度与列数不符时返回错误
+    pub fn multiply_by_vector(&self, vector: &[T]) -> Result<Matrix2D<T>, MatrixError> {
+        let product = self.try_mul_vector(vector)?;
+        Matrix2D::from_vec(self.rows, 1, product)
+    }
 
-                Some(det)
-            }
-        }
+    /// 创建所有元素都等于 `value` 的矩阵，`f64` 全零矩阵可以直接使用 [`Matrix2D::zeros`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::filled(2, 3, 0.5f32);
+    /// assert_eq!(m.shape(), (2, 3));
+    /// assert!(m.as_slice().iter().all(|&x| x == 0.5));
+    /// ```
+    pub fn filled(rows: usize, cols: usize, value: T) -> Matrix2D<T> {
+        Matrix2D { rows, cols, data: vec![value; rows * cols] }
     }
-    // 计算矩阵的逆矩阵
-    pub fn inverse(&self) -> Option<Matrix2D> {
-        // 检查矩阵是否为方阵
-        if self.rows != self.cols {
-            return None;
-        }
 
-        // 递归计算行列式
-        let det_a = self.determinant();
-        if det_a.unwrap() == 0.0 {
-            return None; // 行列式为零，逆矩阵不存在
-        }
+    /// 创建任意元素类型的单位矩阵，`f64` 矩阵可以直接使用 [`Matrix2D::eye`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let i = Matrix2D::<f32>::identity(2);
+    /// assert_eq!(i.to_nested(), vec![vec![1.0f32, 0.0], vec![0.0, 1.0]]);
+    /// ```
+    pub fn identity(size: usize) -> Matrix2D<T> {
+        Matrix2D::from_fn(size, size, |i, j| if i == j { T::one() } else { T::zero() })
+    }
 
-        // 计算矩阵的伴随矩阵
-        let adj_a = self.adjoint();
+    // 获取矩阵的某一列
+    pub fn column(&self, col_index: usize) -> Vec<T> {
+        assert!(col_index < self.cols, "Column index out of bounds.");
+        (0..self.rows).map(|i| self.data[i * self.cols + col_index]).collect()
+    }
 
-        // 计算逆矩阵
-        let inv_det = 1.0 / det_a.unwrap();
-        let mut inv_a_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            let mut row = Vec::with_capacity(self.cols);
-            for j in 0..self.cols {
-                row.push(inv_det * adj_a.data[i][j]);
-            }
-            inv_a_data.push(row);
+    /// 计算方阵的迹，即主对角线元素之和。
+    ///
+    /// # 返回
+    ///
+    /// 返回迹；如果矩阵不是方阵，返回 `MatrixError::NotSquare`。
+    pub fn trace(&self) -> Result<T, MatrixError> {
+        if self.rows != self.cols {
+            return Err(MatrixError::NotSquare { rows: self.rows, cols: self.cols });
         }
+        Ok((0..self.rows).fold(T::zero(), |sum, i| sum + self[(i, i)]))
+    }
 
-        Some(Matrix2D {
-            rows: self.rows,
-            cols: self.cols,
-            data: inv_a_data,
+    /// 计算 Kronecker 积 `A ⊗ B`。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `(m·p)×(n·q)` 的分块矩阵，第 `(i, j)` 块为 `a_ij * B`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0]]).unwrap();
+    /// let b = Matrix2D::new(vec![vec![1.0], vec![10.0]]).unwrap();
+    /// assert_eq!(a.kronecker(&b).to_nested(), vec![vec![1.0, 2.0], vec![10.0, 20.0]]);
+    /// ```
+    pub fn kronecker(&self, other: &Matrix2D<T>) -> Matrix2D<T> {
+        Matrix2D::from_fn(self.rows * other.rows, self.cols * other.cols, |i, j| {
+            self[(i / other.rows, j / other.cols)] * other[(i % other.rows, j % other.cols)]
         })
     }
-    // 计算矩阵的伴随矩阵
-    fn adjoint(&self) -> Matrix2D {
-        // 计算代数余子式
-        let mut adj_a_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            let mut row = Vec::with_capacity(self.cols);
-            for j in 0..self.cols {
-                // 计算代数余子式
-                let submatrix_data: Vec<Vec<f64>> = self.data
-                    .iter()
-                    .enumerate()
-                    .filter(|&(row_idx, _)| row_idx != i)
-                    .map(|(_, row)| row.iter().enumerate().filter(|&(col_idx, _)| col_idx != j).map(|(_, &val)| val).collect())
-                    .collect();
-
-                let submatrix = Matrix2D {
-                    rows: self.rows - 1,
-                    cols: self.cols - 1,
-                    data: submatrix_data,
-                };
-
-                let cofactor = if (i + j) % 2 == 0 { 1.0 } else { -1.0 };
-                row.push(cofactor * self.calculate_determinant(&submatrix.data).unwrap());
-            }
-            adj_a_data.push(row);
-        }
 
-        Matrix2D {
-            rows: self.rows,
-            cols: self.cols,
-            data: adj_a_data,
-        }
+    /// 计算 Hadamard 积（逐元素乘积）。
+    ///
+    /// # 返回
+    ///
+    /// 返回逐元素乘积；如果形状不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn hadamard(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.zip_with(other, |a, b| a * b)
     }
 
-    // 计算矩阵的特征值和特征向量
-    pub fn eigenvalue_eigenvector(&self) -> Option<(Vec<f64>, Vec<Vec<f64>>)> {
-        // 检查矩阵是否为方阵
-        if self.rows != self.cols {
-            return None;
-        }
-
-        // 构造特征值方程的左侧矩阵 A - λI
-        let a_minus_lambda_i: Matrix2D = self.subtract_identity();
+    /// 计算逐元素除法，除以零的元素按 IEEE 754 规则得到无穷大或 NaN。
+    ///
+    /// # 返回
+    ///
+    /// 返回逐元素商；如果形状不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn hadamard_divide(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.zip_with(other, |a, b| a / b)
+    }
 
-        // 求解特征值
-        let eigenvalues = match a_minus_lambda_i.determinant() {
-            Some(det) => self.find_eigenvalues(det),
-            None => return None,
-        };
+    /// 判断矩阵是否为对角矩阵，即主对角线以外的元素全为零；非方阵返回 `false`。
+    pub fn is_diagonal(&self) -> bool {
+        self.rows == self.cols && self.is_upper_triangular() && self.is_lower_triangular()
+    }
 
-        // 求解特征向量
-        let mut eigenvectors: Vec<Vec<f64>> = Vec::with_capacity(eigenvalues.len());
-        for &eigenvalue in &eigenvalues {
-            let eigenvector = self.solve_eigenvector(eigenvalue);
-            eigenvectors.push(eigenvector);
-        }
+    /// 判断矩阵是否为上三角矩阵，即主对角线以下的元素全为零。
+    pub fn is_upper_triangular(&self) -> bool {
+        (0..self.rows).all(|i| (0..i.min(self.cols)).all(|j| self[(i, j)].is_zero()))
+    }
 
-        Some((eigenvalues, eigenvectors))
+    /// 判断矩阵是否为下三角矩阵，即主对角线以上的元素全为零。
+    pub fn is_lower_triangular(&self) -> bool {
+        (0..self.rows).all(|i| (i + 1..self.cols).all(|j| self[(i, j)].is_zero()))
     }
 
-    // 辅助方法：构造 A - λI
-    fn subtract_identity(&self) -> Matrix2D {
-        let mut result_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            let mut row = Vec::with_capacity(self.cols);
-            for j in 0..self.cols {
-                let element = if i == j {
-                    self.data[i][j] - 1.0 // λ 对应的位置减去 1
-                } else {
-                    self.data[i][j]
-                };
-                row.push(element);
+    /// 水平拼接若干行数相同的矩阵，`f64` 矩阵可以直接使用 [`Matrix2D::hstack`]。
+    ///
+    /// # 参数
+    ///
+    /// * `blocks` - 自左向右排列的矩阵，允许为空（得到 0×0 矩阵）。
+    ///
+    /// # 返回
+    ///
+    /// 返回拼接后的矩阵；如果行数不一致，返回 `MatrixError::DimensionMismatch`，
+    /// 其中 `left` 为第一个矩阵的形状，`right` 为第一个不匹配矩阵的形状。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::identity(2);
+    /// let b = Matrix2D::new(vec![vec![5.0f32], vec![6.0]]).unwrap();
+    /// let c = Matrix2D::concat_horizontal(&[a, b]).unwrap();
+    /// assert_eq!(c.to_nested(), vec![vec![1.0, 0.0, 5.0], vec![0.0, 1.0, 6.0]]);
+    /// ```
+    pub fn concat_horizontal(blocks: &[Matrix2D<T>]) -> Result<Matrix2D<T>, MatrixError> {
+        let Some(first) = blocks.first() else {
+            return Ok(Matrix2D::filled(0, 0, T::zero()));
+        };
+        if let Some(block) = blocks.iter().find(|block| block.rows != first.rows) {
+            return Err(MatrixError::DimensionMismatch { left: first.shape(), right: block.shape() });
+        }
+        let cols = blocks.iter().map(|block| block.cols).sum();
+        let mut data = Vec::with_capacity(first.rows * cols);
+        for i in 0..first.rows {
+            for block in blocks {
+                data.extend_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
             }
-            result_data.push(row);
-        }
-
-        Matrix2D {
-            rows: self.rows,
-            cols: self.cols,
-            data: result_data,
         }
+        Ok(Matrix2D { rows: first.rows, cols, data })
     }
 
-    // 辅助方法：求解特征值方程的根
-    fn find_eigenvalues(&self, _det_a_minus_lambda_i: f64) -> Vec<f64> {
-        let mut eigenvalues = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            eigenvalues.push(self.data[i][i]);
+    /// 垂直拼接若干列数相同的矩阵，`f64` 矩阵可以直接使用 [`Matrix2D::vstack`]。
+    ///
+    /// # 参数
+    ///
+    /// * `blocks` - 自上而下排列的矩阵，允许为空（得到 0×0 矩阵）。
+    ///
+    /// # 返回
+    ///
+    /// 返回拼接后的矩阵；如果列数不一致，返回 `MatrixError::DimensionMismatch`，
+    /// 其中 `left` 为第一个矩阵的形状，`right` 为第一个不匹配矩阵的形状。
+    pub fn concat_vertical(blocks: &[Matrix2D<T>]) -> Result<Matrix2D<T>, MatrixError> {
+        let Some(first) = blocks.first() else {
+            return Ok(Matrix2D::filled(0, 0, T::zero()));
+        };
+        if let Some(block) = blocks.iter().find(|block| block.cols != first.cols) {
+            return Err(MatrixError::DimensionMismatch { left: first.shape(), right: block.shape() });
         }
-        eigenvalues
+        let data = blocks.iter().flat_map(|block| block.data.iter().copied()).collect();
+        Ok(Matrix2D { rows: blocks.iter().map(|block| block.rows).sum(), cols: first.cols, data })
     }
 
-    // 辅助方法：求解特征向量
-    fn solve_eigenvector(&self, eigenvalue: f64) -> Vec<f64> {
-        let mut augmented_matrix_data = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            let mut row = Vec::with_capacity(self.cols + 1);
-            for j in 0..self.cols {
-                row.push(self.data[i][j] - eigenvalue * if i == j { 1.0 } else { 0.0 });
+    /// 构造分块对角矩阵，各矩阵依次放在主对角线上，其余位置为零，`f64` 矩阵可以直接使用 [`Matrix2D::block_diag`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0f32, 2.0]]).unwrap();
+    /// let b = Matrix2D::new(vec![vec![3.0], vec![4.0]]).unwrap();
+    /// let d = Matrix2D::block_diagonal(&[a, b]);
+    /// assert_eq!(d.to_nested(), vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 3.0], vec![0.0, 0.0, 4.0]]);
+    /// ```
+    pub fn block_diagonal(blocks: &[Matrix2D<T>]) -> Matrix2D<T> {
+        let rows = blocks.iter().map(|block| block.rows).sum();
+        let cols = blocks.iter().map(|block| block.cols).sum();
+        let mut result = Matrix2D::filled(rows, cols, T::zero());
+        let (mut row_offset, mut col_offset) = (0, 0);
+        for block in blocks {
+            for i in 0..block.rows {
+                let start = (row_offset + i) * cols + col_offset;
+                result.data[start..start + block.cols].copy_from_slice(&block.data[i * block.cols..(i + 1) * block.cols]);
             }
-            row.push(0.0); // 右侧的常数项
-            augmented_matrix_data.push(row);
+            row_offset += block.rows;
+            col_offset += block.cols;
         }
+        result
+    }
+}
 
-        let mut augmented_matrix = Matrix2D {
-            rows: self.rows,
-            cols: self.cols + 1,
-            data: augmented_matrix_data,
-        };
-
-        // 简单的高斯消元法求解线性方程组
-        let mut solution = Vec::with_capacity(self.rows);
-        for i in 0..self.rows {
-            let mut pivot_row = i;
-            for j in i + 1..self.rows {
-                if augmented_matrix.data[j][i].abs() > augmented_matrix.data[pivot_row][i].abs() {
-                    pivot_row = j;
-                }
-            }
-
-            if augmented_matrix.data[pivot_row][i].abs() < EPSILON {
-                return vec![];
-            }
-
-            augmented_matrix.swap_rows(i, pivot_row);
-
-            for j in i + 1..self.rows {
-                let factor = augmented_matrix.data[j][i] / augmented_matrix.data[i][i];
-                for k in i..self.cols + 1 {
-                    augmented_matrix.data[j][k] -= factor * augmented_matrix.data[i][k];
-                }
-            }
-        }
+#[allow(dead_code)]
+impl<T: RealField> Matrix2D<T> {
+    /// 计算 Frobenius 范数 `‖A‖_F = sqrt(Σ a_ij²)`。
+    pub fn norm_frobenius(&self) -> T {
+        self.data.iter().fold(T::zero(), |sum, &x| sum + x * x).sqrt()
+    }
 
-        // 回代求解
-        for i in (0..self.rows).rev() {
-            let mut sum = 0.0;
-            for j in i + 1..self.cols {
-                sum += augmented_matrix.data[i][j] * solution[j - i - 1];
+    /// 计算 1-范数，即各列元素绝对值之和的最大值。
+    pub fn norm_1(&self) -> T {
+        let mut sums = vec![T::zero(); self.cols];
+        for row in self.data.chunks_exact(self.cols.max(1)) {
+            for (sum, value) in sums.iter_mut().zip(row) {
+                *sum += value.abs();
             }
-            solution.push((augmented_matrix.data[i][self.cols] - sum) / augmented_matrix.data[i][i]);
         }
-
-        // 归一化特征向量
-        let norm = solution.iter().fold(0.0, |acc, &x| acc + x.powi(2)).sqrt();
-        solution.iter().map(|&x| x / norm).collect()
+        sums.into_iter().fold(T::zero(), T::max)
     }
 
-    // 辅助方法：交换矩阵的两行
-    fn swap_rows(&self, i: usize, j: usize) {
-        let mut data_copy = self.data.clone();
-        data_copy.swap(i, j);
-        Matrix2D {
-            rows: self.rows,
-            cols: self.cols,
-            data: data_copy,
-        };
+    /// 计算 ∞-范数，即各行元素绝对值之和的最大值。
+    pub fn norm_inf(&self) -> T {
+        self.data
+            .chunks_exact(self.cols.max(1))
+            .map(|row| row.iter().fold(T::zero(), |sum, x| sum + x.abs()))
+            .fold(T::zero(), T::max)
     }
 
-    //////////////
-
-
-    // 计算矩阵的奇异值分解
-    pub fn svd(&self) -> (Matrix2D, Matrix2D, Matrix2D) {
-        let mut u = self.clone();
-        let mut vt = self.clone();
-
-        let mut s = Matrix2D::zeros(self.cols, self.cols);
-
-        // 初始化 U、Σ、V
-        let mut ut = Matrix2D::eye(self.cols);
-        let mut v = Matrix2D::eye(self.cols);
+    /// 判断矩阵是否在容差 `tol` 内对称，即 `|a_ij - a_ji| <= tol`；非方阵返回 `false`。
+    pub fn is_symmetric(&self, tol: T) -> bool {
+        self.rows == self.cols
+            && (0..self.rows).all(|i| (i + 1..self.cols).all(|j| (self[(i, j)] - self[(j, i)]).abs() <= tol))
+    }
 
-        // 迭代次数，可以根据需要调整
-        let max_iter = 100;
+    /// 判断矩阵是否在容差 `tol` 内正交，即 `AᵀA` 与单位矩阵的每个元素之差不超过 `tol`；非方阵返回 `false`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let (s, c) = 0.3_f64.sin_cos();
+    /// let rotation = Matrix2D::new(vec![vec![c, -s], vec![s, c]]).unwrap();
+    /// assert!(rotation.is_orthogonal(1e-12));
+    /// assert!(!(rotation * 2.0).is_orthogonal(1e-12));
+    /// ```
+    pub fn is_orthogonal(&self, tol: T) -> bool {
+        if self.rows != self.cols {
+            return false;
+        }
+        let gram = &self.transpose() * self;
+        gram.data.iter().enumerate().all(|(k, &value)| {
+            let expected = if k / self.cols == k % self.cols { T::one() } else { T::zero() };
+            (value - expected).abs() <= tol
+        })
+    }
+}
 
-        for _ in 0..max_iter {
-            // U的计算
-            let uu = u.transpose().multiply(&u);
-            let (u_eigenvalues, u_eigenvectors) = uu.eigen();
-            let u_sort_indices = Matrix2D::argsort(&u_eigenvalues);
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 创建 `f64` 单位矩阵，即 `Matrix2D::<f64>::identity(size)`。
+    ///
+    /// `zeros`、`eye` 以及下面的拼接函数只对 `f64` 提供，其他元素类型请使用对应的泛型版本，
+    /// 参见 [`Matrix2D`] 的“构造函数的选择”一节。
+    pub fn eye(size: usize) -> Matrix2D {
+        Matrix2D::identity(size)
+    }
 
-            u = u.multiply_by_vector(&u_eigenvectors.column(u_sort_indices[0]));
+    /// 创建 `f64` 全零矩阵，即 `Matrix2D::filled(rows, cols, 0.0)`。
+    pub fn zeros(rows: usize, cols: usize) -> Matrix2D {
+        Matrix2D::filled(rows, cols, 0.0)
+    }
 
-            // V的计算
-            let vv = vt.transpose().multiply(&vt);
-            let (v_eigenvalues, v_eigenvectors) = vv.eigen();
-            let v_sort_indices = Matrix2D::argsort(&v_eigenvalues);
+    /// 水平拼接若干行数相同的 `f64` 矩阵，即 [`Matrix2D::concat_horizontal`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::eye(2);
+    /// let b = Matrix2D::new(vec![vec![5.0], vec![6.0]]).unwrap();
+    /// let c = Matrix2D::hstack(&[a, b]).unwrap();
+    /// assert_eq!(c.to_nested(), vec![vec![1.0, 0.0, 5.0], vec![0.0, 1.0, 6.0]]);
+    /// ```
+    pub fn hstack(blocks: &[Matrix2D]) -> Result<Matrix2D, MatrixError> {
+        Matrix2D::concat_horizontal(blocks)
+    }
 
-            vt = vt.multiply_by_vector(&v_eigenvectors.column(v_sort_indices[0])).transpose();
+    /// 垂直拼接若干列数相同的 `f64` 矩阵，即 [`Matrix2D::concat_vertical`]。
+    pub fn vstack(blocks: &[Matrix2D]) -> Result<Matrix2D, MatrixError> {
+        Matrix2D::concat_vertical(blocks)
+    }
 
-            // 更新 Σ
-            s.data = u.transpose().multiply(&self.multiply(&vt)).data;
+    /// 构造 `f64` 分块对角矩阵，即 [`Matrix2D::block_diagonal`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0]]).unwrap();
+    /// let b = Matrix2D::new(vec![vec![3.0], vec![4.0]]).unwrap();
+    /// let d = Matrix2D::block_diag(&[a, b]);
+    /// assert_eq!(d.to_nested(), vec![vec![1.0, 2.0, 0.0], vec![0.0, 0.0, 3.0], vec![0.0, 0.0, 4.0]]);
+    /// ```
+    pub fn block_diag(blocks: &[Matrix2D]) -> Matrix2D {
+        Matrix2D::block_diagonal(blocks)
+    }
 
-            // 更新 U、V
-            ut = ut.multiply(&u);
-            v = v.multiply(&vt);
-        }
+    /// 计算矩阵的行列式。
+    ///
+    /// 基于带部分主元选取的 LU 分解计算，复杂度为 O(n³)。
+    ///
+    /// # 返回
+    ///
+    /// 如果矩阵为方阵，返回行列式的值；否则返回 `MatrixError::NotSquare`。
+    pub fn determinant(&self) -> Result<f64, MatrixError> {
+        Ok(self.lu()?.determinant())
+    }
 
-        // Σ的对角线元素开平方得到奇异值
-        for i in 0..self.cols {
-            s.data[i][i] = s.data[i][i].sqrt();
-        }
+    /// 计算矩阵的逆矩阵。
+    ///
+    /// 基于带部分主元选取的 LU 分解计算。如需对同一矩阵多次求解，
+    /// 请直接使用 [`Matrix2D::lu`] 并复用分解结果。
+    ///
+    /// # 返回
+    ///
+    /// 如果矩阵为非奇异方阵，返回逆矩阵；矩阵不是方阵时返回 `MatrixError::NotSquare`，
+    /// 奇异时返回带有失效主元下标的 `MatrixError::Singular`。
+    pub fn inverse(&self) -> Result<Matrix2D, MatrixError> {
+        self.lu()?.inverse()
+    }
 
-        (ut, s, v)
+    /// 计算实对称矩阵的特征值和特征向量。
+    ///
+    /// 基于循环 Jacobi 方法，只读取矩阵的上三角部分，详见 [`Matrix2D::symmetric_eigen`]。
+    ///
+    /// # 返回
+    ///
+    /// 返回按升序排列的特征值，以及与之一一对应的单位特征向量；
+    /// 如果矩阵不是方阵，返回 `MatrixError::NotSquare`；迭代未收敛时返回 `MatrixError::NotConverged`。
+    pub fn eigenvalue_eigenvector(&self) -> Result<(Vec<f64>, Vec<Vec<f64>>), MatrixError> {
+        let eigen = self.symmetric_eigen()?;
+        let eigenvectors = (0..self.cols).map(|j| eigen.eigenvectors.column(j)).collect();
+        Ok((eigen.eigenvalues, eigenvectors))
     }
-    // 计算矩阵乘以向量
-    pub fn multiply_by_vector(&self, vector: &Vec<f64>) -> Matrix2D {
-        let mut result = Matrix2D::zeros(self.rows, 1);
 
-        for i in 0..self.rows {
-            for j in 0..1 {
-                for k in 0..self.cols {
-                    result.data[i][j] += self.data[i][k] * vector[k];
-                }
-            }
-        }
+    /// 求解实对称矩阵的特征值和特征向量。
+    ///
+    /// # 返回
+    ///
+    /// 返回按升序排列的特征值，以及以列存放对应特征向量的标准正交矩阵。
+    ///
+    /// 如果矩阵不是方阵或迭代未收敛，返回相应的 `MatrixError`；
+    /// 需要迭代次数等更多信息时请使用 [`Matrix2D::symmetric_eigen`]。
+    pub fn eigen(&self) -> Result<(Vec<f64>, Matrix2D), MatrixError> {
+        let eigen = self.symmetric_eigen()?;
+        Ok((eigen.eigenvalues, eigen.eigenvectors))
+    }
 
-        result
+    // 对向量进行排序并返回排序后的索引
+    pub fn argsort(vector: &[f64]) -> Vec<usize> {
+        let mut indices: Vec<usize> = (0..vector.len()).collect();
+        indices.sort_by(|&a, &b| vector[a].partial_cmp(&vector[b]).unwrap());
+        indices
     }
-    // 单位矩阵
-    pub fn eye(size: usize) -> Matrix2D {
-        let mut result = Matrix2D::zeros(size, size);
-        for i in 0..size {
-            result.data[i][i] = 1.0;
-        }
-        result
+
+    /// 计算谱范数（2-范数），即最大奇异值。
+    ///
+    /// # 返回
+    ///
+    /// 返回最大奇异值，空矩阵返回 0；如果奇异值分解未收敛，返回 `MatrixError::NotConverged`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![3.0, 0.0], vec![4.0, 0.0]]).unwrap();
+    /// assert!((a.norm_2().unwrap() - 5.0).abs() < 1e-12);
+    /// assert_eq!(a.norm_1(), 7.0);
+    /// assert_eq!(a.norm_inf(), 4.0);
+    /// ```
+    pub fn norm_2(&self) -> Result<f64, MatrixError> {
+        if self.data.is_empty() {
+            return Ok(0.0);
+        }
+        Ok(self.svd()?.singular_values[0])
     }
 
-    // 全0矩阵
-    pub fn zeros(rows: usize, cols: usize) -> Matrix2D {
-        Matrix2D {
-            rows,
-            cols,
-            data: vec![vec![0.0; cols]; rows],
-        }
+    /// 判断矩阵是否对称正定。
+    ///
+    /// 先以 `n·ε·‖A‖_F` 为容差检查对称性，再尝试 Cholesky 分解。非方阵返回 `false`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// assert!(Matrix2D::new(vec![vec![2.0, -1.0], vec![-1.0, 2.0]]).unwrap().is_positive_definite());
+    /// assert!(!Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap().is_positive_definite());
+    /// ```
+    pub fn is_positive_definite(&self) -> bool {
+        let tol = self.rows as f64 * f64::EPSILON * self.norm_frobenius();
+        self.is_symmetric(tol) && self.cholesky().is_ok()
     }
-    // 求解特征值和特征向量
-    pub fn eigen(&self) -> (Vec<f64>, Matrix2D) {
-        // 简化处理，假设矩阵是实对称矩阵
-        // 在实际应用中，可以使用更复杂的算法来处理一般情况
-        let n = self.rows;
-        let mut a = self.clone();
-        let mut v = Matrix2D::eye(n);
-
-        let mut d = Vec::with_capacity(n);
-        for i in 0..n {
-            d.push(a.data[i][i]);
-        }
+}
 
-        // 迭代次数，可以根据需要调整
-        let max_iter = 100;
+impl<T> Index<(usize, usize)> for Matrix2D<T> {
+    type Output = T;
 
-        for _ in 0..max_iter {
-            let mut sm = 0.0;
-            for i in 0..n - 1 {
-                for j in i + 1..n {
-                    sm += f64::abs(a.data[i][j]);
-                }
-            }
+    fn index(&self, (i, j): (usize, usize)) -> &T {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &self.data[i * self.cols + j]
+    }
+}
 
-            if sm == 0.0 {
-                // 矩阵已经对称
-                return (d, v);
-            }
+impl<T> IndexMut<(usize, usize)> for Matrix2D<T> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut T {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &mut self.data[i * self.cols + j]
+    }
+}
 
-            let tresh = if a.data[0][0] < a.data[0][n - 1] {
-                0.2 * sm / (n as f64)
-            } else {
-                0.2 * sm / (n as f64)
-            };
-
-            for ip in 0..n - 1 {
-                for iq in ip + 1..n {
-                    let g = 100.0 * f64::abs(a.data[ip][iq]);
-
-                    if ip > 0 && ip < n - 1 {
-                        let mut _h = 0.5 * (a.data[ip][ip] - a.data[iq][iq]);
-                        let t = f64::sqrt(_h * _h + g * g);
-                        _h = _h / t;
-                        let c = f64::sqrt(0.5 + 0.5 * _h);
-                        let  s = -0.5 * _h / c;
-                        _h = 0.5 * (g / c) * (_h / c);
-                        let mut b = Matrix2D::eye(n);
-                        b.data[ip][ip] = c;
-                        b.data[iq][iq] = c;
-                        b.data[ip][iq] = s;
-                        b.data[iq][ip] = -s;
-
-                        let  at = b.transpose().multiply(&a).multiply(&b);
-                        a = at.clone();
-                        let  vt = b.multiply(&v);
-                        v = vt.clone();
-                    }
-
-                    if f64::abs(a.data[ip][iq]) > tresh {
-                        let _h = a.data[ip][ip] - a.data[iq][iq];
-                        let t = if _h.abs() + g == _h.abs() {
-                            a.data[ip][iq] / _h
-                        } else {
-                            let theta = 0.5 * _h / (a.data[ip][iq]);
-                            1.0 / (f64::abs(theta) + f64::sqrt(1.0 + theta * theta))
-                        };
-
-                        let c = 1.0 / f64::sqrt(1.0 + t * t);
-                        let s = t * c;
-
-                        let _z = 1.0 / f64::sqrt(1.0 + t * t);
-                        let mut b = Matrix2D::eye(n);
-                        b.data[ip][ip] = c;
-                        b.data[iq][iq] = c;
-                        b.data[ip][iq] = s;
-                        b.data[iq][ip] = -s;
-
-                        let  at = b.transpose().multiply(&a).multiply(&b);
-                        a = at.clone();
-                        let  vt = b.multiply(&v);
-                        v = vt.clone();
-                    }
-                }
-            }
-        }
+impl<T: Clone> TryFrom<Vec<Vec<T>>> for Matrix2D<T> {
+    type Error = MatrixError;
 
-        (d, v)
+    /// 由旧版的嵌套向量形式转换，等价于 [`Matrix2D::from_rows`]。
+    fn try_from(rows: Vec<Vec<T>>) -> Result<Self, Self::Error> {
+        Matrix2D::from_rows(rows)
     }
+}
 
-    // 对向量进行排序并返回排序后的索引
-    pub fn argsort(vector: &Vec<f64>) -> Vec<usize> {
-        let mut indices: Vec<usize> = (0..vector.len()).collect();
-        indices.sort_by(|&a, &b| vector[a].partial_cmp(&vector[b]).unwrap());
-        indices
+impl<T: Clone> From<Matrix2D<T>> for Vec<Vec<T>> {
+    /// 转换为旧版的嵌套向量形式，等价于 [`Matrix2D::to_nested`]。
+    fn from(matrix: Matrix2D<T>) -> Self {
+        matrix.to_nested()
     }
-    // 获取矩阵的某一列
-    pub fn column(&self, col_index: usize) -> Vec<f64> {
-        assert!(col_index < self.cols, "Column index out of bounds.");
-        self.data.iter().map(|row| row[col_index]).collect()
-    }
-
 }
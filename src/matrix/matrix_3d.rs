@@ -0,0 +1,361 @@
+use std::ops::{Index, IndexMut};
+
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 表示形状为 d0×d1×d2 的三维矩阵（三阶张量）。
+///
+/// 元素按行优先顺序连续存放，第 `(i, j, k)` 个元素位于 `data[(i * d1 + j) * d2 + k]`。
+/// 通常把第 0 轴视为批次维，即一个 `Matrix3D` 是 d0 个 d1×d2 矩阵的堆叠。
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix3D {
+    /// 三个轴的长度 `[d0, d1, d2]`。
+    shape: [usize; 3],
+    /// 按行优先顺序连续存储的数据。
+    data: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl Matrix3D {
+    /// 创建全零的三维矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `shape` - 三个轴的长度 `[d0, d1, d2]`。
+    pub fn zeros(shape: [usize; 3]) -> Matrix3D {
+        Matrix3D { shape, data: vec![0.0; shape.iter().product()] }
+    }
+
+    /// 由按行优先顺序排列的数据创建三维矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `shape` - 三个轴的长度 `[d0, d1, d2]`。
+    /// * `data` - 长度为 `d0 * d1 * d2` 的数据。
+    ///
+    /// # 返回
+    ///
+    /// 返回创建的三维矩阵；如果数据长度与形状不符，返回 `MatrixError::DataLength`。
+    pub fn from_vec(shape: [usize; 3], data: Vec<f64>) -> Result<Matrix3D, MatrixError> {
+        let expected = shape.iter().product();
+        if data.len() != expected {
+            return Err(MatrixError::DataLength { expected, found: data.len() });
+        }
+        Ok(Matrix3D { shape, data })
+    }
+
+    /// 由元素生成函数创建三维矩阵，`f(i, j, k)` 给出对应位置的元素。
+    pub fn from_fn<F>(shape: [usize; 3], mut f: F) -> Matrix3D
+    where
+        F: FnMut(usize, usize, usize) -> f64,
+    {
+        let mut data = Vec::with_capacity(shape.iter().product());
+        for i in 0..shape[0] {
+            for j in 0..shape[1] {
+                for k in 0..shape[2] {
+                    data.push(f(i, j, k));
+                }
+            }
+        }
+        Matrix3D { shape, data }
+    }
+
+    /// 沿第 0 轴堆叠若干个同形状的二维矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `matrices` - 待堆叠的矩阵，形状必须相同；允许为空，此时得到空张量。
+    ///
+    /// # 返回
+    ///
+    /// 返回形状为 `[matrices.len(), rows, cols]` 的三维矩阵；如果矩阵形状不一致，
+    /// 返回 `MatrixError::DimensionMismatch`；如果没有输入矩阵，返回形状为 `[0, 0, 0]` 的空张量。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::matrix::matrix_3d::Matrix3D;
+    ///
+    /// let stack = Matrix3D::stack(&[Matrix2D::eye(2), Matrix2D::zeros(2, 2)]).unwrap();
+    /// assert_eq!(stack.shape(), [2, 2, 2]);
+    /// assert_eq!(stack.matrix(0), Matrix2D::eye(2));
+    /// ```
+    pub fn stack(matrices: &[Matrix2D]) -> Result<Matrix3D, MatrixError> {
+        let (rows, cols) = matrices.first().map_or((0, 0), |m| m.shape());
+        let mut data = Vec::with_capacity(matrices.len() * rows * cols);
+        for matrix in matrices {
+            if matrix.shape() != (rows, cols) {
+                return Err(MatrixError::DimensionMismatch { left: (rows, cols), right: matrix.shape() });
+            }
+            data.extend_from_slice(matrix.as_slice());
+        }
+        Ok(Matrix3D { shape: [matrices.len(), rows, cols], data })
+    }
+
+    /// 返回三个轴的长度 `[d0, d1, d2]`。
+    pub fn shape(&self) -> [usize; 3] {
+        self.shape
+    }
+
+    /// 以按行优先顺序排列的切片形式访问全部元素。
+    pub fn as_slice(&self) -> &[f64] {
+        &self.data
+    }
+
+    /// 以按行优先顺序排列的可变切片形式访问全部元素。
+    pub fn as_mut_slice(&mut self) -> &mut [f64] {
+        &mut self.data
+    }
+
+    /// 计算 `(i, j, k)` 在连续存储中的偏移量。
+    fn offset(&self, i: usize, j: usize, k: usize) -> usize {
+        (i * self.shape[1] + j) * self.shape[2] + k
+    }
+
+    /// 沿指定轴取出一个二维切片。
+    ///
+    /// # 参数
+    ///
+    /// * `axis` - 切片所沿的轴，取值为 0、1 或 2。
+    /// * `index` - 该轴上的下标。
+    ///
+    /// # 返回
+    ///
+    /// 返回由其余两个轴按原顺序组成的矩阵：沿第 0 轴得到 d1×d2，沿第 1 轴得到 d0×d2，
+    /// 沿第 2 轴得到 d0×d1。
+    ///
+    /// # 注意
+    ///
+    /// 如果 `axis` 大于 2 或 `index` 越界，将会引发 panic。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_3d::Matrix3D;
+    ///
+    /// let t = Matrix3D::from_fn([2, 3, 4], |i, j, k| (100 * i + 10 * j + k) as f64);
+    /// assert_eq!(t.slice(1, 2).shape(), (2, 4));
+    /// assert_eq!(t.slice(1, 2)[(1, 3)], 123.0);
+    /// assert_eq!(t.slice(2, 3)[(1, 2)], 123.0);
+    /// ```
+    pub fn slice(&self, axis: usize, index: usize) -> Matrix2D {
+        assert!(axis < 3, "Axis must be 0, 1 or 2.");
+        assert!(index < self.shape[axis], "Slice index out of bounds.");
+        let [d0, d1, d2] = self.shape;
+        match axis {
+            0 => Matrix2D::from_fn(d1, d2, |j, k| self.data[self.offset(index, j, k)]),
+            1 => Matrix2D::from_fn(d0, d2, |i, k| self.data[self.offset(i, index, k)]),
+            _ => Matrix2D::from_fn(d0, d1, |i, j| self.data[self.offset(i, j, index)]),
+        }
+    }
+
+    /// 取出第 0 轴上的第 `index` 个矩阵，等价于 `slice(0, index)`。
+    pub fn matrix(&self, index: usize) -> Matrix2D {
+        self.slice(0, index)
+    }
+
+    /// 对每个元素应用函数，返回同形状的新三维矩阵。
+    pub fn map<F>(&self, f: F) -> Matrix3D
+    where
+        F: Fn(f64) -> f64,
+    {
+        Matrix3D { shape: self.shape, data: self.data.iter().map(|&x| f(x)).collect() }
+    }
+
+    /// 按广播规则逐元素相加。
+    ///
+    /// 两个操作数在每个轴上的长度必须相等，或其中之一为 1（长度为 1 的轴会被复制以匹配另一方）。
+    ///
+    /// # 返回
+    ///
+    /// 返回广播后形状的结果；如果形状无法广播，返回 `MatrixError::BroadcastMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::matrix::matrix_3d::Matrix3D;
+    ///
+    /// let batch = Matrix3D::zeros([3, 2, 2]);
+    /// // 二维矩阵视为形状 [1, 2, 2]，加到批次中的每一个矩阵上
+    /// let shifted = batch.add(&Matrix3D::from(Matrix2D::eye(2))).unwrap();
+    /// assert_eq!(shifted.matrix(2), Matrix2D::eye(2));
+    /// ```
+    pub fn add(&self, other: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+        self.broadcast_with(other, |a, b| a + b)
+    }
+
+    /// 按广播规则逐元素相减，规则同 [`Matrix3D::add`]。
+    pub fn subtract(&self, other: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+        self.broadcast_with(other, |a, b| a - b)
+    }
+
+    /// 按广播规则逐元素相乘（Hadamard 积），规则同 [`Matrix3D::add`]。
+    pub fn mul_elementwise(&self, other: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+        self.broadcast_with(other, |a, b| a * b)
+    }
+
+    /// 按广播规则逐元素相除，规则同 [`Matrix3D::add`]。
+    pub fn div_elementwise(&self, other: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+        self.broadcast_with(other, |a, b| a / b)
+    }
+
+    /// 将两个操作数广播到同一形状后逐元素组合。
+    fn broadcast_with<F>(&self, other: &Matrix3D, f: F) -> Result<Matrix3D, MatrixError>
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        let mut shape = [0; 3];
+        for (axis, (&a, &b)) in shape.iter_mut().zip(self.shape.iter().zip(&other.shape)) {
+            *axis = if a == b || b == 1 {
+                a
+            } else if a == 1 {
+                b
+            } else {
+                return Err(MatrixError::BroadcastMismatch { left: self.shape, right: other.shape });
+            };
+        }
+
+        // 长度为 1 的轴下标恒取 0
+        let pick = |t: &Matrix3D, i: usize, j: usize, k: usize| {
+            let [d0, d1, d2] = t.shape;
+            t.data[t.offset(i.min(d0 - 1), j.min(d1 - 1), k.min(d2 - 1))]
+        };
+        Ok(Matrix3D::from_fn(shape, |i, j, k| f(pick(self, i, j, k), pick(other, i, j, k))))
+    }
+
+    /// 计算全部元素之和。
+    pub fn sum(&self) -> f64 {
+        self.data.iter().sum()
+    }
+
+    /// 沿指定轴求和，返回由其余两个轴组成的矩阵，形状约定同 [`Matrix3D::slice`]。
+    ///
+    /// # 注意
+    ///
+    /// 如果 `axis` 大于 2，将会引发 panic。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_3d::Matrix3D;
+    ///
+    /// let t = Matrix3D::from_fn([2, 2, 3], |i, _, k| (i * 3 + k) as f64);
+    /// assert_eq!(t.sum_axis(0).to_nested(), vec![vec![3.0, 5.0, 7.0], vec![3.0, 5.0, 7.0]]);
+    /// assert_eq!(t.sum_axis(2)[(1, 0)], 12.0);
+    /// ```
+    pub fn sum_axis(&self, axis: usize) -> Matrix2D {
+        self.reduce_axis(axis, 0.0, |acc, x| acc + x)
+    }
+
+    /// 沿指定轴求平均值，形状约定同 [`Matrix3D::slice`]；被约化的轴长度为 0 时结果为 NaN。
+    pub fn mean_axis(&self, axis: usize) -> Matrix2D {
+        assert!(axis < 3, "Axis must be 0, 1 or 2.");
+        let count = self.shape[axis] as f64;
+        let mut mean = self.sum_axis(axis);
+        mean.as_mut_slice().iter_mut().for_each(|x| *x /= count);
+        mean
+    }
+
+    /// 沿指定轴求最大值，形状约定同 [`Matrix3D::slice`]；被约化的轴长度为 0 时结果为负无穷。
+    pub fn max_axis(&self, axis: usize) -> Matrix2D {
+        self.reduce_axis(axis, f64::NEG_INFINITY, f64::max)
+    }
+
+    /// 沿指定轴折叠，结果矩阵由其余两个轴按原顺序组成。
+    fn reduce_axis<F>(&self, axis: usize, init: f64, f: F) -> Matrix2D
+    where
+        F: Fn(f64, f64) -> f64,
+    {
+        assert!(axis < 3, "Axis must be 0, 1 or 2.");
+        let [d0, d1, d2] = self.shape;
+        let fold = |index: &dyn Fn(usize) -> usize| {
+            (0..self.shape[axis]).fold(init, |acc, t| f(acc, self.data[index(t)]))
+        };
+        match axis {
+            0 => Matrix2D::from_fn(d1, d2, |j, k| fold(&|t| self.offset(t, j, k))),
+            1 => Matrix2D::from_fn(d0, d2, |i, k| fold(&|t| self.offset(i, t, k))),
+            _ => Matrix2D::from_fn(d0, d1, |i, j| fold(&|t| self.offset(i, j, t))),
+        }
+    }
+
+    /// 沿第 0 轴批量做矩阵乘法，第 `b` 个结果为 `self.matrix(b) * other.matrix(b)`。
+    ///
+    /// 第 0 轴支持广播：任一操作数的批次数为 1 时，它会与另一方的每个矩阵相乘，
+    /// 例如用同一个变换矩阵批量作用于一组矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 形状为 `[batch, k, n]` 的右操作数，其中 `k` 必须等于当前张量的 d2。
+    ///
+    /// # 返回
+    ///
+    /// 返回形状为 `[batch, d1, n]` 的结果；内维不一致时返回 `MatrixError::DimensionMismatch`，
+    /// 批次数无法广播时返回 `MatrixError::BroadcastMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    /// use rs_math::matrix::matrix_3d::Matrix3D;
+    ///
+    /// let points = Matrix3D::from_fn([4, 2, 1], |i, j, _| (i + j) as f64);
+    /// let scale = Matrix3D::from(Matrix2D::new(vec![vec![2.0, 0.0], vec![0.0, 3.0]]).unwrap());
+    /// let scaled = scale.batch_multiply(&points).unwrap();
+    /// assert_eq!(scaled.shape(), [4, 2, 1]);
+    /// assert_eq!(scaled.matrix(3).column(0), vec![6.0, 12.0]);
+    /// ```
+    pub fn batch_multiply(&self, other: &Matrix3D) -> Result<Matrix3D, MatrixError> {
+        let [b0, m, k] = self.shape;
+        let [b1, k2, n] = other.shape;
+        if k != k2 {
+            return Err(MatrixError::DimensionMismatch { left: (m, k), right: (k2, n) });
+        }
+        let batch = match (b0, b1) {
+            _ if b0 == b1 => b0,
+            (1, _) => b1,
+            (_, 1) => b0,
+            _ => return Err(MatrixError::BroadcastMismatch { left: self.shape, right: other.shape }),
+        };
+
+        let mut data = Vec::with_capacity(batch * m * n);
+        for b in 0..batch {
+            let left = self.matrix(b.min(b0 - 1));
+            let right = other.matrix(b.min(b1 - 1));
+            data.extend_from_slice(left.multiply(&right)?.as_slice());
+        }
+        Ok(Matrix3D { shape: [batch, m, n], data })
+    }
+}
+
+impl Index<(usize, usize, usize)> for Matrix3D {
+    type Output = f64;
+
+    fn index(&self, (i, j, k): (usize, usize, usize)) -> &f64 {
+        assert!(
+            i < self.shape[0] && j < self.shape[1] && k < self.shape[2],
+            "Matrix index out of bounds."
+        );
+        &self.data[self.offset(i, j, k)]
+    }
+}
+
+impl IndexMut<(usize, usize, usize)> for Matrix3D {
+    fn index_mut(&mut self, (i, j, k): (usize, usize, usize)) -> &mut f64 {
+        assert!(
+            i < self.shape[0] && j < self.shape[1] && k < self.shape[2],
+            "Matrix index out of bounds."
+        );
+        let offset = self.offset(i, j, k);
+        &mut self.data[offset]
+    }
+}
+
+impl From<Matrix2D> for Matrix3D {
+    /// 把二维矩阵视为批次数为 1 的三维矩阵，形状为 `[1, rows, cols]`，可参与广播运算。
+    fn from(matrix: Matrix2D) -> Self {
+        let (rows, cols) = matrix.shape();
+        Matrix3D { shape: [1, rows, cols], data: matrix.into_vec() }
+    }
+}
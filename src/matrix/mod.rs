@@ -1,2 +1,20 @@
+pub mod banded;
+pub mod cholesky;
+pub mod complex;
+pub mod eigen;
+pub mod elimination;
+pub mod error;
+pub mod format;
+pub mod functions;
+pub mod gemm;
+pub mod io;
+pub mod krylov;
+pub mod lu;
+pub mod ops;
+pub mod qr;
+pub mod smatrix;
+pub mod sparse;
+pub mod svd;
+pub mod view;
 pub mod matrix_2d;
-pub mod matrix_3d;
\ No newline at end of file
+pub mod matrix_3d;
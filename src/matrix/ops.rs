@@ -0,0 +1,269 @@
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::number::scalar::Scalar;
+
+#[allow(dead_code)]
+impl<T: Scalar> Matrix2D<T> {
+    /// `+` 运算符的带检查版本，形状不同时返回错误而不是引发 panic，等价于 [`Matrix2D::add`]。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 与当前矩阵相加的另一个矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回相加结果；如果两个矩阵形状不同，返回 `MatrixError::DimensionMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(a.try_add(&a).unwrap(), &a * 2.0);
+    /// assert!(a.try_add(&Matrix2D::zeros(1, 2)).is_err());
+    /// ```
+    pub fn try_add(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.add(other)
+    }
+
+    /// `-` 运算符的带检查版本，形状不同时返回错误而不是引发 panic，等价于 [`Matrix2D::subtract`]。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 从当前矩阵中减去的另一个矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回相减结果；如果两个矩阵形状不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn try_sub(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.subtract(other)
+    }
+
+    /// `*` 运算符的带检查版本，内维不一致时返回错误而不是引发 panic，等价于 [`Matrix2D::multiply`]。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 右乘的矩阵，其行数必须等于当前矩阵的列数。
+    ///
+    /// # 返回
+    ///
+    /// 返回相乘结果；如果维度不符合相乘规则，返回 `MatrixError::DimensionMismatch`。
+    pub fn try_mul(&self, other: &Matrix2D<T>) -> Result<Matrix2D<T>, MatrixError> {
+        self.multiply(other)
+    }
+
+    /// 矩阵乘以向量 `A * x` 的带检查版本，长度不符时返回错误而不是引发 panic。
+    ///
+    /// # 参数
+    ///
+    /// * `vector` - 长度等于矩阵列数的向量。
+    ///
+    /// # 返回
+    ///
+    /// 返回长度等于矩阵行数的结果向量；如果长度不符，返回 `MatrixError::DimensionMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// assert_eq!(a.try_mul_vector(&[1.0, 1.0]).unwrap(), vec![3.0, 7.0]);
+    /// ```
+    pub fn try_mul_vector(&self, vector: &[T]) -> Result<Vec<T>, MatrixError> {
+        if self.cols() != vector.len() {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: (vector.len(), 1) });
+        }
+        let cols = self.cols();
+        Ok((0..self.rows())
+            .map(|i| {
+                self.as_slice()[i * cols..(i + 1) * cols]
+                    .iter()
+                    .zip(vector)
+                    .fold(T::zero(), |sum, (&a, &b)| sum + a * b)
+            })
+            .collect())
+    }
+
+    /// 将矩阵的每个元素乘以标量。
+    fn scale(&self, scalar: T) -> Matrix2D<T> {
+        let data = self.as_slice().iter().map(|&x| x * scalar).collect();
+        Matrix2D::from_vec(self.rows(), self.cols(), data).unwrap()
+    }
+}
+
+/// 为矩阵与矩阵之间的二元运算生成所有权与引用的四种组合，形状不符时引发 panic。
+macro_rules! impl_matrix_binary_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl<T: Scalar> $trait<&Matrix2D<T>> for &Matrix2D<T> {
+            type Output = Matrix2D<T>;
+
+            fn $method(self, rhs: &Matrix2D<T>) -> Matrix2D<T> {
+                self.$checked(rhs).unwrap_or_else(|e| panic!("{}", e))
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix2D<T>> for &Matrix2D<T> {
+            type Output = Matrix2D<T>;
+
+            fn $method(self, rhs: Matrix2D<T>) -> Matrix2D<T> {
+                <&Matrix2D<T> as $trait<&Matrix2D<T>>>::$method(self, &rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<&Matrix2D<T>> for Matrix2D<T> {
+            type Output = Matrix2D<T>;
+
+            fn $method(self, rhs: &Matrix2D<T>) -> Matrix2D<T> {
+                <&Matrix2D<T> as $trait<&Matrix2D<T>>>::$method(&self, rhs)
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix2D<T>> for Matrix2D<T> {
+            type Output = Matrix2D<T>;
+
+            fn $method(self, rhs: Matrix2D<T>) -> Matrix2D<T> {
+                <&Matrix2D<T> as $trait<&Matrix2D<T>>>::$method(&self, &rhs)
+            }
+        }
+    };
+}
+
+impl_matrix_binary_op!(Add, add, try_add);
+impl_matrix_binary_op!(Sub, sub, try_sub);
+impl_matrix_binary_op!(Mul, mul, try_mul);
+
+/// 为复合赋值运算生成右操作数为所有权与引用的两种实现。
+macro_rules! impl_matrix_assign_op {
+    ($trait:ident, $method:ident, $checked:ident) => {
+        impl<T: Scalar> $trait<&Matrix2D<T>> for Matrix2D<T> {
+            fn $method(&mut self, rhs: &Matrix2D<T>) {
+                *self = self.$checked(rhs).unwrap_or_else(|e| panic!("{}", e));
+            }
+        }
+
+        impl<T: Scalar> $trait<Matrix2D<T>> for Matrix2D<T> {
+            fn $method(&mut self, rhs: Matrix2D<T>) {
+                self.$method(&rhs);
+            }
+        }
+    };
+}
+
+impl_matrix_assign_op!(AddAssign, add_assign, try_add);
+impl_matrix_assign_op!(SubAssign, sub_assign, try_sub);
+impl_matrix_assign_op!(MulAssign, mul_assign, try_mul);
+
+impl<T: Scalar> Mul<T> for &Matrix2D<T> {
+    type Output = Matrix2D<T>;
+
+    fn mul(self, scalar: T) -> Matrix2D<T> {
+        self.scale(scalar)
+    }
+}
+
+impl<T: Scalar> Mul<T> for Matrix2D<T> {
+    type Output = Matrix2D<T>;
+
+    fn mul(mut self, scalar: T) -> Matrix2D<T> {
+        self *= scalar;
+        self
+    }
+}
+
+/// 为标量在左侧的乘法生成实现；孤儿规则不允许对泛型 `T` 实现，因此逐个列出元素类型。
+macro_rules! impl_scalar_left_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<&Matrix2D<$t>> for $t {
+            type Output = Matrix2D<$t>;
+
+            fn mul(self, matrix: &Matrix2D<$t>) -> Matrix2D<$t> {
+                matrix.scale(self)
+            }
+        }
+
+        impl Mul<Matrix2D<$t>> for $t {
+            type Output = Matrix2D<$t>;
+
+            fn mul(self, matrix: Matrix2D<$t>) -> Matrix2D<$t> {
+                matrix * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_left_mul!(f32, f64);
+
+impl<T: Scalar> MulAssign<T> for Matrix2D<T> {
+    fn mul_assign(&mut self, scalar: T) {
+        self.as_mut_slice().iter_mut().for_each(|x| *x *= scalar);
+    }
+}
+
+impl<T: Scalar> Mul<&[T]> for &Matrix2D<T> {
+    type Output = Vec<T>;
+
+    /// 矩阵乘以向量，向量长度与列数不符时引发 panic。
+    fn mul(self, vector: &[T]) -> Vec<T> {
+        self.try_mul_vector(vector).unwrap_or_else(|e| panic!("{}", e))
+    }
+}
+
+impl<T: Scalar> Mul<&[T]> for Matrix2D<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, vector: &[T]) -> Vec<T> {
+        &self * vector
+    }
+}
+
+impl<T: Scalar> Mul<&Vec<T>> for &Matrix2D<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, vector: &Vec<T>) -> Vec<T> {
+        self * vector.as_slice()
+    }
+}
+
+impl<T: Scalar> Mul<Vec<T>> for &Matrix2D<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, vector: Vec<T>) -> Vec<T> {
+        self * vector.as_slice()
+    }
+}
+
+impl<T: Scalar> Mul<&Vec<T>> for Matrix2D<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, vector: &Vec<T>) -> Vec<T> {
+        &self * vector.as_slice()
+    }
+}
+
+impl<T: Scalar> Mul<Vec<T>> for Matrix2D<T> {
+    type Output = Vec<T>;
+
+    fn mul(self, vector: Vec<T>) -> Vec<T> {
+        &self * vector.as_slice()
+    }
+}
+
+impl<T: Scalar> Neg for &Matrix2D<T> {
+    type Output = Matrix2D<T>;
+
+    fn neg(self) -> Matrix2D<T> {
+        self.scale(-T::one())
+    }
+}
+
+impl<T: Scalar> Neg for Matrix2D<T> {
+    type Output = Matrix2D<T>;
+
+    fn neg(self) -> Matrix2D<T> {
+        self * -T::one()
+    }
+}
@@ -0,0 +1,190 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 基于 Householder 反射的 QR 分解结果，满足 `A = Q * R`。
+///
+/// `Q` 以 Householder 向量的形式隐式保存，只在需要时才显式构造；
+/// `R` 与原矩阵同形状，对角线以下的元素均为零。
+#[derive(Debug, Clone, PartialEq)]
+pub struct QR {
+    /// 上三角因子 `R`，形状为 m×n。
+    r: Matrix2D,
+    /// 第 k 个 Householder 向量，作用于第 k 行及其之后的分量，已单位化。
+    reflectors: Vec<Vec<f64>>,
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 使用 Householder 反射对 m×n 矩阵进行 QR 分解。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `QR` 分解结果，其中 `Q` 为 m×m 正交矩阵，`R` 为 m×n 上三角矩阵。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![3.0, 1.0], vec![4.0, 2.0], vec![0.0, 5.0]]).unwrap();
+    /// let qr = a.qr();
+    /// let rebuilt = qr.q().multiply(&qr.r()).unwrap();
+    /// assert!((rebuilt[(1, 0)] - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn qr(&self) -> QR {
+        let m = self.rows();
+        let n = self.cols();
+        let mut r = self.clone();
+        let mut reflectors = Vec::with_capacity(n.min(m));
+
+        for k in 0..n.min(m.saturating_sub(1)) {
+            let norm = (k..m).map(|i| r[(i, k)].powi(2)).sum::<f64>().sqrt();
+            if norm == 0.0 {
+                // 该列已经为零，使用零向量表示恒等变换
+                reflectors.push(vec![0.0; m - k]);
+                continue;
+            }
+
+            // 选取与首元素符号相反的 alpha，避免相减时的抵消误差
+            let alpha = if r[(k, k)] > 0.0 { -norm } else { norm };
+            let mut v: Vec<f64> = (k..m).map(|i| r[(i, k)]).collect();
+            v[0] -= alpha;
+            let v_norm = v.iter().map(|x| x * x).sum::<f64>().sqrt();
+            v.iter_mut().for_each(|x| *x /= v_norm);
+
+            // R = (I - 2vvᵀ) R
+            for j in k..n {
+                let dot: f64 = (k..m).map(|i| v[i - k] * r[(i, j)]).sum();
+                for i in k..m {
+                    r[(i, j)] -= 2.0 * v[i - k] * dot;
+                }
+            }
+            for i in k + 1..m {
+                r[(i, k)] = 0.0;
+            }
+
+            reflectors.push(v);
+        }
+
+        QR { r, reflectors }
+    }
+
+    /// 使用 QR 分解求解超定线性方程组的最小二乘解，即最小化 `‖A * x - b‖₂`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 右端项，长度必须等于矩阵的行数。
+    ///
+    /// # 返回
+    ///
+    /// 返回最小二乘解 `x`；如果矩阵列不满秩，返回 `MatrixError::Singular`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// // 用直线 y = c0 + c1 * x 拟合 (0, 1), (1, 3), (2, 5)
+    /// let a = Matrix2D::new(vec![vec![1.0, 0.0], vec![1.0, 1.0], vec![1.0, 2.0]]).unwrap();
+    /// let x = a.least_squares(&[1.0, 3.0, 5.0]).unwrap();
+    /// assert!((x[0] - 1.0).abs() < 1e-12 && (x[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn least_squares(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        self.qr().solve_least_squares(b)
+    }
+}
+
+impl QR {
+    /// 返回完整的 m×m 正交因子 `Q`。
+    pub fn q(&self) -> Matrix2D {
+        let m = self.r.rows();
+        let mut q = Matrix2D::eye(m);
+        // Q = H0 * H1 * ... * Hk，从右往左依次作用于单位矩阵
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            for j in 0..m {
+                let dot: f64 = (k..m).map(|i| v[i - k] * q[(i, j)]).sum();
+                for i in k..m {
+                    q[(i, j)] -= 2.0 * v[i - k] * dot;
+                }
+            }
+        }
+        q
+    }
+
+    /// 返回精简形式的正交因子，即 `Q` 的前 `min(m, n)` 列。
+    pub fn q_thin(&self) -> Matrix2D {
+        let q = self.q();
+        let k = self.r.rows().min(self.r.cols());
+        Matrix2D::from_fn(q.rows(), k, |i, j| q[(i, j)])
+    }
+
+    /// 返回 m×n 上三角因子 `R`。
+    pub fn r(&self) -> Matrix2D {
+        self.r.clone()
+    }
+
+    /// 返回精简形式的上三角因子，即 `R` 的前 `min(m, n)` 行。
+    pub fn r_thin(&self) -> Matrix2D {
+        let k = self.r.rows().min(self.r.cols());
+        Matrix2D::from_fn(k, self.r.cols(), |i, j| self.r[(i, j)])
+    }
+
+    /// 计算 `Qᵀ * b`，无需显式构造 `Q`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 长度等于矩阵行数的向量。
+    ///
+    /// # 返回
+    ///
+    /// 返回 `Qᵀ * b`；如果 `b` 的长度与行数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn apply_qt(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let m = self.r.rows();
+        if b.len() != m {
+            return Err(MatrixError::DimensionMismatch { left: self.r.shape(), right: (b.len(), 1) });
+        }
+        let mut y = b.to_vec();
+        for (k, v) in self.reflectors.iter().enumerate() {
+            let dot: f64 = (k..m).map(|i| v[i - k] * y[i]).sum();
+            for i in k..m {
+                y[i] -= 2.0 * v[i - k] * dot;
+            }
+        }
+        Ok(y)
+    }
+
+    /// 利用分解结果求解最小二乘问题 `min ‖A * x - b‖₂`。
+    ///
+    /// # 参数
+    ///
+    /// * `b` - 右端项，长度必须等于矩阵的行数。
+    ///
+    /// # 返回
+    ///
+    /// 返回最小二乘解 `x`；如果 `R` 的某个对角元可忽略（列不满秩），
+    /// 返回 `MatrixError::Singular`。行数少于列数时，第 `m` 列没有主元，同样视为奇异。
+    pub fn solve_least_squares(&self, b: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        let m = self.r.rows();
+        let n = self.r.cols();
+        let y = self.apply_qt(b)?;
+
+        let max_diag = (0..n.min(m)).fold(0.0_f64, |acc, i| acc.max(self.r[(i, i)].abs()));
+        let tolerance = m.max(n) as f64 * f64::EPSILON * max_diag;
+        for i in 0..n.min(m) {
+            if self.r[(i, i)].abs() <= tolerance {
+                return Err(MatrixError::Singular { pivot: i });
+            }
+        }
+        if m < n {
+            return Err(MatrixError::Singular { pivot: m });
+        }
+
+        // 回代求解 R[0..n, 0..n] * x = y[0..n]
+        let mut x = vec![0.0; n];
+        for i in (0..n).rev() {
+            let sum: f64 = (i + 1..n).map(|j| self.r[(i, j)] * x[j]).sum();
+            x[i] = (y[i] - sum) / self.r[(i, i)];
+        }
+        Ok(x)
+    }
+}
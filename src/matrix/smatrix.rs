@@ -0,0 +1,331 @@
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 在栈上存储的 R×C 固定尺寸矩阵。
+///
+/// 形状由常量泛型参数给出，矩阵乘法的维度在编译期检查：`SMatrix<R, K> * SMatrix<K, C>`
+/// 得到 `SMatrix<R, C>`，内维不一致的乘法无法通过编译。适用于 2×2、3×3、4×4 等小尺寸变换，
+/// 需要动态尺寸时请使用 [`Matrix2D`]，两者之间可以无损转换。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::matrix::smatrix::SMatrix;
+///
+/// let rotation = SMatrix::new([[0.0, -1.0], [1.0, 0.0]]);
+/// let scale = SMatrix::new([[2.0, 0.0], [0.0, 2.0]]);
+/// let transform = rotation * scale;
+/// assert_eq!(transform * [1.0, 0.0], [0.0, 2.0]);
+/// assert_eq!(transform.determinant(), 4.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SMatrix<const R: usize, const C: usize> {
+    /// 按行存放的矩阵数据。
+    data: [[f64; C]; R],
+}
+
+/// 2×2 固定尺寸矩阵。
+pub type SMatrix2 = SMatrix<2, 2>;
+/// 3×3 固定尺寸矩阵。
+pub type SMatrix3 = SMatrix<3, 3>;
+/// 4×4 固定尺寸矩阵。
+pub type SMatrix4 = SMatrix<4, 4>;
+
+#[allow(dead_code)]
+impl<const R: usize, const C: usize> SMatrix<R, C> {
+    /// 由按行排列的数组创建矩阵。
+    pub const fn new(data: [[f64; C]; R]) -> Self {
+        SMatrix { data }
+    }
+
+    /// 创建全零矩阵。
+    pub const fn zeros() -> Self {
+        SMatrix { data: [[0.0; C]; R] }
+    }
+
+    /// 由元素生成函数创建矩阵，`f(i, j)` 给出第 `i` 行第 `j` 列的元素。
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> f64,
+    {
+        let mut data = [[0.0; C]; R];
+        for (i, row) in data.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = f(i, j);
+            }
+        }
+        SMatrix { data }
+    }
+
+    /// 返回矩阵的形状 `(R, C)`。
+    pub const fn shape(&self) -> (usize, usize) {
+        (R, C)
+    }
+
+    /// 以按行排列的数组形式访问矩阵数据。
+    pub fn as_array(&self) -> &[[f64; C]; R] {
+        &self.data
+    }
+
+    /// 获取当前矩阵的转置。
+    pub fn transpose(&self) -> SMatrix<C, R> {
+        SMatrix::from_fn(|i, j| self.data[j][i])
+    }
+
+    /// 获取矩阵的某一列。
+    pub fn column(&self, col_index: usize) -> [f64; R] {
+        let mut column = [0.0; R];
+        for (value, row) in column.iter_mut().zip(&self.data) {
+            *value = row[col_index];
+        }
+        column
+    }
+}
+
+#[allow(dead_code)]
+impl<const N: usize> SMatrix<N, N> {
+    /// 创建单位矩阵。
+    pub fn identity() -> Self {
+        SMatrix::from_fn(|i, j| if i == j { 1.0 } else { 0.0 })
+    }
+
+    /// 计算矩阵的迹。
+    pub fn trace(&self) -> f64 {
+        (0..N).map(|i| self.data[i][i]).sum()
+    }
+}
+
+#[allow(dead_code)]
+impl SMatrix<2, 2> {
+    /// 使用闭式公式计算 2×2 矩阵的行列式。
+    pub fn determinant(&self) -> f64 {
+        let [[a, b], [c, d]] = self.data;
+        a * d - b * c
+    }
+
+    /// 使用伴随矩阵公式计算 2×2 矩阵的逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回逆矩阵；行列式为零时返回 `MatrixError::Singular`。闭式公式不区分具体主元，`pivot` 恒为 0。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return Err(MatrixError::Singular { pivot: 0 });
+        }
+        let [[a, b], [c, d]] = self.data;
+        Ok(SMatrix::new([[d / det, -b / det], [-c / det, a / det]]))
+    }
+}
+
+#[allow(dead_code)]
+impl SMatrix<3, 3> {
+    /// 使用按第一行展开的闭式公式计算 3×3 矩阵的行列式。
+    pub fn determinant(&self) -> f64 {
+        let m = &self.data;
+        m[0][0] * (m[1][1] * m[2][2] - m[1][2] * m[2][1])
+            - m[0][1] * (m[1][0] * m[2][2] - m[1][2] * m[2][0])
+            + m[0][2] * (m[1][0] * m[2][1] - m[1][1] * m[2][0])
+    }
+
+    /// 使用伴随矩阵公式计算 3×3 矩阵的逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回逆矩阵；行列式为零时返回 `MatrixError::Singular`。闭式公式不区分具体主元，`pivot` 恒为 0。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let det = self.determinant();
+        if det == 0.0 {
+            return Err(MatrixError::Singular { pivot: 0 });
+        }
+        let m = &self.data;
+        // 逆矩阵第 (i, j) 个元素为代数余子式 C_ji / det，利用下标循环移位得到正确的符号
+        Ok(SMatrix::from_fn(|i, j| {
+            let (r0, r1) = ((j + 1) % 3, (j + 2) % 3);
+            let (c0, c1) = ((i + 1) % 3, (i + 2) % 3);
+            (m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]) / det
+        }))
+    }
+}
+
+#[allow(dead_code)]
+impl SMatrix<4, 4> {
+    /// 使用 2×2 子式的 Laplace 展开计算 4×4 矩阵的行列式。
+    pub fn determinant(&self) -> f64 {
+        let (s, c) = self.minors();
+        s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0]
+    }
+
+    /// 使用伴随矩阵公式计算 4×4 矩阵的逆矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回逆矩阵；行列式为零时返回 `MatrixError::Singular`。闭式公式不区分具体主元，`pivot` 恒为 0。
+    pub fn inverse(&self) -> Result<Self, MatrixError> {
+        let (s, c) = self.minors();
+        let det = s[0] * c[5] - s[1] * c[4] + s[2] * c[3] + s[3] * c[2] - s[4] * c[1] + s[5] * c[0];
+        if det == 0.0 {
+            return Err(MatrixError::Singular { pivot: 0 });
+        }
+        let m = &self.data;
+        let adjugate = [
+            [
+                m[1][1] * c[5] - m[1][2] * c[4] + m[1][3] * c[3],
+                -m[0][1] * c[5] + m[0][2] * c[4] - m[0][3] * c[3],
+                m[3][1] * s[5] - m[3][2] * s[4] + m[3][3] * s[3],
+                -m[2][1] * s[5] + m[2][2] * s[4] - m[2][3] * s[3],
+            ],
+            [
+                -m[1][0] * c[5] + m[1][2] * c[2] - m[1][3] * c[1],
+                m[0][0] * c[5] - m[0][2] * c[2] + m[0][3] * c[1],
+                -m[3][0] * s[5] + m[3][2] * s[2] - m[3][3] * s[1],
+                m[2][0] * s[5] - m[2][2] * s[2] + m[2][3] * s[1],
+            ],
+            [
+                m[1][0] * c[4] - m[1][1] * c[2] + m[1][3] * c[0],
+                -m[0][0] * c[4] + m[0][1] * c[2] - m[0][3] * c[0],
+                m[3][0] * s[4] - m[3][1] * s[2] + m[3][3] * s[0],
+                -m[2][0] * s[4] + m[2][1] * s[2] - m[2][3] * s[0],
+            ],
+            [
+                -m[1][0] * c[3] + m[1][1] * c[1] - m[1][2] * c[0],
+                m[0][0] * c[3] - m[0][1] * c[1] + m[0][2] * c[0],
+                -m[3][0] * s[3] + m[3][1] * s[1] - m[3][2] * s[0],
+                m[2][0] * s[3] - m[2][1] * s[1] + m[2][2] * s[0],
+            ],
+        ];
+        Ok(SMatrix::from_fn(|i, j| adjugate[i][j] / det))
+    }
+
+    /// 计算前两行与后两行的全部 2×2 子式，供行列式与逆矩阵共用。
+    fn minors(&self) -> ([f64; 6], [f64; 6]) {
+        let m = &self.data;
+        let s = [
+            m[0][0] * m[1][1] - m[1][0] * m[0][1],
+            m[0][0] * m[1][2] - m[1][0] * m[0][2],
+            m[0][0] * m[1][3] - m[1][0] * m[0][3],
+            m[0][1] * m[1][2] - m[1][1] * m[0][2],
+            m[0][1] * m[1][3] - m[1][1] * m[0][3],
+            m[0][2] * m[1][3] - m[1][2] * m[0][3],
+        ];
+        let c = [
+            m[2][0] * m[3][1] - m[3][0] * m[2][1],
+            m[2][0] * m[3][2] - m[3][0] * m[2][2],
+            m[2][0] * m[3][3] - m[3][0] * m[2][3],
+            m[2][1] * m[3][2] - m[3][1] * m[2][2],
+            m[2][1] * m[3][3] - m[3][1] * m[2][3],
+            m[2][2] * m[3][3] - m[3][2] * m[2][3],
+        ];
+        (s, c)
+    }
+}
+
+impl<const R: usize, const C: usize> Default for SMatrix<R, C> {
+    fn default() -> Self {
+        SMatrix::zeros()
+    }
+}
+
+impl<const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<R, C> {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        &self.data[i][j]
+    }
+}
+
+impl<const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        &mut self.data[i][j]
+    }
+}
+
+impl<const R: usize, const C: usize> Add for SMatrix<R, C> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        SMatrix::from_fn(|i, j| self.data[i][j] + rhs.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize> Sub for SMatrix<R, C> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        SMatrix::from_fn(|i, j| self.data[i][j] - rhs.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize> Neg for SMatrix<R, C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        SMatrix::from_fn(|i, j| -self.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize> Mul<f64> for SMatrix<R, C> {
+    type Output = Self;
+
+    fn mul(self, scalar: f64) -> Self {
+        SMatrix::from_fn(|i, j| self.data[i][j] * scalar)
+    }
+}
+
+impl<const R: usize, const K: usize, const C: usize> Mul<SMatrix<K, C>> for SMatrix<R, K> {
+    type Output = SMatrix<R, C>;
+
+    /// 矩阵乘法，内维 `K` 在编译期匹配。
+    fn mul(self, rhs: SMatrix<K, C>) -> SMatrix<R, C> {
+        SMatrix::from_fn(|i, j| (0..K).map(|k| self.data[i][k] * rhs.data[k][j]).sum())
+    }
+}
+
+impl<const R: usize, const C: usize> Mul<[f64; C]> for SMatrix<R, C> {
+    type Output = [f64; R];
+
+    /// 矩阵乘以向量，向量长度在编译期匹配。
+    fn mul(self, vector: [f64; C]) -> [f64; R] {
+        let mut result = [0.0; R];
+        for (value, row) in result.iter_mut().zip(&self.data) {
+            *value = row.iter().zip(&vector).map(|(a, b)| a * b).sum();
+        }
+        result
+    }
+}
+
+impl<const R: usize, const C: usize> From<[[f64; C]; R]> for SMatrix<R, C> {
+    fn from(data: [[f64; C]; R]) -> Self {
+        SMatrix::new(data)
+    }
+}
+
+impl<const R: usize, const C: usize> From<SMatrix<R, C>> for Matrix2D {
+    /// 转换为动态尺寸矩阵，元素逐一复制，不损失精度。
+    fn from(matrix: SMatrix<R, C>) -> Self {
+        Matrix2D::from_fn(R, C, |i, j| matrix.data[i][j])
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<&Matrix2D> for SMatrix<R, C> {
+    type Error = MatrixError;
+
+    /// 由动态尺寸矩阵转换；形状不是 R×C 时返回 `MatrixError::DimensionMismatch`，
+    /// 其中 `left` 为输入矩阵的形状，`right` 为目标形状。
+    fn try_from(matrix: &Matrix2D) -> Result<Self, Self::Error> {
+        if matrix.shape() != (R, C) {
+            return Err(MatrixError::DimensionMismatch { left: matrix.shape(), right: (R, C) });
+        }
+        Ok(SMatrix::from_fn(|i, j| matrix[(i, j)]))
+    }
+}
+
+impl<const R: usize, const C: usize> TryFrom<Matrix2D> for SMatrix<R, C> {
+    type Error = MatrixError;
+
+    fn try_from(matrix: Matrix2D) -> Result<Self, Self::Error> {
+        SMatrix::try_from(&matrix)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::matrix::sparse::compress;
+use crate::matrix::sparse::csc::CscMatrix;
+use crate::matrix::sparse::csr::CsrMatrix;
+
+/// 坐标（COO）格式的稀疏矩阵，以 `(行, 列, 值)` 三元组存储非零元素。
+///
+/// 适合逐个元素组装矩阵，例如有限元刚度矩阵的单元装配；同一坐标可以出现多次，
+/// 转换为 CSR 或 CSC 时重复坐标的值会被相加。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CooMatrix {
+    rows: usize,
+    cols: usize,
+    row_indices: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl CooMatrix {
+    /// 创建一个不含任何元素的 rows×cols 稀疏矩阵。
+    pub fn new(rows: usize, cols: usize) -> CooMatrix {
+        CooMatrix::with_capacity(rows, cols, 0)
+    }
+
+    /// 创建一个预留 `capacity` 个元素空间的 rows×cols 稀疏矩阵。
+    pub fn with_capacity(rows: usize, cols: usize, capacity: usize) -> CooMatrix {
+        CooMatrix {
+            rows,
+            cols,
+            row_indices: Vec::with_capacity(capacity),
+            col_indices: Vec::with_capacity(capacity),
+            values: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// 由三元组列表创建稀疏矩阵。
+    ///
+    /// # 参数
+    ///
+    /// * `rows` - 行数。
+    /// * `cols` - 列数。
+    /// * `triplets` - `(行, 列, 值)` 三元组，允许重复坐标。
+    ///
+    /// # 返回
+    ///
+    /// 返回创建的稀疏矩阵；如果某个坐标越界，返回 `MatrixError::IndexOutOfBounds`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::sparse::coo::CooMatrix;
+    ///
+    /// let coo = CooMatrix::from_triplets(2, 2, &[(0, 0, 1.0), (1, 1, 2.0), (0, 0, 3.0)]).unwrap();
+    /// let csr = coo.to_csr();
+    /// assert_eq!(csr.nnz(), 2);
+    /// assert_eq!(csr.get(0, 0), 4.0);
+    /// ```
+    pub fn from_triplets(rows: usize, cols: usize, triplets: &[(usize, usize, f64)]) -> Result<CooMatrix, MatrixError> {
+        let mut coo = CooMatrix::with_capacity(rows, cols, triplets.len());
+        for &(i, j, value) in triplets {
+            coo.push(i, j, value)?;
+        }
+        Ok(coo)
+    }
+
+    /// 追加一个元素；同一坐标多次追加时，其值在压缩时相加。
+    ///
+    /// # 返回
+    ///
+    /// 如果坐标越界，返回 `MatrixError::IndexOutOfBounds`。
+    pub fn push(&mut self, i: usize, j: usize, value: f64) -> Result<(), MatrixError> {
+        if i >= self.rows || j >= self.cols {
+            return Err(MatrixError::IndexOutOfBounds { index: (i, j), shape: self.shape() });
+        }
+        self.row_indices.push(i);
+        self.col_indices.push(j);
+        self.values.push(value);
+        Ok(())
+    }
+
+    /// 返回矩阵的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 返回已存储的三元组个数，重复坐标分别计数。
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 按追加顺序遍历全部 `(行, 列, 值)` 三元组。
+    pub fn triplets(&self) -> impl Iterator<Item = (usize, usize, f64)> + '_ {
+        self.row_indices
+            .iter()
+            .zip(&self.col_indices)
+            .zip(&self.values)
+            .map(|((&i, &j), &value)| (i, j, value))
+    }
+
+    /// 获取转置矩阵，只需交换行列下标。
+    pub fn transpose(&self) -> CooMatrix {
+        CooMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_indices: self.col_indices.clone(),
+            col_indices: self.row_indices.clone(),
+            values: self.values.clone(),
+        }
+    }
+
+    /// 压缩为 CSR 格式，重复坐标的值相加，每行内的列下标升序排列。
+    pub fn to_csr(&self) -> CsrMatrix {
+        let (indptr, indices, values) = compress(self.rows, &self.row_indices, &self.col_indices, &self.values);
+        CsrMatrix::from_compressed(self.rows, self.cols, indptr, indices, values)
+    }
+
+    /// 压缩为 CSC 格式，重复坐标的值相加，每列内的行下标升序排列。
+    pub fn to_csc(&self) -> CscMatrix {
+        let (indptr, indices, values) = compress(self.cols, &self.col_indices, &self.row_indices, &self.values);
+        CscMatrix::from_compressed(self.rows, self.cols, indptr, indices, values)
+    }
+
+    /// 转换为稠密矩阵，重复坐标的值相加。
+    pub fn to_dense(&self) -> Matrix2D {
+        let mut dense = Matrix2D::zeros(self.rows, self.cols);
+        for (i, j, value) in self.triplets() {
+            dense[(i, j)] += value;
+        }
+        dense
+    }
+}
+
+impl From<&Matrix2D> for CooMatrix {
+    /// 由稠密矩阵转换，只保留非零元素。
+    fn from(matrix: &Matrix2D) -> Self {
+        let (rows, cols) = matrix.shape();
+        let mut coo = CooMatrix::new(rows, cols);
+        for (index, &value) in matrix.as_slice().iter().enumerate() {
+            if value != 0.0 {
+                coo.row_indices.push(index / cols);
+                coo.col_indices.push(index % cols);
+                coo.values.push(value);
+            }
+        }
+        coo
+    }
+}
+
+impl From<&CooMatrix> for Matrix2D {
+    fn from(matrix: &CooMatrix) -> Self {
+        matrix.to_dense()
+    }
+}
@@ -0,0 +1,165 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::matrix::sparse::coo::CooMatrix;
+use crate::matrix::sparse::csr::CsrMatrix;
+use crate::matrix::sparse::{compress, expand_indptr};
+
+/// 压缩稀疏列（CSC）格式的稀疏矩阵。
+///
+/// 第 `j` 列的非零元素的行下标为 `indices[indptr[j]..indptr[j + 1]]`，值为
+/// `values[indptr[j]..indptr[j + 1]]`，每列内的行下标严格升序。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CscMatrix {
+    rows: usize,
+    cols: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl CscMatrix {
+    /// 由已经压缩好的数组构造，调用方保证数组满足 CSC 的约定。
+    pub(crate) fn from_compressed(
+        rows: usize,
+        cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<f64>,
+    ) -> CscMatrix {
+        CscMatrix { rows, cols, indptr, indices, values }
+    }
+
+    /// 返回矩阵的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 返回存储的非零元素个数。
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 返回列指针数组，长度为列数加一。
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// 返回各非零元素的行下标。
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// 返回各非零元素的值。
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// 返回第 `j` 列的行下标与值。
+    pub fn column(&self, j: usize) -> (&[usize], &[f64]) {
+        let range = self.indptr[j]..self.indptr[j + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    /// 获取第 `i` 行第 `j` 列的元素，未存储的元素为零。
+    ///
+    /// # 注意
+    ///
+    /// 如果下标越界，将会引发 panic。
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        let (indices, values) = self.column(j);
+        indices.binary_search(&i).map_or(0.0, |k| values[k])
+    }
+
+    /// 计算稀疏矩阵与向量的乘积 `A * x`，按列累加。
+    ///
+    /// # 返回
+    ///
+    /// 返回长度等于行数的结果向量；如果向量长度与列数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn mul_vector(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: (x.len(), 1) });
+        }
+        let mut y = vec![0.0; self.rows];
+        for (j, &x_j) in x.iter().enumerate() {
+            let (indices, values) = self.column(j);
+            for (&i, &value) in indices.iter().zip(values) {
+                y[i] += value * x_j;
+            }
+        }
+        Ok(y)
+    }
+
+    /// 计算稀疏矩阵与稠密矩阵的乘积 `A * B`。
+    ///
+    /// # 返回
+    ///
+    /// 返回稠密的结果矩阵；如果 `B` 的行数与列数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn mul_dense(&self, b: &Matrix2D) -> Result<Matrix2D, MatrixError> {
+        if b.rows() != self.cols {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: b.shape() });
+        }
+        let n = b.cols();
+        let mut result = Matrix2D::zeros(self.rows, n);
+        let (b_data, out) = (b.as_slice(), result.as_mut_slice());
+        for k in 0..self.cols {
+            let (indices, values) = self.column(k);
+            let b_row = &b_data[k * n..(k + 1) * n];
+            for (&i, &value) in indices.iter().zip(values) {
+                for (target, &b_kj) in out[i * n..(i + 1) * n].iter_mut().zip(b_row) {
+                    *target += value * b_kj;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 获取转置矩阵，结果仍为 CSC 格式。
+    pub fn transpose(&self) -> CscMatrix {
+        let cols = expand_indptr(&self.indptr);
+        let (indptr, indices, values) = compress(self.rows, &self.indices, &cols, &self.values);
+        CscMatrix::from_compressed(self.cols, self.rows, indptr, indices, values)
+    }
+
+    /// 转换为 CSR 格式。
+    pub fn to_csr(&self) -> CsrMatrix {
+        let cols = expand_indptr(&self.indptr);
+        let (indptr, indices, values) = compress(self.rows, &self.indices, &cols, &self.values);
+        CsrMatrix::from_compressed(self.rows, self.cols, indptr, indices, values)
+    }
+
+    /// 转换为 COO 格式。
+    pub fn to_coo(&self) -> CooMatrix {
+        let mut coo = CooMatrix::with_capacity(self.rows, self.cols, self.nnz());
+        for (j, (&i, &value)) in expand_indptr(&self.indptr).into_iter().zip(self.indices.iter().zip(&self.values)) {
+            coo.push(i, j, value).expect("CSC indices are within bounds");
+        }
+        coo
+    }
+
+    /// 转换为稠密矩阵。
+    pub fn to_dense(&self) -> Matrix2D {
+        let mut dense = Matrix2D::zeros(self.rows, self.cols);
+        for j in 0..self.cols {
+            let (indices, values) = self.column(j);
+            for (&i, &value) in indices.iter().zip(values) {
+                dense[(i, j)] = value;
+            }
+        }
+        dense
+    }
+}
+
+impl From<&Matrix2D> for CscMatrix {
+    /// 由稠密矩阵转换，只保留非零元素。
+    fn from(matrix: &Matrix2D) -> Self {
+        CooMatrix::from(matrix).to_csc()
+    }
+}
+
+impl From<&CscMatrix> for Matrix2D {
+    fn from(matrix: &CscMatrix) -> Self {
+        matrix.to_dense()
+    }
+}
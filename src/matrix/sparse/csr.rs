@@ -0,0 +1,172 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+use crate::matrix::sparse::coo::CooMatrix;
+use crate::matrix::sparse::csc::CscMatrix;
+use crate::matrix::sparse::{compress, expand_indptr};
+
+/// 压缩稀疏行（CSR）格式的稀疏矩阵。
+///
+/// 第 `i` 行的非零元素的列下标为 `indices[indptr[i]..indptr[i + 1]]`，值为
+/// `values[indptr[i]..indptr[i + 1]]`，每行内的列下标严格升序。
+#[derive(Debug, Clone, PartialEq)]
+pub struct CsrMatrix {
+    rows: usize,
+    cols: usize,
+    indptr: Vec<usize>,
+    indices: Vec<usize>,
+    values: Vec<f64>,
+}
+
+#[allow(dead_code)]
+impl CsrMatrix {
+    /// 由已经压缩好的数组构造，调用方保证数组满足 CSR 的约定。
+    pub(crate) fn from_compressed(
+        rows: usize,
+        cols: usize,
+        indptr: Vec<usize>,
+        indices: Vec<usize>,
+        values: Vec<f64>,
+    ) -> CsrMatrix {
+        CsrMatrix { rows, cols, indptr, indices, values }
+    }
+
+    /// 返回矩阵的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 返回存储的非零元素个数。
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// 返回行指针数组，长度为行数加一。
+    pub fn indptr(&self) -> &[usize] {
+        &self.indptr
+    }
+
+    /// 返回各非零元素的列下标。
+    pub fn indices(&self) -> &[usize] {
+        &self.indices
+    }
+
+    /// 返回各非零元素的值。
+    pub fn values(&self) -> &[f64] {
+        &self.values
+    }
+
+    /// 返回第 `i` 行的列下标与值。
+    pub fn row(&self, i: usize) -> (&[usize], &[f64]) {
+        let range = self.indptr[i]..self.indptr[i + 1];
+        (&self.indices[range.clone()], &self.values[range])
+    }
+
+    /// 获取第 `i` 行第 `j` 列的元素，未存储的元素为零。
+    ///
+    /// # 注意
+    ///
+    /// 如果下标越界，将会引发 panic。
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        let (indices, values) = self.row(i);
+        indices.binary_search(&j).map_or(0.0, |k| values[k])
+    }
+
+    /// 计算稀疏矩阵与向量的乘积 `A * x`。
+    ///
+    /// # 返回
+    ///
+    /// 返回长度等于行数的结果向量；如果向量长度与列数不符，返回 `MatrixError::DimensionMismatch`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::sparse::coo::CooMatrix;
+    ///
+    /// let a = CooMatrix::from_triplets(2, 3, &[(0, 0, 1.0), (0, 2, 2.0), (1, 1, 3.0)]).unwrap().to_csr();
+    /// assert_eq!(a.mul_vector(&[1.0, 1.0, 1.0]).unwrap(), vec![3.0, 3.0]);
+    /// ```
+    pub fn mul_vector(&self, x: &[f64]) -> Result<Vec<f64>, MatrixError> {
+        if x.len() != self.cols {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: (x.len(), 1) });
+        }
+        Ok((0..self.rows)
+            .map(|i| {
+                let (indices, values) = self.row(i);
+                indices.iter().zip(values).map(|(&j, &value)| value * x[j]).sum()
+            })
+            .collect())
+    }
+
+    /// 计算稀疏矩阵与稠密矩阵的乘积 `A * B`。
+    ///
+    /// # 返回
+    ///
+    /// 返回稠密的结果矩阵；如果 `B` 的行数与列数不符，返回 `MatrixError::DimensionMismatch`。
+    pub fn mul_dense(&self, b: &Matrix2D) -> Result<Matrix2D, MatrixError> {
+        if b.rows() != self.cols {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: b.shape() });
+        }
+        let n = b.cols();
+        let mut result = Matrix2D::zeros(self.rows, n);
+        let (b_data, out) = (b.as_slice(), result.as_mut_slice());
+        for i in 0..self.rows {
+            let (indices, values) = self.row(i);
+            let out_row = &mut out[i * n..(i + 1) * n];
+            for (&k, &value) in indices.iter().zip(values) {
+                for (target, &b_kj) in out_row.iter_mut().zip(&b_data[k * n..(k + 1) * n]) {
+                    *target += value * b_kj;
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 获取转置矩阵，结果仍为 CSR 格式。
+    pub fn transpose(&self) -> CsrMatrix {
+        let rows = expand_indptr(&self.indptr);
+        let (indptr, indices, values) = compress(self.cols, &self.indices, &rows, &self.values);
+        CsrMatrix::from_compressed(self.cols, self.rows, indptr, indices, values)
+    }
+
+    /// 转换为 CSC 格式。
+    pub fn to_csc(&self) -> CscMatrix {
+        let rows = expand_indptr(&self.indptr);
+        let (indptr, indices, values) = compress(self.cols, &self.indices, &rows, &self.values);
+        CscMatrix::from_compressed(self.rows, self.cols, indptr, indices, values)
+    }
+
+    /// 转换为 COO 格式。
+    pub fn to_coo(&self) -> CooMatrix {
+        let mut coo = CooMatrix::with_capacity(self.rows, self.cols, self.nnz());
+        for (i, (&j, &value)) in expand_indptr(&self.indptr).into_iter().zip(self.indices.iter().zip(&self.values)) {
+            coo.push(i, j, value).expect("CSR indices are within bounds");
+        }
+        coo
+    }
+
+    /// 转换为稠密矩阵。
+    pub fn to_dense(&self) -> Matrix2D {
+        let mut dense = Matrix2D::zeros(self.rows, self.cols);
+        for i in 0..self.rows {
+            let (indices, values) = self.row(i);
+            for (&j, &value) in indices.iter().zip(values) {
+                dense[(i, j)] = value;
+            }
+        }
+        dense
+    }
+}
+
+impl From<&Matrix2D> for CsrMatrix {
+    /// 由稠密矩阵转换，只保留非零元素。
+    fn from(matrix: &Matrix2D) -> Self {
+        CooMatrix::from(matrix).to_csr()
+    }
+}
+
+impl From<&CsrMatrix> for Matrix2D {
+    fn from(matrix: &CsrMatrix) -> Self {
+        matrix.to_dense()
+    }
+}
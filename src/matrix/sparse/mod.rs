@@ -0,0 +1,61 @@
+pub mod coo;
+pub mod csc;
+pub mod csr;
+
+/// 将三元组按主轴压缩为 `(indptr, indices, values)`。
+///
+/// `majors` 为主轴下标（CSR 为行，CSC 为列），`minors` 为次轴下标。
+/// 每个主轴段内的次轴下标按升序排列，重复的坐标会被合并并累加其值。
+pub(crate) fn compress(
+    major_len: usize,
+    majors: &[usize],
+    minors: &[usize],
+    values: &[f64],
+) -> (Vec<usize>, Vec<usize>, Vec<f64>) {
+    // 计数排序：先按主轴分桶
+    let mut indptr = vec![0; major_len + 1];
+    for &major in majors {
+        indptr[major + 1] += 1;
+    }
+    for i in 0..major_len {
+        indptr[i + 1] += indptr[i];
+    }
+    let mut next = indptr.clone();
+    let mut bucket = vec![(0, 0.0); majors.len()];
+    for ((&major, &minor), &value) in majors.iter().zip(minors).zip(values) {
+        bucket[next[major]] = (minor, value);
+        next[major] += 1;
+    }
+
+    // 段内按次轴排序并合并重复坐标
+    let mut compressed_indptr = Vec::with_capacity(major_len + 1);
+    let mut indices = Vec::with_capacity(bucket.len());
+    let mut data = Vec::with_capacity(bucket.len());
+    compressed_indptr.push(0);
+    for major in 0..major_len {
+        let segment = &mut bucket[indptr[major]..indptr[major + 1]];
+        segment.sort_by_key(|&(minor, _)| minor);
+        let start = indices.len();
+        for &(minor, value) in segment.iter() {
+            if indices.len() > start && indices.last() == Some(&minor) {
+                *data.last_mut().unwrap() += value;
+            } else {
+                indices.push(minor);
+                data.push(value);
+            }
+        }
+        compressed_indptr.push(indices.len());
+    }
+    (compressed_indptr, indices, data)
+}
+
+/// 将压缩格式展开为主轴下标序列，与 `indices` 一一对应。
+// `repeat_n` 需要 Rust 1.82，这里保留 `repeat().take()` 以免提高最低支持版本
+#[allow(clippy::manual_repeat_n)]
+pub(crate) fn expand_indptr(indptr: &[usize]) -> Vec<usize> {
+    let mut majors = Vec::with_capacity(indptr.last().copied().unwrap_or(0));
+    for (major, window) in indptr.windows(2).enumerate() {
+        majors.extend(std::iter::repeat(major).take(window[1] - window[0]));
+    }
+    majors
+}
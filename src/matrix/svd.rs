@@ -0,0 +1,284 @@
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 单边 Jacobi 奇异值分解允许的最大扫描次数。
+pub const DEFAULT_SVD_MAX_SWEEPS: usize = 60;
+
+/// 奇异值分解结果，满足 `A = U * Σ * Vᵀ`。
+///
+/// 精简形式中 `U` 为 m×k、`Vᵀ` 为 k×n，其中 `k = min(m, n)`；
+/// 完整形式中 `U` 为 m×m、`Vᵀ` 为 n×n。两种形式下 `U` 与 `V` 的列均标准正交。
+#[derive(Debug, Clone, PartialEq)]
+pub struct SVD {
+    /// 左奇异向量，按列存放。
+    pub u: Matrix2D,
+    /// 按降序排列的奇异值，共 `min(m, n)` 个。
+    pub singular_values: Vec<f64>,
+    /// 右奇异向量的转置，按行存放。
+    pub vt: Matrix2D,
+}
+
+impl SVD {
+    /// 返回与 `U`、`Vᵀ` 形状匹配的对角矩阵 `Σ`，使得 `U * Σ * Vᵀ` 还原原矩阵。
+    pub fn sigma(&self) -> Matrix2D {
+        let mut sigma = Matrix2D::zeros(self.u.cols(), self.vt.rows());
+        for (i, &value) in self.singular_values.iter().enumerate() {
+            sigma[(i, i)] = value;
+        }
+        sigma
+    }
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 计算 m×n 矩阵的精简奇异值分解。
+    ///
+    /// 使用单边 Jacobi（Hestenes）方法对列做正交化，精度高且适用于任意形状的矩阵。
+    ///
+    /// # 返回
+    ///
+    /// 返回精简形式的 `SVD`：`U` 为 m×k，奇异值共 k 个且按降序排列，`Vᵀ` 为 k×n，
+    /// 其中 `k = min(m, n)`；如果迭代未收敛，返回 `MatrixError::NotConverged`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![3.0, 0.0], vec![0.0, -4.0], vec![0.0, 0.0]]).unwrap();
+    /// let svd = a.svd().unwrap();
+    /// assert!((svd.singular_values[0] - 4.0).abs() < 1e-12);
+    /// assert!((svd.singular_values[1] - 3.0).abs() < 1e-12);
+    /// assert_eq!((svd.u.rows(), svd.u.cols(), svd.vt.rows(), svd.vt.cols()), (3, 2, 2, 2));
+    /// ```
+    pub fn svd(&self) -> Result<SVD, MatrixError> {
+        self.svd_impl(false)
+    }
+
+    /// 计算 m×n 矩阵的完整奇异值分解。
+    ///
+    /// 与 [`Matrix2D::svd`] 相同，但 `U` 被补全为 m×m 正交矩阵，`Vᵀ` 被补全为 n×n 正交矩阵。
+    pub fn svd_full(&self) -> Result<SVD, MatrixError> {
+        self.svd_impl(true)
+    }
+
+    fn svd_impl(&self, full: bool) -> Result<SVD, MatrixError> {
+        if self.rows() < self.cols() {
+            // Aᵀ = U' * Σ * V'ᵀ  =>  A = V' * Σ * U'ᵀ
+            let svd = self.transpose().svd_impl(full)?;
+            return Ok(SVD {
+                u: svd.vt.transpose(),
+                singular_values: svd.singular_values,
+                vt: svd.u.transpose(),
+            });
+        }
+
+        let m = self.rows();
+        let n = self.cols();
+        let mut u = self.clone();
+        let mut v = Matrix2D::eye(n);
+
+        let mut sweeps = 0;
+        loop {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (mut alpha, mut beta, mut gamma) = (0.0, 0.0, 0.0);
+                    for i in 0..m {
+                        alpha += u[(i, p)] * u[(i, p)];
+                        beta += u[(i, q)] * u[(i, q)];
+                        gamma += u[(i, p)] * u[(i, q)];
+                    }
+                    if gamma == 0.0 || gamma.abs() <= f64::EPSILON * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (2.0 * gamma);
+                    let t = zeta.signum() / (zeta.abs() + (1.0 + zeta * zeta).sqrt());
+                    let c = 1.0 / (1.0 + t * t).sqrt();
+                    let s = c * t;
+                    rotate_columns(&mut u, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+            sweeps += 1;
+            if sweeps == DEFAULT_SVD_MAX_SWEEPS {
+                return Err(MatrixError::NotConverged { iterations: sweeps });
+            }
+        }
+
+        // 列范数即奇异值，按降序排列
+        let norms: Vec<f64> = (0..n)
+            .map(|j| (0..m).map(|i| u[(i, j)].powi(2)).sum::<f64>().sqrt())
+            .collect();
+        let mut order = Matrix2D::argsort(&norms);
+        order.reverse();
+        let singular_values: Vec<f64> = order.iter().map(|&j| norms[j]).collect();
+
+        let tolerance = m.max(n) as f64 * f64::EPSILON * singular_values.first().copied().unwrap_or(0.0);
+        let mut left: Vec<Vec<f64>> = Vec::with_capacity(n);
+        for (&j, &sigma) in order.iter().zip(&singular_values) {
+            if sigma > tolerance {
+                left.push((0..m).map(|i| u[(i, j)] / sigma).collect());
+            }
+        }
+        let u = complete_orthonormal_columns(m, &left, if full { m } else { n });
+
+        let mut vt = Matrix2D::zeros(n, n);
+        for (row, &j) in order.iter().enumerate() {
+            for i in 0..n {
+                vt[(row, i)] = v[(i, j)];
+            }
+        }
+
+        Ok(SVD { u, singular_values, vt })
+    }
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 计算 Moore–Penrose 伪逆 `A⁺ = V * Σ⁺ * Uᵀ`。
+    ///
+    /// # 参数
+    ///
+    /// * `tol` - 奇异值截断阈值，不超过该值的奇异值视为零；
+    ///   为 `None` 时使用默认阈值 `max(m, n) * EPSILON * σ_max`。
+    ///
+    /// # 返回
+    ///
+    /// 返回 n×m 的伪逆矩阵；如果 SVD 未收敛，返回 `MatrixError::NotConverged`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+    /// let pinv = a.pinv(None).unwrap();
+    /// assert!((pinv[(0, 0)] - 0.04).abs() < 1e-12);
+    /// ```
+    pub fn pinv(&self, tol: Option<f64>) -> Result<Matrix2D, MatrixError> {
+        let svd = self.svd()?;
+        let tolerance = tol.unwrap_or_else(|| self.default_rank_tolerance(&svd.singular_values));
+
+        let mut result = Matrix2D::zeros(self.cols(), self.rows());
+        for (k, &sigma) in svd.singular_values.iter().enumerate() {
+            if sigma <= tolerance {
+                continue;
+            }
+            for i in 0..self.cols() {
+                let scaled = svd.vt[(k, i)] / sigma;
+                for j in 0..self.rows() {
+                    result[(i, j)] += scaled * svd.u[(j, k)];
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// 计算矩阵的数值秩，即大于阈值的奇异值个数。
+    ///
+    /// # 参数
+    ///
+    /// * `tol` - 奇异值截断阈值；为 `None` 时使用默认阈值 `max(m, n) * EPSILON * σ_max`。
+    pub fn rank(&self, tol: Option<f64>) -> Result<usize, MatrixError> {
+        let svd = self.svd()?;
+        let tolerance = tol.unwrap_or_else(|| self.default_rank_tolerance(&svd.singular_values));
+        Ok(svd.singular_values.iter().filter(|&&sigma| sigma > tolerance).count())
+    }
+
+    /// 计算零空间的一组标准正交基。
+    ///
+    /// # 返回
+    ///
+    /// 返回 n×(n - r) 矩阵，其列构成 `A * x = 0` 解空间的标准正交基，`r` 为数值秩；
+    /// 矩阵列满秩时返回 n×0 矩阵。
+    pub fn null_space(&self) -> Result<Matrix2D, MatrixError> {
+        let svd = self.svd_full()?;
+        let rank = self.rank_from_singular_values(&svd.singular_values);
+        Ok(Matrix2D::new_columns(self.cols(), (rank..self.cols()).map(|k| (0..self.cols()).map(|j| svd.vt[(k, j)]).collect()).collect()))
+    }
+
+    /// 计算列空间（值域）的一组标准正交基。
+    ///
+    /// # 返回
+    ///
+    /// 返回 m×r 矩阵，其列构成 `A` 列空间的标准正交基，`r` 为数值秩。
+    pub fn column_space(&self) -> Result<Matrix2D, MatrixError> {
+        let svd = self.svd()?;
+        let rank = self.rank_from_singular_values(&svd.singular_values);
+        Ok(Matrix2D::new_columns(self.rows(), (0..rank).map(|k| svd.u.column(k)).collect()))
+    }
+
+    /// 计算 2-范数条件数 `σ_max / σ_min`。
+    ///
+    /// # 返回
+    ///
+    /// 返回条件数；最小奇异值为零时返回 `f64::INFINITY`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let a = Matrix2D::new(vec![vec![2.0, 0.0], vec![0.0, 0.5]]).unwrap();
+    /// assert!((a.cond().unwrap() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn cond(&self) -> Result<f64, MatrixError> {
+        let svd = self.svd()?;
+        let max = svd.singular_values.first().copied().unwrap_or(0.0);
+        let min = svd.singular_values.last().copied().unwrap_or(0.0);
+        Ok(if min == 0.0 { f64::INFINITY } else { max / min })
+    }
+
+    /// 默认的奇异值截断阈值 `max(m, n) * EPSILON * σ_max`。
+    fn default_rank_tolerance(&self, singular_values: &[f64]) -> f64 {
+        self.rows().max(self.cols()) as f64 * f64::EPSILON * singular_values.first().copied().unwrap_or(0.0)
+    }
+
+    fn rank_from_singular_values(&self, singular_values: &[f64]) -> usize {
+        let tolerance = self.default_rank_tolerance(singular_values);
+        singular_values.iter().filter(|&&sigma| sigma > tolerance).count()
+    }
+
+    /// 由若干列向量构造 `rows` 行的矩阵，列数可以为零。
+    fn new_columns(rows: usize, columns: Vec<Vec<f64>>) -> Matrix2D {
+        let mut result = Matrix2D::zeros(rows, columns.len());
+        for (j, column) in columns.iter().enumerate() {
+            for (i, &value) in column.iter().enumerate() {
+                result[(i, j)] = value;
+            }
+        }
+        result
+    }
+}
+
+/// 对矩阵的第 `p`、`q` 列做平面旋转。
+fn rotate_columns(a: &mut Matrix2D, p: usize, q: usize, c: f64, s: f64) {
+    let cols = a.cols();
+    for row in a.as_mut_slice().chunks_exact_mut(cols) {
+        let (ap, aq) = (row[p], row[q]);
+        row[p] = c * ap - s * aq;
+        row[q] = s * ap + c * aq;
+    }
+}
+
+/// 以给定的标准正交列向量为前几列，补全为 `rows`×`total` 的列标准正交矩阵。
+///
+/// 补全的列取自这些列向量 QR 分解中完整 `Q` 的剩余列，即它们的正交补空间。
+fn complete_orthonormal_columns(rows: usize, columns: &[Vec<f64>], total: usize) -> Matrix2D {
+    let r = columns.len();
+    let complement = if r == 0 {
+        Matrix2D::eye(rows)
+    } else {
+        Matrix2D::from_fn(rows, r, |i, j| columns[j][i])
+            .qr()
+            .q()
+    };
+
+    Matrix2D::from_fn(rows, total, |i, j| if j < r { columns[j][i] } else { complement[(i, j)] })
+}
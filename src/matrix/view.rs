@@ -0,0 +1,324 @@
+use std::ops::{Index, IndexMut};
+
+use crate::matrix::error::MatrixError;
+use crate::matrix::matrix_2d::Matrix2D;
+
+/// 借用 `Matrix2D` 中一个矩形子块的只读视图，不复制数据。
+///
+/// 第 `i` 行第 `j` 列的元素位于 `data[i * stride + j]`，其中 `stride` 为原矩阵的列数。
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a> {
+    data: &'a [f64],
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+/// 借用 `Matrix2D` 中一个矩形子块的可变视图，写入会直接作用于原矩阵。
+#[derive(Debug)]
+pub struct MatrixViewMut<'a> {
+    data: &'a mut [f64],
+    rows: usize,
+    cols: usize,
+    stride: usize,
+}
+
+/// 检查子块是否落在 `shape` 范围内，并返回子块在行优先存储中的起止位置与子块使用的行跨度。
+///
+/// 空子块不借用任何元素，行跨度取 0，使按行切片时不会越过空切片。
+fn block_range(
+    shape: (usize, usize),
+    (row, col): (usize, usize),
+    (rows, cols): (usize, usize),
+    stride: usize,
+) -> Result<(std::ops::Range<usize>, usize), MatrixError> {
+    if row + rows > shape.0 || col + cols > shape.1 {
+        return Err(MatrixError::IndexOutOfBounds { index: (row + rows, col + cols), shape });
+    }
+    if rows == 0 || cols == 0 {
+        return Ok((0..0, 0));
+    }
+    let start = row * stride + col;
+    Ok((start..start + (rows - 1) * stride + cols, stride))
+}
+
+#[allow(dead_code)]
+impl<'a> MatrixView<'a> {
+    /// 返回视图的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 返回视图的行数。
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// 返回视图的列数。
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// 获取视图的第 `i` 行。
+    ///
+    /// # 注意
+    ///
+    /// 如果行下标越界，将会引发 panic。
+    pub fn row(&self, i: usize) -> &'a [f64] {
+        assert!(i < self.rows, "Row index out of bounds.");
+        &self.data[i * self.stride..i * self.stride + self.cols]
+    }
+
+    /// 按行遍历视图，每一行为一个切片。
+    pub fn row_iter(&self) -> impl Iterator<Item = &'a [f64]> + 'a {
+        let (data, cols, stride) = (self.data, self.cols, self.stride);
+        (0..self.rows).map(move |i| &data[i * stride..i * stride + cols])
+    }
+
+    /// 按列遍历视图，每一列为一个 n×1 的视图。
+    pub fn column_iter(&self) -> impl Iterator<Item = MatrixView<'a>> + 'a {
+        let view = *self;
+        (0..self.cols).map(move |j| view.view(0, j, view.rows, 1).expect("column is within the view"))
+    }
+
+    /// 按行优先顺序遍历视图中的所有元素。
+    pub fn iter(&self) -> impl Iterator<Item = &'a f64> + 'a {
+        self.row_iter().flatten()
+    }
+
+    /// 获取视图中的子块视图。
+    ///
+    /// # 参数
+    ///
+    /// * `row`、`col` - 子块左上角在当前视图中的位置。
+    /// * `rows`、`cols` - 子块的行数与列数。
+    ///
+    /// # 返回
+    ///
+    /// 返回子块视图；如果子块超出当前视图，返回 `MatrixError::IndexOutOfBounds`，
+    /// 其中 `index` 为子块右下角的下一个位置。
+    pub fn view(&self, row: usize, col: usize, rows: usize, cols: usize) -> Result<MatrixView<'a>, MatrixError> {
+        let (range, stride) = block_range(self.shape(), (row, col), (rows, cols), self.stride)?;
+        Ok(MatrixView { data: &self.data[range], rows, cols, stride })
+    }
+
+    /// 将视图复制为独立的矩阵。
+    pub fn to_matrix(&self) -> Matrix2D {
+        let data = self.iter().copied().collect();
+        Matrix2D::from_vec(self.rows, self.cols, data).expect("view length matches its shape")
+    }
+}
+
+impl Index<(usize, usize)> for MatrixView<'_> {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &self.data[i * self.stride + j]
+    }
+}
+
+#[allow(dead_code)]
+impl MatrixViewMut<'_> {
+    /// 返回视图的形状 `(行数, 列数)`。
+    pub fn shape(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    /// 以只读视图的形式重新借用。
+    pub fn as_view(&self) -> MatrixView<'_> {
+        MatrixView { data: self.data, rows: self.rows, cols: self.cols, stride: self.stride }
+    }
+
+    /// 获取视图第 `i` 行的可变切片。
+    ///
+    /// # 注意
+    ///
+    /// 如果行下标越界，将会引发 panic。
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        assert!(i < self.rows, "Row index out of bounds.");
+        &mut self.data[i * self.stride..i * self.stride + self.cols]
+    }
+
+    /// 按行遍历视图，每一行为一个可变切片。
+    pub fn row_iter_mut(&mut self) -> impl Iterator<Item = &mut [f64]> {
+        let (cols, stride) = (self.cols, self.stride);
+        let mut rest = &mut *self.data;
+        (0..self.rows).map(move |_| {
+            // 最后一行之后可能没有完整的跨度，空视图的跨度为 0，每行都得到空切片
+            let data = std::mem::take(&mut rest);
+            let (row, tail) = data.split_at_mut(stride.min(data.len()));
+            rest = tail;
+            &mut row[..cols]
+        })
+    }
+
+    /// 获取视图中子块的可变视图，规则同 [`MatrixView::view`]。
+    pub fn view_mut(&mut self, row: usize, col: usize, rows: usize, cols: usize) -> Result<MatrixViewMut<'_>, MatrixError> {
+        let (range, stride) = block_range(self.shape(), (row, col), (rows, cols), self.stride)?;
+        Ok(MatrixViewMut { data: &mut self.data[range], rows, cols, stride })
+    }
+
+    /// 将视图中的所有元素设为 `value`。
+    pub fn fill(&mut self, value: f64) {
+        for row in self.row_iter_mut() {
+            row.fill(value);
+        }
+    }
+
+    /// 用同形状的矩阵覆盖视图中的元素。
+    ///
+    /// # 返回
+    ///
+    /// 如果形状不同，返回 `MatrixError::DimensionMismatch`。
+    pub fn copy_from(&mut self, source: &Matrix2D) -> Result<(), MatrixError> {
+        if source.shape() != self.shape() {
+            return Err(MatrixError::DimensionMismatch { left: self.shape(), right: source.shape() });
+        }
+        let cols = self.cols;
+        for (i, row) in self.row_iter_mut().enumerate() {
+            row.copy_from_slice(&source.as_slice()[i * cols..(i + 1) * cols]);
+        }
+        Ok(())
+    }
+
+    /// 将视图复制为独立的矩阵。
+    pub fn to_matrix(&self) -> Matrix2D {
+        self.as_view().to_matrix()
+    }
+}
+
+impl Index<(usize, usize)> for MatrixViewMut<'_> {
+    type Output = f64;
+
+    fn index(&self, (i, j): (usize, usize)) -> &f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &self.data[i * self.stride + j]
+    }
+}
+
+impl IndexMut<(usize, usize)> for MatrixViewMut<'_> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut f64 {
+        assert!(i < self.rows && j < self.cols, "Matrix index out of bounds.");
+        &mut self.data[i * self.stride + j]
+    }
+}
+
+#[allow(dead_code)]
+impl Matrix2D {
+    /// 获取整个矩阵的只读视图。
+    pub fn as_view(&self) -> MatrixView<'_> {
+        MatrixView { data: self.as_slice(), rows: self.rows(), cols: self.cols(), stride: self.cols() }
+    }
+
+    /// 获取整个矩阵的可变视图。
+    pub fn as_view_mut(&mut self) -> MatrixViewMut<'_> {
+        let (rows, cols) = self.shape();
+        MatrixViewMut { data: self.as_mut_slice(), rows, cols, stride: cols }
+    }
+
+    /// 借用矩阵中的一个矩形子块，不复制数据。
+    ///
+    /// # 参数
+    ///
+    /// * `row`、`col` - 子块左上角的位置。
+    /// * `rows`、`cols` - 子块的行数与列数。
+    ///
+    /// # 返回
+    ///
+    /// 返回子块的只读视图；如果子块超出矩阵范围，返回 `MatrixError::IndexOutOfBounds`，
+    /// 其中 `index` 为子块右下角的下一个位置。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::from_fn(3, 4, |i, j| (i * 4 + j) as f64);
+    /// let block = m.view(1, 1, 2, 2).unwrap();
+    /// assert_eq!(block.row(0), &[5.0, 6.0]);
+    /// assert_eq!(block[(1, 1)], 10.0);
+    /// assert!(m.view(2, 0, 2, 1).is_err());
+    /// ```
+    pub fn view(&self, row: usize, col: usize, rows: usize, cols: usize) -> Result<MatrixView<'_>, MatrixError> {
+        self.as_view().view(row, col, rows, cols)
+    }
+
+    /// 可变地借用矩阵中的一个矩形子块，规则同 [`Matrix2D::view`]。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let mut m = Matrix2D::zeros(3, 3);
+    /// m.view_mut(0, 1, 2, 2).unwrap().fill(1.0);
+    /// assert_eq!(m.to_nested(), vec![vec![0.0, 1.0, 1.0], vec![0.0, 1.0, 1.0], vec![0.0, 0.0, 0.0]]);
+    /// ```
+    pub fn view_mut(&mut self, row: usize, col: usize, rows: usize, cols: usize) -> Result<MatrixViewMut<'_>, MatrixError> {
+        let (range, stride) = block_range(self.shape(), (row, col), (rows, cols), self.cols())?;
+        Ok(MatrixViewMut { data: &mut self.as_mut_slice()[range], rows, cols, stride })
+    }
+
+    /// 获取矩阵的第 `i` 行。
+    ///
+    /// # 注意
+    ///
+    /// 如果行下标越界，将会引发 panic。
+    pub fn row(&self, i: usize) -> &[f64] {
+        assert!(i < self.rows(), "Row index out of bounds.");
+        &self.as_slice()[i * self.cols()..(i + 1) * self.cols()]
+    }
+
+    /// 获取矩阵第 `i` 行的可变切片。
+    ///
+    /// # 注意
+    ///
+    /// 如果行下标越界，将会引发 panic。
+    pub fn row_mut(&mut self, i: usize) -> &mut [f64] {
+        assert!(i < self.rows(), "Row index out of bounds.");
+        let cols = self.cols();
+        &mut self.as_mut_slice()[i * cols..(i + 1) * cols]
+    }
+
+    /// 按行遍历矩阵，每一行为一个切片。
+    pub fn row_iter(&self) -> impl Iterator<Item = &[f64]> {
+        self.as_view().row_iter()
+    }
+
+    /// 按列遍历矩阵，每一列为一个 n×1 的视图，可通过 [`MatrixView::iter`] 访问元素。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let m = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+    /// let sums: Vec<f64> = m.column_iter().map(|c| c.iter().sum()).collect();
+    /// assert_eq!(sums, vec![4.0, 6.0]);
+    /// ```
+    pub fn column_iter(&self) -> impl Iterator<Item = MatrixView<'_>> {
+        self.as_view().column_iter()
+    }
+
+    /// 用给定矩阵覆盖以 `(row, col)` 为左上角的子块。
+    ///
+    /// # 返回
+    ///
+    /// 如果子块超出矩阵范围，返回 `MatrixError::IndexOutOfBounds`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::matrix::matrix_2d::Matrix2D;
+    ///
+    /// let mut m = Matrix2D::zeros(3, 3);
+    /// m.set_block(1, 1, &Matrix2D::eye(2)).unwrap();
+    /// assert_eq!(m[(2, 2)], 1.0);
+    /// assert!(m.set_block(2, 2, &Matrix2D::eye(2)).is_err());
+    /// ```
+    pub fn set_block(&mut self, row: usize, col: usize, block: &Matrix2D) -> Result<(), MatrixError> {
+        let (rows, cols) = block.shape();
+        self.view_mut(row, col, rows, cols)?.copy_from(block)
+    }
+}
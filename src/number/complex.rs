@@ -0,0 +1,498 @@
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use crate::number::field::Field;
+use crate::number::scalar::{RealField, Scalar};
+
+/// 直角坐标形式的复数 `re + im·i`，分量类型 `T` 默认为 `f64`。
+///
+/// 多值函数（[`Complex::ln`]、[`Complex::sqrt`]、反三角与反双曲函数等）均返回主值，
+/// 分支切割的位置与 C99 及大多数数值库一致；落在切割线上的点按该点为零的分量的符号决定取哪一侧：
+/// 实轴上的切割看虚部，虚轴上的切割看实部，`+0.0` 与从正方向逼近的结果相同，`-0.0` 与从负方向逼近的结果相同。
+/// 反三角与反双曲函数采用 Kahan 的公式，在切割线上保留零的符号，自变量很大时也不会溢出。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::number::complex::Complex;
+///
+/// let z = Complex::new(3.0, 4.0);
+/// assert_eq!(z.norm(), 5.0);
+/// assert_eq!(z * z.conj(), Complex::new(25.0, 0.0));
+/// assert_eq!(z.to_string(), "3+4i");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Complex<T = f64> {
+    /// 实部。
+    pub re: T,
+    /// 虚部。
+    pub im: T,
+}
+
+#[allow(dead_code)]
+impl<T: RealField> Complex<T> {
+    /// 由实部与虚部创建复数。
+    pub fn new(re: T, im: T) -> Complex<T> {
+        Complex { re, im }
+    }
+
+    /// 虚数单位 `i`。
+    pub fn i() -> Complex<T> {
+        Complex::new(T::zero(), T::one())
+    }
+
+    /// 由极坐标形式 `r·e^{iθ}` 创建复数。
+    ///
+    /// # 参数
+    ///
+    /// * `r` - 模。
+    /// * `theta` - 辐角（弧度）。
+    pub fn from_polar(r: T, theta: T) -> Complex<T> {
+        Complex::new(r * theta.cos(), r * theta.sin())
+    }
+
+    /// 转换为极坐标形式，返回 `(模, 辐角)`，辐角位于 `[-π, π]`。
+    pub fn to_polar(self) -> (T, T) {
+        (self.norm(), self.arg())
+    }
+
+    /// 模 `|z|`，计算时不会因中间结果平方而溢出。
+    pub fn norm(self) -> T {
+        self.re.hypot(self.im)
+    }
+
+    /// 模的平方 `re² + im²`。
+    pub fn norm_sqr(self) -> T {
+        self.re * self.re + self.im * self.im
+    }
+
+    /// 辐角的主值，位于 `[-π, π]`；负实轴上按虚部零的符号取 `π` 或 `-π`。
+    pub fn arg(self) -> T {
+        self.im.atan2(self.re)
+    }
+
+    /// 共轭复数 `re - im·i`。
+    pub fn conj(self) -> Complex<T> {
+        Complex::new(self.re, -self.im)
+    }
+
+    /// 倒数 `1 / z`。
+    pub fn recip(self) -> Complex<T> {
+        Complex::one() / self
+    }
+
+    /// 乘以 `i`，直接交换分量而不经过复数乘法，以保留零的符号。
+    fn mul_i(self) -> Complex<T> {
+        Complex::new(-self.im, self.re)
+    }
+
+    /// 乘以 `-i`，同样保留零的符号。
+    fn mul_neg_i(self) -> Complex<T> {
+        Complex::new(self.im, -self.re)
+    }
+
+    /// 判断实部与虚部是否都是有限值。
+    pub fn is_finite(self) -> bool {
+        self.re.is_finite() && self.im.is_finite()
+    }
+
+    /// 复指数 `e^z`。
+    pub fn exp(self) -> Complex<T> {
+        Complex::from_polar(self.re.exp(), self.im)
+    }
+
+    /// 自然对数的主值 `ln|z| + i·arg(z)`，分支切割位于负实轴。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let above = Complex::new(-1.0, 0.0).ln();
+    /// let below = Complex::new(-1.0, -0.0).ln();
+    /// assert_eq!((above.re, above.im), (0.0, std::f64::consts::PI));
+    /// assert_eq!((below.re, below.im), (0.0, -std::f64::consts::PI));
+    /// ```
+    pub fn ln(self) -> Complex<T> {
+        Complex::new(self.norm().ln(), self.arg())
+    }
+
+    /// 平方根的主值，实部非负，分支切割位于负实轴。
+    ///
+    /// 使用 Kahan 的公式，避免 `re` 接近 `-|z|` 时的相消误差；`|z| < 1` 时先开方再缩放，
+    /// 避免 `(|re| + |z|) / 2` 对次正规数下溢为零。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+    /// assert_eq!(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+    /// assert_eq!(Complex::new(3.0, 4.0).sqrt(), Complex::new(2.0, 1.0));
+    /// ```
+    pub fn sqrt(self) -> Complex<T> {
+        if self.re.is_zero() && self.im.is_zero() {
+            return Complex::new(T::zero(), self.im);
+        }
+        let two = T::one() + T::one();
+        let norm = self.norm();
+        let t = if norm >= T::one() {
+            (self.re.abs() / two + norm / two).sqrt()
+        } else {
+            (self.re.abs() + norm).sqrt() * (T::one() / two).sqrt()
+        };
+        if self.re >= T::zero() {
+            Complex::new(t, self.im / (two * t))
+        } else {
+            Complex::new(self.im.abs() / (two * t), t.copysign(self.im))
+        }
+    }
+
+    /// 整数次幂，使用重复平方计算；负指数先求倒数。
+    pub fn powi(self, n: i32) -> Complex<T> {
+        let mut base = if n < 0 { self.recip() } else { self };
+        let mut exponent = n.unsigned_abs();
+        let mut result = Complex::one();
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result *= base;
+            }
+            base *= base;
+            exponent >>= 1;
+        }
+        result
+    }
+
+    /// 实数次幂的主值 `|z|^p · e^{i·p·arg(z)}`，`0` 的正数次幂为 `0`。
+    pub fn powf(self, exponent: T) -> Complex<T> {
+        if self.is_zero() {
+            return if exponent.is_zero() { Complex::one() } else { Complex::zero() };
+        }
+        let (r, theta) = self.to_polar();
+        Complex::from_polar(r.powf(exponent), theta * exponent)
+    }
+
+    /// 复数次幂的主值 `e^{w·ln z}`，`0` 的实部为正的次幂为 `0`。
+    pub fn powc(self, exponent: Complex<T>) -> Complex<T> {
+        if self.is_zero() {
+            return if exponent.is_zero() { Complex::one() } else { Complex::zero() };
+        }
+        (exponent * self.ln()).exp()
+    }
+
+    /// 正弦 `sin(a + bi) = sin a·cosh b + i·cos a·sinh b`。
+    pub fn sin(self) -> Complex<T> {
+        Complex::new(self.re.sin() * self.im.cosh(), self.re.cos() * self.im.sinh())
+    }
+
+    /// 余弦 `cos(a + bi) = cos a·cosh b - i·sin a·sinh b`。
+    pub fn cos(self) -> Complex<T> {
+        Complex::new(self.re.cos() * self.im.cosh(), -(self.re.sin() * self.im.sinh()))
+    }
+
+    /// 正切，按 `tan z = -i·tanh(iz)` 计算，虚部很大时同样不会溢出。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let t = Complex::new(1.0, 400.0).tan();
+    /// assert!((t - Complex::i()).norm() < 1e-15);
+    /// ```
+    pub fn tan(self) -> Complex<T> {
+        self.mul_i().tanh().mul_neg_i()
+    }
+
+    /// 双曲正弦 `sinh(a + bi) = sinh a·cos b + i·cosh a·sin b`。
+    pub fn sinh(self) -> Complex<T> {
+        Complex::new(self.re.sinh() * self.im.cos(), self.re.cosh() * self.im.sin())
+    }
+
+    /// 双曲余弦 `cosh(a + bi) = cosh a·cos b + i·sinh a·sin b`。
+    pub fn cosh(self) -> Complex<T> {
+        Complex::new(self.re.cosh() * self.im.cos(), self.re.sinh() * self.im.sin())
+    }
+
+    /// 双曲正切，使用 Kahan 的公式 `(β·ρ·s + i·t) / (1 + β·s²)`，其中 `t = tan b`、`β = 1 + t²`、
+    /// `s = sinh a`、`ρ = sqrt(1 + s²)`；不需要计算 `2b`，虚部很大时也不会溢出。
+    ///
+    /// `|a|` 超过 `-ln ε / 2` 时 `tanh a` 已舍入为 `±1`，此时虚部改用 `4·sin b·cos b·e^{-2|a|}`，
+    /// 避免 `sinh a` 的平方溢出后相除得到 NaN。
+    pub fn tanh(self) -> Complex<T> {
+        let (one, two) = (T::one(), T::one() + T::one());
+        if self.re.abs() > -T::epsilon().ln() / two {
+            let four = two + two;
+            let im = four * self.im.sin() * self.im.cos() * (-two * self.re.abs()).exp();
+            return Complex::new(one.copysign(self.re), im);
+        }
+        let t = self.im.tan();
+        let beta = one + t * t;
+        let s = self.re.sinh();
+        let rho = (one + s * s).sqrt();
+        let denominator = one + beta * s * s;
+        Complex::new(beta * rho * s / denominator, t / denominator)
+    }
+
+    /// 反正弦的主值，按 `asin z = -i·ln(iz + sqrt(1 - z²))` 的定义取值。
+    ///
+    /// 分支切割位于实轴上 `|x| > 1` 的两段，实部位于 `[-π/2, π/2]`。使用 Kahan 的公式，
+    /// 以 `sqrt(1 - z)·sqrt(1 + z)` 代替 `sqrt(1 - z²)`，切割线上保留虚部零的符号，`|z|` 很大时也不会溢出。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::number::complex::Complex;
+    ///
+    /// let above = Complex::new(2.0, 0.0).asin();
+    /// let below = Complex::new(2.0, -0.0).asin();
+    /// assert!(above.im > 0.0 && below.im < 0.0);
+    /// assert!(Complex::new(1e200, 0.0).asin().is_finite());
+    /// ```
+    pub fn asin(self) -> Complex<T> {
+        let one = T::one();
+        let xi = Complex::new(one - self.re, -self.im).sqrt();
+        let eta = Complex::new(one + self.re, self.im).sqrt();
+        Complex::new(
+            (self.re / (xi.re * eta.re - xi.im * eta.im)).atan(),
+            (xi.re * eta.im - xi.im * eta.re).asinh(),
+        )
+    }
+
+    /// 反余弦的主值，按 `acos z = -i·ln(z + i·sqrt(1 - z²))` 的定义取值。
+    ///
+    /// 分支切割位于实轴上 `|x| > 1` 的两段，实部位于 `[0, π]`，计算方式同 [`Complex::asin`]。
+    pub fn acos(self) -> Complex<T> {
+        let (one, two) = (T::one(), T::one() + T::one());
+        let xi = Complex::new(one - self.re, -self.im).sqrt();
+        let eta = Complex::new(one + self.re, self.im).sqrt();
+        Complex::new(two * (xi.re / eta.re).atan(), (eta.re * xi.im - eta.im * xi.re).asinh())
+    }
+
+    /// 反正切的主值，按 `atan z = -i·atanh(iz)` 计算。
+    ///
+    /// 分支切割位于虚轴上 `|y| > 1` 的两段，实部位于 `[-π/2, π/2]`；`z = ±i` 处为无穷大。
+    pub fn atan(self) -> Complex<T> {
+        self.mul_i().atanh().mul_neg_i()
+    }
+
+    /// 反双曲正弦的主值，按 `asinh z = -i·asin(iz)` 计算，分支切割位于虚轴上 `|y| > 1` 的两段。
+    pub fn asinh(self) -> Complex<T> {
+        self.mul_i().asin().mul_neg_i()
+    }
+
+    /// 反双曲余弦的主值，按 `acosh z = 2·ln(sqrt((z + 1) / 2) + sqrt((z - 1) / 2))` 的定义取值，
+    /// 分支切割位于实轴上 `x < 1` 的部分，实部非负。
+    ///
+    /// 使用 Kahan 的公式，由 `sqrt(z - 1)` 与 `sqrt(z + 1)` 直接得到实部与虚部。
+    pub fn acosh(self) -> Complex<T> {
+        let (one, two) = (T::one(), T::one() + T::one());
+        let xi = Complex::new(self.re - one, self.im).sqrt();
+        let eta = Complex::new(self.re + one, self.im).sqrt();
+        Complex::new((xi.re * eta.re + xi.im * eta.im).asinh(), two * (xi.im / eta.re).atan())
+    }
+
+    /// 反双曲正切的主值，按 `atanh z = (ln(1 + z) - ln(1 - z)) / 2` 的定义取值，
+    /// 分支切割位于实轴上 `|x| > 1` 的两段。
+    ///
+    /// 使用 Kahan 的公式：先借助 `atanh(-z) = -atanh(z)` 化到实部非负的半平面，
+    /// `|z|` 很大时使用 `atanh z = atanh(1/z) ± iπ/2` 的首项，避免平方溢出。
+    pub fn atanh(self) -> Complex<T> {
+        let (one, two) = (T::one(), T::one() + T::one());
+        let four = two + two;
+        let sign = one.copysign(self.re);
+        let (x, y) = (self.re * sign, self.im * sign);
+        let half_pi = T::pi() / two;
+        let (re, im) = if x.max(y.abs()) > one / T::epsilon().sqrt() {
+            let r = x.hypot(y);
+            (x / r / r, half_pi.copysign(y) - y / r / r)
+        } else if x == one && !y.is_zero() && y.abs() < one {
+            // 1 - x 恰为零，单独处理以免 4 / y² 溢出
+            let ay = y.abs();
+            ((four + y * y).ln() / four - ay.ln() / two, (half_pi + (ay / two).atan()).copysign(y) / two)
+        } else {
+            let t = (one - x) * (one - x) + y * y;
+            ((four * x / t).ln_1p() / four, (two * y).atan2((one - x) * (one + x) - y * y) / two)
+        };
+        Complex::new(re * sign, im * sign)
+    }
+}
+
+impl<T: RealField> From<T> for Complex<T> {
+    fn from(re: T) -> Complex<T> {
+        Complex::new(re, T::zero())
+    }
+}
+
+impl<T: RealField> Display for Complex<T> {
+    /// 格式为 `a+bi` 或 `a-bi`，格式说明中的精度同时作用于实部与虚部。
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let sign = if T::one().copysign(self.im) < T::zero() { '-' } else { '+' };
+        match f.precision() {
+            Some(precision) => write!(f, "{:.*}{}{:.*}i", precision, self.re, sign, precision, self.im.abs()),
+            None => write!(f, "{}{}{}i", self.re, sign, self.im.abs()),
+        }
+    }
+}
+
+impl<T: RealField> Field for Complex<T> {
+    fn zero() -> Self {
+        Complex::new(T::zero(), T::zero())
+    }
+
+    fn one() -> Self {
+        Complex::new(T::one(), T::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.re.is_zero() && self.im.is_zero()
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.norm().to_f64()
+    }
+}
+
+impl<T: RealField> Scalar for Complex<T> {
+    type Real = T;
+
+    fn from_f64(value: f64) -> Self {
+        Complex::new(T::from_f64(value), T::zero())
+    }
+
+    fn from_real(value: T) -> Self {
+        Complex::new(value, T::zero())
+    }
+
+    fn conj(self) -> Self {
+        Complex::conj(self)
+    }
+
+    fn modulus(self) -> T {
+        self.norm()
+    }
+}
+
+impl<T: RealField> Add for Complex<T> {
+    type Output = Complex<T>;
+
+    fn add(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re + rhs.re, self.im + rhs.im)
+    }
+}
+
+impl<T: RealField> Sub for Complex<T> {
+    type Output = Complex<T>;
+
+    fn sub(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re - rhs.re, self.im - rhs.im)
+    }
+}
+
+impl<T: RealField> Mul for Complex<T> {
+    type Output = Complex<T>;
+
+    fn mul(self, rhs: Complex<T>) -> Complex<T> {
+        Complex::new(self.re * rhs.re - self.im * rhs.im, self.re * rhs.im + self.im * rhs.re)
+    }
+}
+
+impl<T: RealField> Div for Complex<T> {
+    type Output = Complex<T>;
+
+    /// 使用 Smith 算法，先以较大的分量缩放分母，避免 `c² + d²` 溢出或下溢；除以零得到 NaN。
+    fn div(self, rhs: Complex<T>) -> Complex<T> {
+        let (a, b, c, d) = (self.re, self.im, rhs.re, rhs.im);
+        if c.abs() >= d.abs() {
+            let ratio = d / c;
+            let denominator = c + d * ratio;
+            Complex::new((a + b * ratio) / denominator, (b - a * ratio) / denominator)
+        } else {
+            let ratio = c / d;
+            let denominator = c * ratio + d;
+            Complex::new((a * ratio + b) / denominator, (b * ratio - a) / denominator)
+        }
+    }
+}
+
+impl<T: RealField> Neg for Complex<T> {
+    type Output = Complex<T>;
+
+    fn neg(self) -> Complex<T> {
+        Complex::new(-self.re, -self.im)
+    }
+}
+
+/// 复数与实数之间的二元运算：实数按虚部为零的复数参与运算。
+macro_rules! impl_real_operand {
+    ($($trait:ident, $method:ident);*) => {$(
+        impl<T: RealField> $trait<T> for Complex<T> {
+            type Output = Complex<T>;
+
+            fn $method(self, rhs: T) -> Complex<T> {
+                self.$method(Complex::from(rhs))
+            }
+        }
+    )*};
+}
+
+impl_real_operand!(Add, add; Sub, sub; Mul, mul; Div, div);
+
+/// 复合赋值运算，右操作数可以是复数或实数。
+macro_rules! impl_complex_assign_op {
+    ($($trait:ident, $method:ident, $op:ident);*) => {$(
+        impl<T: RealField> $trait for Complex<T> {
+            fn $method(&mut self, rhs: Complex<T>) {
+                *self = self.$op(rhs);
+            }
+        }
+
+        impl<T: RealField> $trait<T> for Complex<T> {
+            fn $method(&mut self, rhs: T) {
+                *self = self.$op(rhs);
+            }
+        }
+    )*};
+}
+
+impl_complex_assign_op!(AddAssign, add_assign, add; SubAssign, sub_assign, sub; MulAssign, mul_assign, mul; DivAssign, div_assign, div);
+
+/// 实数在左侧的二元运算；孤儿规则不允许对泛型 `T` 实现，因此逐个列出实数类型。
+macro_rules! impl_real_left_ops {
+    ($($t:ty),*) => {$(
+        impl Add<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn add(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex::from(self) + rhs
+            }
+        }
+
+        impl Sub<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn sub(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex::from(self) - rhs
+            }
+        }
+
+        impl Mul<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn mul(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex::new(self * rhs.re, self * rhs.im)
+            }
+        }
+
+        impl Div<Complex<$t>> for $t {
+            type Output = Complex<$t>;
+
+            fn div(self, rhs: Complex<$t>) -> Complex<$t> {
+                Complex::from(self) / rhs
+            }
+        }
+    )*};
+}
+
+impl_real_left_ops!(f32, f64);
@@ -0,0 +1,73 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::number::rational::Rational;
+
+/// 域（Field）：支持加、减、乘以及除以非零元素的数，是高斯消元所需的最小代数结构。
+///
+/// 对 `f64` 而言 [`Field::is_zero`] 是精确比较，舍入误差不会被视为零，因此依赖它的精确
+/// 消元算法（如 [`Matrix2D::rref`](crate::matrix::matrix_2d::Matrix2D::rref)）主要面向
+/// [`Rational`] 这类精确类型；浮点矩阵请优先使用 LU、QR 等带容差的分解。
+pub trait Field:
+    Clone + PartialEq + Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self> + Div<Output = Self> + Neg<Output = Self>
+{
+    /// 加法单位元。
+    fn zero() -> Self;
+
+    /// 乘法单位元。
+    fn one() -> Self;
+
+    /// 判断是否为加法单位元。
+    fn is_zero(&self) -> bool {
+        *self == Self::zero()
+    }
+
+    /// 元素的大小，仅用于消元时选取绝对值最大的主元。
+    fn magnitude(&self) -> f64;
+}
+
+impl Field for f64 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.abs()
+    }
+}
+
+impl Field for f32 {
+    fn zero() -> Self {
+        0.0
+    }
+
+    fn one() -> Self {
+        1.0
+    }
+
+    fn magnitude(&self) -> f64 {
+        f64::from(self.abs())
+    }
+}
+
+impl Field for Rational {
+    fn zero() -> Self {
+        Rational::from_integer(0)
+    }
+
+    fn one() -> Self {
+        Rational::from_integer(1)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.numer() == 0
+    }
+
+    fn magnitude(&self) -> f64 {
+        self.to_f64().abs()
+    }
+}
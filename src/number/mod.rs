@@ -0,0 +1,4 @@
+pub mod complex;
+pub mod field;
+pub mod rational;
+pub mod scalar;
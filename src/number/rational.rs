@@ -0,0 +1,206 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// 以 `i64` 分子与分母表示的精确有理数。
+///
+/// 始终保持规范形式：分母为正，分子与分母互素，零表示为 `0/1`。
+/// 因此派生的 `PartialEq`、`Eq` 与 `Hash` 直接比较分子和分母即可。
+///
+/// # 注意
+///
+/// 运算的中间结果以 `i128` 计算并约分，约分后仍超出 `i64` 范围时将会引发 panic；
+/// 除以零同样会引发 panic，与整数除法的行为一致。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::number::rational::Rational;
+///
+/// let a = Rational::new(1, 3);
+/// let b = Rational::new(1, 6);
+/// assert_eq!(a + b, Rational::new(1, 2));
+/// assert_eq!(Rational::new(4, -6), Rational::new(-2, 3));
+/// assert_eq!((a / b).to_string(), "2");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numer: i64,
+    denom: i64,
+}
+
+#[allow(dead_code)]
+impl Rational {
+    /// 创建有理数 `numer / denom` 并化为规范形式。
+    ///
+    /// # 注意
+    ///
+    /// 如果分母为零，将会引发 panic。
+    pub fn new(numer: i64, denom: i64) -> Rational {
+        Rational::reduce(numer as i128, denom as i128)
+    }
+
+    /// 创建整数 `n`，即 `n / 1`。
+    pub fn from_integer(n: i64) -> Rational {
+        Rational { numer: n, denom: 1 }
+    }
+
+    /// 返回规范形式下的分子。
+    pub fn numer(&self) -> i64 {
+        self.numer
+    }
+
+    /// 返回规范形式下的分母，恒为正。
+    pub fn denom(&self) -> i64 {
+        self.denom
+    }
+
+    /// 判断是否为整数。
+    pub fn is_integer(&self) -> bool {
+        self.denom == 1
+    }
+
+    /// 转换为最接近的 `f64`。
+    pub fn to_f64(&self) -> f64 {
+        self.numer as f64 / self.denom as f64
+    }
+
+    /// 返回绝对值。
+    ///
+    /// # 注意
+    ///
+    /// 分子为 `i64::MIN` 且分母为 1 时结果无法用 `i64` 表示，将会引发 panic。
+    pub fn abs(&self) -> Rational {
+        Rational::reduce((self.numer as i128).abs(), self.denom as i128)
+    }
+
+    /// 返回倒数 `denom / numer`。
+    ///
+    /// # 注意
+    ///
+    /// 如果当前值为零，将会引发 panic。
+    pub fn recip(&self) -> Rational {
+        Rational::reduce(self.denom as i128, self.numer as i128)
+    }
+
+    /// 约分并把符号移到分子上，结果超出 `i64` 范围时引发 panic。
+    fn reduce(numer: i128, denom: i128) -> Rational {
+        assert!(denom != 0, "Division by zero.");
+        let divisor = gcd(numer, denom) as i128 * denom.signum();
+        let (numer, denom) = (numer / divisor, denom / divisor);
+        match (i64::try_from(numer), i64::try_from(denom)) {
+            (Ok(numer), Ok(denom)) => Rational { numer, denom },
+            _ => panic!("Rational overflow: {}/{} does not fit in i64.", numer, denom),
+        }
+    }
+}
+
+/// 求最大公约数，`denom` 非零时结果为正。
+fn gcd(a: i128, b: i128) -> u128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Rational::from_integer(0)
+    }
+}
+
+impl From<i64> for Rational {
+    fn from(n: i64) -> Self {
+        Rational::from_integer(n)
+    }
+}
+
+impl From<i32> for Rational {
+    fn from(n: i32) -> Self {
+        Rational::from_integer(n as i64)
+    }
+}
+
+impl fmt::Display for Rational {
+    /// 整数只输出分子，否则输出 `分子/分母`。
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.denom == 1 {
+            write!(f, "{}", self.numer)
+        } else {
+            write!(f, "{}/{}", self.numer, self.denom)
+        }
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Rational {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // 分母恒为正，交叉相乘不改变不等号方向
+        (self.numer as i128 * other.denom as i128).cmp(&(other.numer as i128 * self.denom as i128))
+    }
+}
+
+impl Add for Rational {
+    type Output = Rational;
+
+    fn add(self, rhs: Rational) -> Rational {
+        Rational::reduce(
+            self.numer as i128 * rhs.denom as i128 + rhs.numer as i128 * self.denom as i128,
+            self.denom as i128 * rhs.denom as i128,
+        )
+    }
+}
+
+impl Sub for Rational {
+    type Output = Rational;
+
+    fn sub(self, rhs: Rational) -> Rational {
+        self + -rhs
+    }
+}
+
+impl Mul for Rational {
+    type Output = Rational;
+
+    fn mul(self, rhs: Rational) -> Rational {
+        Rational::reduce(self.numer as i128 * rhs.numer as i128, self.denom as i128 * rhs.denom as i128)
+    }
+}
+
+impl Div for Rational {
+    type Output = Rational;
+
+    fn div(self, rhs: Rational) -> Rational {
+        Rational::reduce(self.numer as i128 * rhs.denom as i128, self.denom as i128 * rhs.numer as i128)
+    }
+}
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational::reduce(-(self.numer as i128), self.denom as i128)
+    }
+}
+
+/// 由二元运算生成对应的复合赋值运算。
+macro_rules! impl_rational_assign_op {
+    ($trait:ident, $method:ident, $op:tt) => {
+        impl $trait for Rational {
+            fn $method(&mut self, rhs: Rational) {
+                *self = *self $op rhs;
+            }
+        }
+    };
+}
+
+impl_rational_assign_op!(AddAssign, add_assign, +);
+impl_rational_assign_op!(SubAssign, sub_assign, -);
+impl_rational_assign_op!(MulAssign, mul_assign, *);
+impl_rational_assign_op!(DivAssign, div_assign, /);
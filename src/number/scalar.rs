@@ -0,0 +1,257 @@
+use std::fmt::Display;
+use std::ops::{AddAssign, DivAssign, MulAssign, SubAssign};
+
+use crate::number::field::Field;
+
+/// 矩阵与向量的元素类型：可按值复制、支持复合赋值运算的数域。
+///
+/// 与 [`Field`] 相比额外要求 `Copy`，使数值内核可以按值读写元素；不要求全序，
+/// 因此 [`Complex`](crate::number::complex::Complex) 也实现了该 trait。需要比较大小或开方等
+/// 实数运算时请使用 [`RealField`]。
+pub trait Scalar: Field + Copy + Display + AddAssign + SubAssign + MulAssign + DivAssign + Send + Sync + 'static {
+    /// 模所属的实数类型，实数的 `Real` 为其自身，`Complex<T>` 的 `Real` 为 `T`。
+    type Real: RealField;
+
+    /// 将 `f64` 转换为该类型，对 `f32` 会舍入到最接近的可表示值。
+    fn from_f64(value: f64) -> Self;
+
+    /// 将实数嵌入该类型，对复数得到虚部为零的值。
+    fn from_real(value: Self::Real) -> Self;
+
+    /// 共轭，实数的共轭为其自身。
+    fn conj(self) -> Self;
+
+    /// 模（绝对值），用于选取主元与判断奇异性。
+    fn modulus(self) -> Self::Real;
+}
+
+/// 实数标量：在 [`Scalar`] 的基础上提供比较、开方、三角函数等运算，目前由 `f32` 与 `f64` 实现。
+///
+/// # 示例
+///
+/// ```
+/// use rs_math::number::scalar::RealField;
+///
+/// fn hypotenuse<T: RealField>(a: T, b: T) -> T {
+///     (a * a + b * b).sqrt()
+/// }
+///
+/// assert_eq!(hypotenuse(3.0f32, 4.0f32), 5.0f32);
+/// assert_eq!(hypotenuse(3.0f64, 4.0f64), 5.0f64);
+/// ```
+pub trait RealField: Scalar<Real = Self> + PartialOrd {
+    /// 转换为 `f64`，`f32` 到 `f64` 的转换是精确的。
+    fn to_f64(self) -> f64;
+
+    /// 机器精度，即 1 与下一个可表示数之差。
+    fn epsilon() -> Self;
+
+    /// 圆周率 π。
+    fn pi() -> Self;
+
+    /// 绝对值。
+    fn abs(self) -> Self;
+
+    /// 平方根，负数返回 NaN。
+    fn sqrt(self) -> Self;
+
+    /// 整数次幂。
+    fn powi(self, n: i32) -> Self;
+
+    /// 实数次幂。
+    fn powf(self, n: Self) -> Self;
+
+    /// 自然指数 `e^x`。
+    fn exp(self) -> Self;
+
+    /// 自然对数。
+    fn ln(self) -> Self;
+
+    /// 计算 `ln(1 + self)`，`self` 接近零时比直接计算更精确。
+    fn ln_1p(self) -> Self;
+
+    /// 正弦，参数为弧度。
+    fn sin(self) -> Self;
+
+    /// 余弦，参数为弧度。
+    fn cos(self) -> Self;
+
+    /// 正切，参数为弧度。
+    fn tan(self) -> Self;
+
+    /// 反正弦，返回 `[-π/2, π/2]` 内的弧度。
+    fn asin(self) -> Self;
+
+    /// 反余弦，返回 `[0, π]` 内的弧度。
+    fn acos(self) -> Self;
+
+    /// 反正切，返回 `[-π/2, π/2]` 内的弧度。
+    fn atan(self) -> Self;
+
+    /// 双曲正弦。
+    fn sinh(self) -> Self;
+
+    /// 双曲余弦。
+    fn cosh(self) -> Self;
+
+    /// 双曲正切。
+    fn tanh(self) -> Self;
+
+    /// 反双曲正弦，`|x|` 接近类型上限时也不会溢出。
+    fn asinh(self) -> Self;
+
+    /// 四象限反正切 `atan2(self, other)`，返回 `[-π, π]` 内的弧度。
+    fn atan2(self, other: Self) -> Self;
+
+    /// 计算 `sqrt(self² + other²)`，中间结果不会溢出。
+    fn hypot(self, other: Self) -> Self;
+
+    /// 两者中的较大值，其中一个为 NaN 时返回另一个。
+    fn max(self, other: Self) -> Self;
+
+    /// 两者中的较小值，其中一个为 NaN 时返回另一个。
+    fn min(self, other: Self) -> Self;
+
+    /// 取 `self` 的绝对值与 `sign` 的符号位，`-0.0` 视为负号。
+    fn copysign(self, sign: Self) -> Self;
+
+    /// 判断是否既不是无穷大也不是 NaN。
+    fn is_finite(self) -> bool;
+}
+
+macro_rules! impl_real_field {
+    ($($t:ident),*) => {$(
+        impl Scalar for $t {
+            type Real = $t;
+
+            fn from_f64(value: f64) -> Self {
+                value as $t
+            }
+
+            fn from_real(value: Self) -> Self {
+                value
+            }
+
+            fn conj(self) -> Self {
+                self
+            }
+
+            fn modulus(self) -> Self {
+                $t::abs(self)
+            }
+        }
+
+        impl RealField for $t {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+
+            fn epsilon() -> Self {
+                $t::EPSILON
+            }
+
+            fn pi() -> Self {
+                std::$t::consts::PI
+            }
+
+            fn abs(self) -> Self {
+                $t::abs(self)
+            }
+
+            fn sqrt(self) -> Self {
+                $t::sqrt(self)
+            }
+
+            fn powi(self, n: i32) -> Self {
+                $t::powi(self, n)
+            }
+
+            fn powf(self, n: Self) -> Self {
+                $t::powf(self, n)
+            }
+
+            fn exp(self) -> Self {
+                $t::exp(self)
+            }
+
+            fn ln(self) -> Self {
+                $t::ln(self)
+            }
+
+            fn ln_1p(self) -> Self {
+                $t::ln_1p(self)
+            }
+
+            fn sin(self) -> Self {
+                $t::sin(self)
+            }
+
+            fn cos(self) -> Self {
+                $t::cos(self)
+            }
+
+            fn tan(self) -> Self {
+                $t::tan(self)
+            }
+
+            fn asin(self) -> Self {
+                $t::asin(self)
+            }
+
+            fn acos(self) -> Self {
+                $t::acos(self)
+            }
+
+            fn atan(self) -> Self {
+                $t::atan(self)
+            }
+
+            fn sinh(self) -> Self {
+                $t::sinh(self)
+            }
+
+            fn cosh(self) -> Self {
+                $t::cosh(self)
+            }
+
+            fn tanh(self) -> Self {
+                $t::tanh(self)
+            }
+
+            fn asinh(self) -> Self {
+                // 标准库的实现在 |x| 接近上限时中间结果会溢出，大数改用 asinh x ≈ ln 2|x|
+                if $t::abs(self) > 1.0 / $t::EPSILON {
+                    ($t::ln($t::abs(self)) + std::$t::consts::LN_2).copysign(self)
+                } else {
+                    $t::asinh(self)
+                }
+            }
+
+            fn atan2(self, other: Self) -> Self {
+                $t::atan2(self, other)
+            }
+
+            fn hypot(self, other: Self) -> Self {
+                $t::hypot(self, other)
+            }
+
+            fn max(self, other: Self) -> Self {
+                $t::max(self, other)
+            }
+
+            fn min(self, other: Self) -> Self {
+                $t::min(self, other)
+            }
+
+            fn copysign(self, sign: Self) -> Self {
+                $t::copysign(self, sign)
+            }
+
+            fn is_finite(self) -> bool {
+                $t::is_finite(self)
+            }
+        }
+    )*};
+}
+
+impl_real_field!(f32, f64);
@@ -0,0 +1,19 @@
+use std::error::Error;
+use std::fmt;
+
+/// 向量运算中可能出现的错误。
+#[derive(Debug, Clone, PartialEq)]
+pub enum VectorError {
+    /// 运算需要确定的方向，但向量的长度为零，例如单位化零向量或向零向量投影。
+    ZeroLength,
+}
+
+impl fmt::Display for VectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VectorError::ZeroLength => write!(f, "vector has zero length"),
+        }
+    }
+}
+
+impl Error for VectorError {}
@@ -1,2 +1,3 @@
+pub mod error;
 pub mod vector_2d;
 pub mod vector_3d;
\ No newline at end of file
@@ -1,6 +1,13 @@
+use std::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::graphical::point_2d::Point2D;
+use crate::number::scalar::RealField;
+use crate::vector::error::VectorError;
+
 /// 定义二维向量结构体。
 ///
-/// 该结构体包含两个分量，分别表示向量的 x 和 y 坐标。
+/// 该结构体包含两个分量，分别表示向量的 x 和 y 坐标。分量类型 `T` 默认为 `f64`，
+/// 也可以使用 `f32` 等实现了 [`RealField`] 的类型。
 ///
 /// # 字段
 ///
@@ -13,16 +20,17 @@
 /// use rs_math::vector::vector_2d::Vector2D;
 ///
 /// let v = Vector2D { x: 1.0, y: 2.0 };
-///
+/// let w: Vector2D<f32> = Vector2D::new(3.0, 4.0);
+/// assert_eq!(w.magnitude(), 5.0f32);
 /// ```
-
-pub struct Vector2D {
-    pub x: f64,
-    pub y: f64,
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Vector2D<T = f64> {
+    pub x: T,
+    pub y: T,
 }
 
 // 实现一个方法来创建新的二维向量
-impl Vector2D {
+impl<T: RealField> Vector2D<T> {
     /// 创建新的二维向量。
     ///
     /// 该函数创建一个新的二维向量，其 x 坐标和 y 坐标分别为 `x` 和 `y`。
@@ -44,7 +52,7 @@ impl Vector2D {
     /// let v = Vector2D::new(1.0, 2.0);
     ///
     /// ```
-    pub fn new(x: f64, y: f64) -> Vector2D {
+    pub fn new(x: T, y: T) -> Vector2D<T> {
         Vector2D { x, y }
     }
 
@@ -58,7 +66,7 @@ impl Vector2D {
     ///
     /// # 返回值
     ///
-    /// 返回二维向量的模（长度）。
+    /// 返回二维向量的模（长度），使用 `hypot` 计算，分量很大或很小时中间结果不会溢出或下溢。
     ///
     /// # 示例
     ///
@@ -68,8 +76,8 @@ impl Vector2D {
     /// let v = Vector2D::new(1.0, 2.0);
     ///
     /// ```
-    pub fn magnitude(&self) -> f64 {
-        (self.x.powi(2) + self.y.powi(2)).sqrt()
+    pub fn magnitude(&self) -> T {
+        self.x.hypot(self.y)
     }
 
 
@@ -97,8 +105,8 @@ impl Vector2D {
     /// let v3 = v1.add(v2);
     ///
     /// ```
-    pub fn add(self, other: Vector2D) -> Vector2D {
-        Vector2D { x: self.x + other.x, y: self.y + other.y }
+    pub fn add(self, other: Vector2D<T>) -> Vector2D<T> {
+        self + other
     }
 
     /// 向量减法。
@@ -120,8 +128,8 @@ impl Vector2D {
     /// let vector2 = Vector2D { x: 1.0, y: 4.0 };
     /// let result = vector1.subtract(vector2);
     /// ```
-    pub fn subtract(self, other: Vector2D) -> Vector2D {
-        Vector2D { x: self.x - other.x, y: self.y - other.y }
+    pub fn subtract(self, other: Vector2D<T>) -> Vector2D<T> {
+        self - other
     }
 
     /// 向量点积。
@@ -143,7 +151,7 @@ impl Vector2D {
     /// let vector2 = Vector2D { x: 1.0, y: 4.0 };
     /// let result = vector1.dot_product(vector2);
     /// ```
-    pub fn dot_product(self, other: Vector2D) -> f64 {
+    pub fn dot_product(self, other: Vector2D<T>) -> T {
         self.x * other.x + self.y * other.y
     }
 
@@ -166,7 +174,207 @@ impl Vector2D {
     /// let vector2 = Vector2D { x: 1.0, y: 4.0 };
     /// let result = vector1.cross_product(vector2);
     /// ```
-    pub fn cross_product(self, other: Vector2D) -> f64 {
+    pub fn cross_product(self, other: Vector2D<T>) -> T {
         self.x * other.y - self.y * other.x
     }
+
+    /// 返回方向相同的单位向量。
+    ///
+    /// # 返回值
+    ///
+    /// 返回长度为 1 的向量；如果向量长度为零，返回 `VectorError::ZeroLength`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::vector::vector_2d::Vector2D;
+    ///
+    /// let unit = Vector2D::new(3.0, 4.0).normalize().unwrap();
+    /// assert_eq!(unit, Vector2D::new(0.6, 0.8));
+    /// assert!(Vector2D::new(0.0, 0.0).normalize().is_err());
+    /// ```
+    pub fn normalize(self) -> Result<Vector2D<T>, VectorError> {
+        let magnitude = self.magnitude();
+        if magnitude.is_zero() {
+            return Err(VectorError::ZeroLength);
+        }
+        Ok(self / magnitude)
+    }
+
+    /// 返回逆时针旋转 90° 得到的垂直向量 `(-y, x)`，长度不变。
+    pub fn perpendicular(self) -> Vector2D<T> {
+        Vector2D { x: -self.y, y: self.x }
+    }
+
+    /// 计算在 `other` 方向上的投影 `(self·other / |other|²) * other`。
+    ///
+    /// # 参数
+    ///
+    /// - `other`：投影的方向，不要求是单位向量。
+    ///
+    /// # 返回值
+    ///
+    /// 返回与 `other` 平行的投影向量；如果 `other` 长度为零，返回 `VectorError::ZeroLength`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::vector::vector_2d::Vector2D;
+    ///
+    /// let v = Vector2D::new(2.0, 3.0);
+    /// let axis = Vector2D::new(4.0, 0.0);
+    /// assert_eq!(v.project_onto(axis).unwrap(), Vector2D::new(2.0, 0.0));
+    /// assert_eq!(v.reject_from(axis).unwrap(), Vector2D::new(0.0, 3.0));
+    /// ```
+    pub fn project_onto(self, other: Vector2D<T>) -> Result<Vector2D<T>, VectorError> {
+        let length_squared = other.dot_product(other);
+        if length_squared.is_zero() {
+            return Err(VectorError::ZeroLength);
+        }
+        Ok(other * (self.dot_product(other) / length_squared))
+    }
+
+    /// 计算相对 `other` 方向的拒绝分量，即减去投影后与 `other` 垂直的部分。
+    ///
+    /// # 返回值
+    ///
+    /// 返回 `self - self.project_onto(other)`；如果 `other` 长度为零，返回 `VectorError::ZeroLength`。
+    pub fn reject_from(self, other: Vector2D<T>) -> Result<Vector2D<T>, VectorError> {
+        Ok(self - self.project_onto(other)?)
+    }
+
+    /// 关于法线为 `normal` 的直线做镜面反射 `v - 2 (v·n̂) n̂`。
+    ///
+    /// # 参数
+    ///
+    /// - `normal`：反射面的法向量，不要求是单位向量。
+    ///
+    /// # 返回值
+    ///
+    /// 返回反射后的向量；如果 `normal` 长度为零，返回 `VectorError::ZeroLength`。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::vector::vector_2d::Vector2D;
+    ///
+    /// // 以 y 轴正方向为法线，即在水平地面上反弹
+    /// let bounced = Vector2D::new(1.0, -2.0).reflect(Vector2D::new(0.0, 5.0)).unwrap();
+    /// assert_eq!(bounced, Vector2D::new(1.0, 2.0));
+    /// ```
+    pub fn reflect(self, normal: Vector2D<T>) -> Result<Vector2D<T>, VectorError> {
+        let projection = self.project_onto(normal)?;
+        Ok(self - projection - projection)
+    }
+
+    /// 计算从 `self` 转到 `other` 的有向角（弧度），逆时针为正，范围为 `[-π, π]`。
+    ///
+    /// 任一向量为零向量时返回 0。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::vector::vector_2d::Vector2D;
+    /// use std::f64::consts::FRAC_PI_2;
+    ///
+    /// let x = Vector2D::new(1.0, 0.0);
+    /// let y = Vector2D::new(0.0, 2.0);
+    /// assert!((x.angle_to(y) - FRAC_PI_2).abs() < 1e-12);
+    /// assert!((y.angle_to(x) + FRAC_PI_2).abs() < 1e-12);
+    /// ```
+    pub fn angle_to(self, other: Vector2D<T>) -> T {
+        self.cross_product(other).atan2(self.dot_product(other))
+    }
+
+    /// 绕原点逆时针旋转 `angle` 弧度。
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// use rs_math::vector::vector_2d::Vector2D;
+    ///
+    /// let rotated = Vector2D::new(1.0, 0.0).rotate(std::f64::consts::FRAC_PI_2);
+    /// assert!(rotated.x.abs() < 1e-12 && (rotated.y - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn rotate(self, angle: T) -> Vector2D<T> {
+        let (sin, cos) = (angle.sin(), angle.cos());
+        Vector2D { x: self.x * cos - self.y * sin, y: self.x * sin + self.y * cos }
+    }
+
+    /// 线性插值 `self + (other - self) * t`，`t = 0` 得到 `self`，`t = 1` 得到 `other`。
+    ///
+    /// `t` 不限于 `[0, 1]`，超出范围时沿直线外推。
+    pub fn lerp(self, other: Vector2D<T>, t: T) -> Vector2D<T> {
+        self + (other - self) * t
+    }
+}
+
+impl<T: RealField> Add for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn add(self, other: Vector2D<T>) -> Vector2D<T> {
+        Vector2D { x: self.x + other.x, y: self.y + other.y }
+    }
+}
+
+impl<T: RealField> Sub for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn sub(self, other: Vector2D<T>) -> Vector2D<T> {
+        Vector2D { x: self.x - other.x, y: self.y - other.y }
+    }
+}
+
+impl<T: RealField> Mul<T> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn mul(self, scalar: T) -> Vector2D<T> {
+        Vector2D { x: self.x * scalar, y: self.y * scalar }
+    }
+}
+
+impl<T: RealField> Div<T> for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    /// 各分量除以标量，除以零按 IEEE 754 规则得到无穷大或 NaN。
+    fn div(self, scalar: T) -> Vector2D<T> {
+        Vector2D { x: self.x / scalar, y: self.y / scalar }
+    }
+}
+
+impl<T: RealField> Neg for Vector2D<T> {
+    type Output = Vector2D<T>;
+
+    fn neg(self) -> Vector2D<T> {
+        Vector2D { x: -self.x, y: -self.y }
+    }
+}
+
+/// 标量在左侧的乘法；孤儿规则不允许对泛型 `T` 实现，因此逐个列出分量类型。
+macro_rules! impl_scalar_left_mul {
+    ($($t:ty),*) => {$(
+        impl Mul<Vector2D<$t>> for $t {
+            type Output = Vector2D<$t>;
+
+            fn mul(self, vector: Vector2D<$t>) -> Vector2D<$t> {
+                vector * self
+            }
+        }
+    )*};
+}
+
+impl_scalar_left_mul!(f32, f64);
+
+/// 把点视为从原点出发的位置向量。
+impl From<Point2D> for Vector2D {
+    fn from(point: Point2D) -> Vector2D {
+        Vector2D { x: point.x, y: point.y }
+    }
+}
+
+/// 把向量视为位置向量，得到其终点。
+impl From<Vector2D> for Point2D {
+    fn from(vector: Vector2D) -> Point2D {
+        Point2D { x: vector.x, y: vector.y }
+    }
 }
\ No newline at end of file
@@ -1,11 +1,14 @@
+use crate::number::scalar::RealField;
+
+/// 三维向量，分量类型 `T` 默认为 `f64`，也可以使用 `f32` 等实现了 [`RealField`] 的类型。
 #[derive(Debug)]
-pub struct Vector3D {
-    pub x: f64,
-    pub y: f64,
-    pub z: f64,
+pub struct Vector3D<T = f64> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
 }
 
-impl Vector3D {
+impl<T: RealField> Vector3D<T> {
     /// 向量加法操作。
     ///
     /// # 参数
@@ -25,7 +28,7 @@ impl Vector3D {
     /// let vector2 = Vector3D { x: 4.0, y: 5.0, z: 6.0 };
     /// let result = vector1.add(&vector2);
     /// ```
-    pub fn add(&self, other: &Vector3D) -> Vector3D {
+    pub fn add(&self, other: &Vector3D<T>) -> Vector3D<T> {
         Vector3D {
             x: self.x + other.x,
             y: self.y + other.y,
@@ -52,7 +55,7 @@ impl Vector3D {
     /// let vector2 = Vector3D { x: 4.0, y: 5.0, z: 6.0 };
     /// let result = vector1.subtract(&vector2);
     /// ```
-    pub fn subtract(&self, other: &Vector3D) -> Vector3D {
+    pub fn subtract(&self, other: &Vector3D<T>) -> Vector3D<T> {
         Vector3D {
             x: self.x - other.x,
             y: self.y - other.y,
@@ -79,7 +82,7 @@ impl Vector3D {
     /// let scalar = 2.0;
     /// let result = vector.scalar_multiply(scalar);
     /// ```
-    pub fn scalar_multiply(&self, scalar: f64) -> Vector3D {
+    pub fn scalar_multiply(&self, scalar: T) -> Vector3D<T> {
         Vector3D {
             x: self.x * scalar,
             y: self.y * scalar,
@@ -106,7 +109,7 @@ impl Vector3D {
     /// let vector2 = Vector3D { x: 4.0, y: 5.0, z: 6.0 };
     /// let cross_product = vector1.cross_product(&vector2);
     /// ```
-    pub fn cross_product(&self, other: &Vector3D) -> Vector3D {
+    pub fn cross_product(&self, other: &Vector3D<T>) -> Vector3D<T> {
         Vector3D {
             x: self.y * other.z - self.z * other.y,
             y: self.z * other.x - self.x * other.z,
@@ -133,7 +136,7 @@ impl Vector3D {
     /// let vector2 = Vector3D { x: 4.0, y: 5.0, z: 6.0 };
     /// let dot_product = vector1.dot_product(&vector2);
     /// ```
-    pub fn dot_product(&self, other: &Vector3D) -> f64 {
+    pub fn dot_product(&self, other: &Vector3D<T>) -> T {
         self.x * other.x + self.y * other.y + self.z * other.z
     }
     /// 计算向量的模长。
@@ -150,7 +153,7 @@ impl Vector3D {
     /// let vector = Vector3D { x: 3.0, y: 4.0, z: 5.0 };
     /// let magnitude = vector.magnitude();
     /// ```
-    pub fn magnitude(&self) -> f64 {
+    pub fn magnitude(&self) -> T {
         (self.x.powi(2) + self.y.powi(2) + self.z.powi(2)).sqrt()
     }
     /// 计算两个向量之间的夹角（弧度）。
@@ -172,7 +175,7 @@ impl Vector3D {
     /// let vector2 = Vector3D { x: 1.0, y: 4.0, z: 2.0 };
     /// let angle = vector1.angle_between(&vector2);
     /// ```
-    pub fn angle_between(&self, other: &Vector3D) -> f64 {
+    pub fn angle_between(&self, other: &Vector3D<T>) -> T {
         let dot_product = self.dot_product(other);
         let magnitude_product = self.magnitude() * other.magnitude();
 
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use rs_math::graphical::ellipse::Ellipse;
+
+    /// `Ellipse` 的字段是私有的，从 `Debug` 输出中依次读出 `[h, k, a, b, phi]`。
+    fn parameters(ellipse: &Ellipse) -> Vec<f64> {
+        format!("{:?}", ellipse)
+            .trim_start_matches("Ellipse {")
+            .trim_end_matches('}')
+            .split(',')
+            .map(|field| field.split(':').nth(1).unwrap().trim().parse().unwrap())
+            .collect()
+    }
+
+    fn sample(h: f64, k: f64, a: f64, b: f64, phi: f64) -> Vec<(f64, f64)> {
+        (0..12)
+            .map(|i| {
+                let t = i as f64 * PI / 6.0;
+                let (u, v) = (a * t.cos(), b * t.sin());
+                (h + u * phi.cos() - v * phi.sin(), k + u * phi.sin() + v * phi.cos())
+            })
+            .collect()
+    }
+
+    fn assert_parameters(ellipse: &Ellipse, expected: [f64; 5]) {
+        let actual = parameters(ellipse);
+        for (value, expected) in actual.iter().zip(expected) {
+            assert!((value - expected).abs() < 1e-9, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn test_fit_with_more_than_five_points() {
+        let fitted = Ellipse::fit(&sample(1.0, -2.0, 3.0, 2.0, 0.0)).unwrap();
+        assert_parameters(&fitted, [1.0, -2.0, 3.0, 2.0, 0.0]);
+        assert!((fitted.area() - 6.0 * PI).abs() < 1e-9);
+        let ((x1, y1), (x2, y2)) = fitted.calculate_foci();
+        let c = 5f64.sqrt();
+        assert!((x1 - (1.0 + c)).abs() < 1e-9 && (x2 - (1.0 - c)).abs() < 1e-9);
+        assert!((y1 + 2.0).abs() < 1e-9 && (y2 + 2.0).abs() < 1e-9);
+
+        // 旋转的椭圆同样恢复中心、半轴长与长轴方向
+        let rotated = Ellipse::fit(&sample(0.5, 1.0, 4.0, 1.5, PI / 6.0)).unwrap();
+        assert_parameters(&rotated, [0.5, 1.0, 4.0, 1.5, PI / 6.0]);
+    }
+
+    #[test]
+    fn test_fit_rejects_hyperbola() {
+        let points: Vec<(f64, f64)> = (1..7).map(|i| (i as f64, 1.0 / i as f64)).collect();
+
+        assert!(Ellipse::fit(&points).is_none());
+    }
+
+    #[test]
+    fn test_fit_degenerate_points() {
+        // 所有点共线，二次曲线系数无法唯一确定
+        let points: Vec<(f64, f64)> = (0..6).map(|i| (i as f64, 2.0 * i as f64)).collect();
+
+        assert!(Ellipse::fit(&points).is_none());
+    }
+}
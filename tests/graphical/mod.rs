@@ -1 +1,2 @@
- mod circle_test;
\ No newline at end of file
+ mod circle_test;
+ mod ellipse_test;
\ No newline at end of file
@@ -1,5 +1,6 @@
 pub  mod graphical;
 pub mod matrix;
+pub mod number;
 pub mod vector;
 
 #[cfg(test)]
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::banded::{solve_cyclic_tridiagonal, solve_tridiagonal, BandedMatrix};
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    fn assert_vec_eq(actual: &[f64], expected: &[f64], tol: f64) {
+        assert_eq!(actual.len(), expected.len());
+        for (i, (a, e)) in actual.iter().zip(expected).enumerate() {
+            assert!((a - e).abs() < tol, "mismatch at {}: {} != {}", i, a, e);
+        }
+    }
+
+    /// 下带宽 2、上带宽 1 的非对称带状矩阵，首列主元很小以触发行交换。
+    fn banded_sample(n: usize) -> Matrix2D {
+        Matrix2D::from_fn(n, n, |i, j| match j as isize - i as isize {
+            0 if i == 0 => 1e-3,
+            0 => 3.0 + i as f64,
+            -1 => 2.0,
+            -2 => -1.0 + 0.1 * j as f64,
+            1 => 0.5,
+            _ => 0.0,
+        })
+    }
+
+    #[test]
+    fn test_banded_lu_matches_dense() {
+        let n = 9;
+        let dense = banded_sample(n);
+        let band = BandedMatrix::from_matrix(&dense, 2, 1).unwrap();
+        assert_eq!(band.to_dense(), dense);
+        assert_eq!(band.bandwidth(), (2, 1));
+
+        let b: Vec<f64> = (0..n).map(|i| (i as f64).sin()).collect();
+        assert_vec_eq(&band.mul_vector(&b).unwrap(), &dense.try_mul_vector(&b).unwrap(), 1e-12);
+
+        let lu = band.lu().unwrap();
+        let x = lu.solve(&b).unwrap();
+        assert_vec_eq(&dense.try_mul_vector(&x).unwrap(), &b, 1e-10);
+        let det = dense.determinant().unwrap();
+        assert!((lu.determinant() - det).abs() < 1e-9 * det.abs());
+    }
+
+    #[test]
+    fn test_banded_errors() {
+        let mut band = BandedMatrix::zeros(3, 1, 0);
+        assert_eq!(band.set(0, 2, 1.0), Err(MatrixError::IndexOutOfBounds { index: (0, 2), shape: (3, 3) }));
+        band.set(1, 0, 1.0).unwrap();
+        assert_eq!(band.get(0, 1), 0.0);
+        assert_eq!(band.lu(), Err(MatrixError::Singular { pivot: 1 }));
+        assert!(band.solve(&[1.0]).is_err());
+        assert_eq!(
+            BandedMatrix::tridiagonal(&[1.0], &[1.0, 2.0, 3.0], &[1.0, 1.0]),
+            Err(MatrixError::DataLength { expected: 2, found: 1 })
+        );
+    }
+
+    #[test]
+    fn test_thomas_algorithm() {
+        let n = 50;
+        let (sub, diag, sup) = (vec![-1.0; n - 1], vec![2.5; n], vec![-1.2; n - 1]);
+        let rhs: Vec<f64> = (0..n).map(|i| 1.0 + i as f64 * 0.1).collect();
+        let x = solve_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+
+        let band = BandedMatrix::tridiagonal(&sub, &diag, &sup).unwrap();
+        assert_vec_eq(&band.mul_vector(&x).unwrap(), &rhs, 1e-10);
+        assert_vec_eq(&band.solve(&rhs).unwrap(), &x, 1e-10);
+
+        assert_eq!(
+            solve_tridiagonal(&[1.0], &[1.0, 1.0], &[1.0], &[1.0, 1.0]),
+            Err(MatrixError::Singular { pivot: 1 })
+        );
+    }
+
+    #[test]
+    fn test_cyclic_tridiagonal() {
+        for n in [1, 2, 3, 8] {
+            let sub: Vec<f64> = (0..n).map(|i| -1.0 - 0.1 * i as f64).collect();
+            let diag = vec![4.0; n];
+            let sup: Vec<f64> = (0..n).map(|i| -0.5 + 0.05 * i as f64).collect();
+            let rhs: Vec<f64> = (0..n).map(|i| (i as f64 + 1.0).ln() + 1.0).collect();
+            let x = solve_cyclic_tridiagonal(&sub, &diag, &sup, &rhs).unwrap();
+
+            for i in 0..n {
+                let previous = sub[i] * x[(i + n - 1) % n];
+                let next = sup[i] * x[(i + 1) % n];
+                assert!((previous + diag[i] * x[i] + next - rhs[i]).abs() < 1e-12, "n = {}", n);
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    fn spd_matrix() -> Matrix2D {
+        Matrix2D::new(vec![
+            vec![4.0, 12.0, -16.0],
+            vec![12.0, 37.0, -43.0],
+            vec![-16.0, -43.0, 98.0],
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_cholesky_factor() {
+        let cholesky = spd_matrix().cholesky().unwrap();
+        let expected = Matrix2D::new(vec![
+            vec![2.0, 0.0, 0.0],
+            vec![6.0, 1.0, 0.0],
+            vec![-8.0, 5.0, 3.0],
+        ]).unwrap();
+        assert_matrix_eq(&cholesky.l(), &expected, 1e-12);
+    }
+
+    #[test]
+    fn test_cholesky_helpers() {
+        let a = spd_matrix();
+        let cholesky = a.cholesky().unwrap();
+
+        let x = cholesky.solve(&[1.0, 2.0, 3.0]).unwrap();
+        let ax = a.multiply_by_vector(&x).unwrap();
+        for (row, expected) in ax.as_slice().iter().zip([1.0, 2.0, 3.0]) {
+            assert!((row - expected).abs() < 1e-10);
+        }
+
+        // det = (2 * 1 * 3)^2 = 36
+        assert!((cholesky.log_determinant() - 36.0_f64.ln()).abs() < 1e-12);
+        assert_matrix_eq(&a.multiply(&cholesky.inverse()).unwrap(), &Matrix2D::eye(3), 1e-10);
+    }
+
+    #[test]
+    fn test_cholesky_reports_first_non_positive_pivot() {
+        let a = Matrix2D::new(vec![
+            vec![1.0, 2.0, 0.0],
+            vec![2.0, 1.0, 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]).unwrap();
+        assert_eq!(a.cholesky(), Err(MatrixError::NotPositiveDefinite { pivot: 1 }));
+
+        let b = Matrix2D::new(vec![vec![1.0, 2.0]]).unwrap();
+        assert_eq!(b.cholesky(), Err(MatrixError::NotSquare { rows: 1, cols: 2 }));
+    }
+
+    #[test]
+    fn test_ldlt_indefinite_matrix() {
+        let a = Matrix2D::new(vec![
+            vec![2.0, 1.0, 0.0],
+            vec![1.0, -3.0, 2.0],
+            vec![0.0, 2.0, 1.0],
+        ]).unwrap();
+        let ldlt = a.ldlt().unwrap();
+
+        let mut d = Matrix2D::zeros(3, 3);
+        for (i, &value) in ldlt.d().iter().enumerate() {
+            d[(i, i)] = value;
+        }
+        let l = ldlt.l();
+        assert_matrix_eq(&l.multiply(&d).unwrap().multiply(&l.transpose()).unwrap(), &a, 1e-12);
+
+        let x = ldlt.solve(&[3.0, 0.0, 3.0]).unwrap();
+        let ax = a.multiply_by_vector(&x).unwrap();
+        for (row, expected) in ax.as_slice().iter().zip([3.0, 0.0, 3.0]) {
+            assert!((row - expected).abs() < 1e-12);
+        }
+
+        let det = a.determinant().unwrap();
+        let (sign, log_abs) = ldlt.log_determinant();
+        assert_eq!(sign, det.signum());
+        assert!((log_abs - det.abs().ln()).abs() < 1e-12);
+        assert_matrix_eq(&a.multiply(&ldlt.inverse()).unwrap(), &Matrix2D::eye(3), 1e-12);
+    }
+
+    #[test]
+    fn test_ldlt_zero_pivot() {
+        let a = Matrix2D::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap();
+        assert_eq!(a.ldlt(), Err(MatrixError::Singular { pivot: 0 }));
+    }
+}
@@ -0,0 +1,91 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::number::complex::Complex;
+
+    fn c(re: f64, im: f64) -> Complex {
+        Complex::new(re, im)
+    }
+
+    fn assert_matrix_close(actual: &Matrix2D<Complex>, expected: &Matrix2D<Complex>) {
+        assert_eq!(actual.shape(), expected.shape());
+        for (a, b) in actual.as_slice().iter().zip(expected.as_slice()) {
+            assert!((*a - *b).norm() < 1e-12, "{} != {}", a, b);
+        }
+    }
+
+    fn sample() -> Matrix2D<Complex> {
+        Matrix2D::new(vec![
+            vec![c(2.0, 1.0), c(0.0, -1.0), c(1.0, 0.0)],
+            vec![c(1.0, 1.0), c(3.0, 0.0), c(0.0, 2.0)],
+            vec![c(0.0, 0.0), c(-1.0, 1.0), c(4.0, -1.0)],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_complex_matrix_multiply_and_conjugate_transpose() {
+        let a = sample();
+        let h = a.conjugate_transpose();
+        assert_eq!(h[(0, 1)], c(1.0, -1.0));
+        assert_eq!(h.conjugate_transpose(), a);
+        assert_eq!(a.conjugate().transpose(), h);
+        // (AB)ᴴ = BᴴAᴴ
+        let b = Matrix2D::from_fn(3, 2, |i, j| c(i as f64, j as f64 - 1.0));
+        assert_matrix_close(&(&a * &b).conjugate_transpose(), &(&b.conjugate_transpose() * &h));
+        // AᴴA 是 Hermitian 矩阵，对角线为实数
+        let gram = &h * &a;
+        assert_matrix_close(&gram, &gram.conjugate_transpose());
+        assert!((0..3).all(|i| gram[(i, i)].im.abs() < 1e-12));
+        assert_eq!(Matrix2D::from_parts(&a.real_part(), &a.imag_part()).unwrap(), a);
+        assert!(Matrix2D::from_parts(&Matrix2D::zeros(1, 2), &Matrix2D::zeros(2, 1)).is_err());
+    }
+
+    #[test]
+    fn test_complex_lu_solve_determinant_inverse() {
+        let a = sample();
+        let lu = a.lu().unwrap();
+        assert_matrix_close(&(&lu.p() * &a), &(&lu.l() * &lu.u()));
+
+        let expected = vec![c(1.0, -1.0), c(0.5, 2.0), c(-3.0, 0.0)];
+        let b = &a * &expected;
+        let x = lu.solve(&b).unwrap();
+        for (value, target) in x.iter().zip(&expected) {
+            assert!((*value - *target).norm() < 1e-12);
+        }
+
+        let inverse = a.inverse().unwrap();
+        assert_matrix_close(&(&a * &inverse), &Matrix2D::identity(3));
+
+        // 行列式与逆矩阵的行列式互为倒数，也等于 Aᴴ 行列式的共轭
+        let det = a.determinant().unwrap();
+        assert!((det * inverse.determinant().unwrap() - c(1.0, 0.0)).norm() < 1e-12);
+        assert!((a.conjugate_transpose().determinant().unwrap() - det.conj()).norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_singular_complex_matrix() {
+        let a = Matrix2D::new(vec![vec![c(1.0, 1.0), c(2.0, 2.0)], vec![c(0.0, 1.0), c(0.0, 2.0)]]).unwrap();
+        assert!(a.lu().unwrap().is_singular());
+        assert!(a.inverse().is_err());
+        assert!(a.determinant().unwrap().norm() < 1e-12);
+    }
+
+    #[test]
+    fn test_general_eigen_complex_helpers() {
+        let a = Matrix2D::new(vec![vec![1.0, -2.0, 0.0], vec![2.0, 1.0, 0.0], vec![0.0, 0.0, 3.0]]).unwrap();
+        let eigen = a.general_eigen().unwrap();
+        let eigenvalues = eigen.eigenvalues();
+        let product = eigenvalues.iter().fold(c(1.0, 0.0), |acc, &z| acc * z);
+        assert!((product - c(a.determinant().unwrap(), 0.0)).norm() < 1e-10);
+
+        let complex_a = a.map(|&x| c(x, 0.0));
+        for (k, &lambda) in eigenvalues.iter().enumerate() {
+            let v = eigen.complex_eigenvector(k);
+            let av = &complex_a * &v;
+            for (left, right) in av.iter().zip(&v) {
+                assert!((*left - lambda * *right).norm() < 1e-10);
+            }
+        }
+    }
+}
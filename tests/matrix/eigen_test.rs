@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    #[test]
+    fn test_symmetric_eigen_decomposition() {
+        let a = Matrix2D::new(vec![
+            vec![4.0, 1.0, -2.0, 2.0],
+            vec![1.0, 2.0, 0.0, 1.0],
+            vec![-2.0, 0.0, 3.0, -2.0],
+            vec![2.0, 1.0, -2.0, -1.0],
+        ]).unwrap();
+        let eigen = a.symmetric_eigen().unwrap();
+        let v = &eigen.eigenvectors;
+
+        // 特征值升序排列
+        assert!(eigen.eigenvalues.windows(2).all(|w| w[0] <= w[1]));
+        // 特征向量标准正交
+        assert_matrix_eq(&v.transpose().multiply(v).unwrap(), &Matrix2D::eye(4), 1e-12);
+        // A = V * Λ * Vᵀ
+        let mut lambda = Matrix2D::zeros(4, 4);
+        for i in 0..4 {
+            lambda[(i, i)] = eigen.eigenvalues[i];
+        }
+        assert_matrix_eq(&v.multiply(&lambda).unwrap().multiply(&v.transpose()).unwrap(), &a, 1e-12);
+        // 特征值之和等于迹
+        let sum: f64 = eigen.eigenvalues.iter().sum();
+        assert!((sum - 8.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_eigenvalue_eigenvector_non_diagonal() {
+        let a = Matrix2D::new(vec![vec![2.0, 1.0], vec![1.0, 2.0]]).unwrap();
+        let (values, vectors) = a.eigenvalue_eigenvector().unwrap();
+
+        assert!((values[0] - 1.0).abs() < 1e-12);
+        assert!((values[1] - 3.0).abs() < 1e-12);
+        for (value, vector) in values.iter().zip(&vectors) {
+            let av = a.multiply_by_vector(vector).unwrap();
+            for (i, component) in vector.iter().enumerate() {
+                assert!((av[(i, 0)] - value * component).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reports_errors() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(a.symmetric_eigen(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+
+        let b = Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 1.0]]).unwrap();
+        assert_eq!(b.symmetric_eigen_with(0.0, 0), Err(MatrixError::NotConverged { iterations: 0 }));
+        assert_eq!(b.symmetric_eigen().unwrap().sweeps, 1);
+    }
+}
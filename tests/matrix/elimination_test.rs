@@ -0,0 +1,71 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::number::field::Field;
+    use rs_math::number::rational::Rational;
+
+    fn exact(rows: Vec<Vec<i64>>) -> Matrix2D<Rational> {
+        Matrix2D::new(rows.into_iter().map(|row| row.into_iter().map(Rational::from).collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_rref_reports_pivot_columns() {
+        let a = exact(vec![vec![1, 2, -1, -4], vec![2, 3, -1, -11], vec![-2, 0, -3, 22]]);
+        let (rref, pivots) = a.rref();
+        assert_eq!(pivots, vec![0, 1, 2]);
+        assert_eq!(rref, exact(vec![vec![1, 0, 0, -8], vec![0, 1, 0, 1], vec![0, 0, 1, -2]]));
+
+        // 秩亏矩阵：第三列等于前两列之和，因此第三列没有主元
+        let deficient = exact(vec![vec![1, 2, 3, 1], vec![2, 1, 3, 0], vec![3, 3, 6, 5]]);
+        let (rref, pivots) = deficient.rref();
+        assert_eq!(pivots, vec![0, 1, 3]);
+        assert_eq!(rref, exact(vec![vec![1, 0, 1, 0], vec![0, 1, 1, 0], vec![0, 0, 0, 1]]));
+        let (_, pivots) = exact(vec![vec![1, 2], vec![2, 4], vec![3, 6]]).rref();
+        assert_eq!(pivots, vec![0]);
+
+        let (rref, pivots) = Matrix2D::<Rational>::new(vec![]).unwrap().rref();
+        assert!(pivots.is_empty() && rref.shape() == (0, 0));
+    }
+
+    #[test]
+    fn test_bareiss_determinant_is_exact() {
+        let a = exact(vec![vec![2, -3, 1], vec![2, 0, -1], vec![1, 4, 5]]);
+        assert_eq!(a.bareiss_determinant().unwrap(), Rational::from_integer(49));
+
+        // 首个主元为零时需要换行，并翻转符号
+        let swapped = exact(vec![vec![0, 1, 2], vec![1, 0, 3], vec![4, -3, 8]]);
+        assert_eq!(swapped.bareiss_determinant().unwrap(), Rational::from_integer(-2));
+
+        // 5 阶 Hilbert 矩阵的行列式为 1/266716800000
+        let hilbert = Matrix2D::from_fn(5, 5, |i, j| Rational::new(1, (i + j + 1) as i64));
+        assert_eq!(hilbert.bareiss_determinant().unwrap(), Rational::new(1, 266_716_800_000));
+
+        assert_eq!(exact(vec![vec![1, 2], vec![2, 4]]).bareiss_determinant().unwrap(), Rational::zero());
+        assert_eq!(exact(vec![vec![1, 2, 3]]).bareiss_determinant(), Err(MatrixError::NotSquare { rows: 1, cols: 3 }));
+
+        // f64 同样满足 Field，结果与 LU 一致
+        let float = a.map(|x| x.to_f64());
+        assert!((float.bareiss_determinant().unwrap() - float.determinant().unwrap()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_gauss_jordan_inverse_is_exact() {
+        let hilbert = Matrix2D::from_fn(4, 4, |i, j| Rational::new(1, (i + j + 1) as i64));
+        let inverse = hilbert.gauss_jordan_inverse().unwrap();
+        assert_eq!(inverse[(0, 0)], Rational::from_integer(16));
+        assert_eq!(inverse[(3, 3)], Rational::from_integer(2800));
+        assert_eq!(inverse[(1, 2)], Rational::from_integer(-2700));
+
+        // A * A⁻¹ 精确等于单位矩阵
+        for i in 0..4 {
+            for j in 0..4 {
+                let sum = (0..4).fold(Rational::zero(), |acc, k| acc + hilbert[(i, k)] * inverse[(k, j)]);
+                assert_eq!(sum, if i == j { Rational::one() } else { Rational::zero() });
+            }
+        }
+
+        let singular = exact(vec![vec![1, 2, 3], vec![2, 4, 6], vec![1, 0, 1]]);
+        assert_eq!(singular.gauss_jordan_inverse(), Err(MatrixError::Singular { pivot: 2 }));
+    }
+}
@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::number::rational::Rational;
+
+    #[test]
+    fn test_display_aligns_and_respects_precision() {
+        let m: Matrix2D = "[1 -20; 300 4.5]".parse().unwrap();
+        assert_eq!(m.to_string(), "[  1  -20]\n[300  4.5]");
+        assert_eq!(format!("{:.1}", m), "[  1.0  -20.0]\n[300.0    4.5]");
+        assert_eq!(Matrix2D::zeros(0, 3).to_string(), "[]");
+
+        // 元素类型只需实现 Display
+        let exact = Matrix2D::from_fn(2, 2, |i, j| Rational::new(i as i64 + 1, j as i64 + 2));
+        assert_eq!(exact.to_string(), "[1/2  1/3]\n[  1  2/3]");
+    }
+
+    #[test]
+    fn test_display_truncates_large_matrices() {
+        let m = Matrix2D::from_fn(20, 12, |i, j| (i * 100 + j) as f64);
+        let text = m.to_string();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines.len(), 9);
+        assert_eq!(lines[0], "[   0     1     2     3  ...     8     9    10    11]");
+        assert_eq!(lines[4], "[ ...   ...   ...   ...  ...   ...   ...   ...   ...]");
+        assert!(lines[8].ends_with("1908  1909  1910  1911]"));
+
+        // `{:#}` 显示全部元素
+        assert_eq!(format!("{:#}", m).lines().count(), 20);
+        assert!(!format!("{:#}", m).contains("..."));
+    }
+
+    #[test]
+    fn test_latex_rendering() {
+        let m: Matrix2D = "[1 0; 0 -1]".parse().unwrap();
+        assert_eq!(m.to_latex(None), "\\begin{bmatrix}\n1 & 0 \\\\\n0 & -1\n\\end{bmatrix}");
+        assert_eq!(Matrix2D::zeros(0, 0).to_latex(Some(2)), "\\begin{bmatrix}\n\\end{bmatrix}");
+    }
+
+    #[test]
+    fn test_parse_literals() {
+        let expected = Matrix2D::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        for literal in ["[1 2 3; 4 5 6]", "1, 2, 3; 4, 5, 6", "[1 2 3\n 4 5 6]", " [1 2 3;\n\n4 5 6;] ", "[1e0 2 3;4 5 6e0]"] {
+            assert_eq!(literal.parse::<Matrix2D>().unwrap(), expected, "literal {:?}", literal);
+        }
+        assert_eq!("[]".parse::<Matrix2D>().unwrap().shape(), (0, 0));
+        assert_eq!("".parse::<Matrix2D>().unwrap().shape(), (0, 0));
+
+        // Display 的输出可以再解析回来
+        let m = Matrix2D::from_fn(3, 3, |i, j| 0.1 * i as f64 - j as f64 / 7.0);
+        assert_eq!(format!("{:#}", m).replace("]\n[", ";").parse::<Matrix2D>().unwrap(), m);
+
+        let error = |literal: &str| {
+            let error = literal.parse::<Matrix2D>().unwrap_err();
+            (error.line, error.column, error.message)
+        };
+        assert_eq!(error("[1 2; 3 x]"), (1, 9, "invalid element `x`".to_string()));
+        assert_eq!(error("[1 2\n 3 4 5]"), (2, 6, "row has 3 elements, expected 2".to_string()));
+        assert_eq!(error("[1 2; 3]"), (1, 8, "row has 1 elements, expected 2".to_string()));
+        assert_eq!(error("  [1 2"), (1, 3, "missing closing `]`".to_string()));
+        assert_eq!(error("[1 2] 3"), (1, 7, "unexpected character after `]`".to_string()));
+        assert_eq!(error("1 2]"), (1, 4, "unexpected `]`".to_string()));
+    }
+}
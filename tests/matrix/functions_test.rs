@@ -0,0 +1,81 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    fn rotation(theta: f64) -> Matrix2D {
+        Matrix2D::new(vec![vec![theta.cos(), -theta.sin()], vec![theta.sin(), theta.cos()]]).unwrap()
+    }
+
+    fn generator(theta: f64) -> Matrix2D {
+        Matrix2D::new(vec![vec![0.0, -theta], vec![theta, 0.0]]).unwrap()
+    }
+
+    #[test]
+    fn test_expm_closed_forms() {
+        // 覆盖低阶 Padé 与 13 阶缩放平方两条路径
+        for &theta in &[1e-3, 0.2, 0.9, 2.0, 3.0, 12.5] {
+            assert_matrix_eq(&generator(theta).expm().unwrap(), &rotation(theta), 1e-12);
+        }
+
+        // 幂零矩阵：exp(N) = I + N + N²/2
+        let nilpotent = Matrix2D::new(vec![vec![0.0, 1.0, 2.0], vec![0.0, 0.0, 3.0], vec![0.0, 0.0, 0.0]]).unwrap();
+        let expected = Matrix2D::new(vec![vec![1.0, 1.0, 3.5], vec![0.0, 1.0, 3.0], vec![0.0, 0.0, 1.0]]).unwrap();
+        assert_matrix_eq(&nilpotent.expm().unwrap(), &expected, 1e-14);
+
+        let diagonal = Matrix2D::new(vec![vec![-20.0, 0.0], vec![0.0, 5.0]]).unwrap();
+        let exp = diagonal.expm().unwrap();
+        assert!(((exp[(0, 0)] - (-20.0_f64).exp()) / (-20.0_f64).exp()).abs() < 1e-12);
+        assert!(((exp[(1, 1)] - 5.0_f64.exp()) / 5.0_f64.exp()).abs() < 1e-12);
+
+        let rect = Matrix2D::zeros(2, 3);
+        assert_eq!(rect.expm(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+    }
+
+    #[test]
+    fn test_logm_inverts_expm() {
+        assert_matrix_eq(&rotation(1.2).logm().unwrap(), &generator(1.2), 1e-12);
+        assert_matrix_eq(&Matrix2D::eye(3).logm().unwrap(), &Matrix2D::zeros(3, 3), 1e-15);
+
+        let a = Matrix2D::new(vec![vec![0.5, -1.0, 0.2], vec![0.3, -0.4, 0.8], vec![-0.6, 0.1, 0.7]]).unwrap();
+        assert_matrix_eq(&a.expm().unwrap().logm().unwrap(), &a, 1e-11);
+
+        // 负实特征值不存在实主对数
+        let negative = Matrix2D::new(vec![vec![-1.0, 0.0], vec![0.0, 2.0]]).unwrap();
+        assert!(matches!(negative.logm(), Err(MatrixError::NotConverged { .. })));
+    }
+
+    #[test]
+    fn test_sqrtm_squares_back() {
+        let diagonal = Matrix2D::new(vec![vec![4.0, 0.0], vec![0.0, 9.0]]).unwrap();
+        let expected = Matrix2D::new(vec![vec![2.0, 0.0], vec![0.0, 3.0]]).unwrap();
+        assert_matrix_eq(&diagonal.sqrtm().unwrap(), &expected, 1e-13);
+
+        // 旋转矩阵的平方根是半角旋转
+        assert_matrix_eq(&rotation(2.0).sqrtm().unwrap(), &rotation(1.0), 1e-13);
+
+        let a = Matrix2D::new(vec![vec![6.0, 1.0, -2.0], vec![0.5, 5.0, 1.0], vec![1.0, -1.0, 7.0]]).unwrap();
+        let root = a.sqrtm().unwrap();
+        assert_matrix_eq(&(&root * &root), &a, 1e-12);
+    }
+
+    #[test]
+    fn test_powi_matches_repeated_multiplication() {
+        assert_matrix_eq(&rotation(0.3).powi(7).unwrap(), &rotation(2.1), 1e-13);
+        assert_matrix_eq(&rotation(0.3).powi(-5).unwrap(), &rotation(-1.5), 1e-13);
+
+        let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![-1.0, 0.5]]).unwrap();
+        assert_eq!(a.powi(0).unwrap(), Matrix2D::eye(2));
+        assert_eq!(a.powi(1).unwrap(), a);
+        let mut repeated = Matrix2D::eye(2);
+        for _ in 0..6 {
+            repeated = &repeated * &a;
+        }
+        assert_matrix_eq(&a.powi(6).unwrap(), &repeated, 1e-12);
+
+        let singular = Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert!(matches!(singular.powi(-1), Err(MatrixError::Singular { .. })));
+    }
+}
@@ -0,0 +1,77 @@
+#[cfg(test)]
+mod tests {
+    use std::time::Instant;
+
+    use log::info;
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    /// 确定性的伪随机矩阵，元素位于 [-1, 1)。
+    fn pseudo_random(rows: usize, cols: usize, seed: u64) -> Matrix2D {
+        let mut state = seed;
+        Matrix2D::from_fn(rows, cols, |_, _| {
+            state = state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+            (state >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+        })
+    }
+
+    fn max_abs_diff(a: &Matrix2D, b: &Matrix2D) -> f64 {
+        assert_eq!(a.shape(), b.shape());
+        a.as_slice().iter().zip(b.as_slice()).map(|(x, y)| (x - y).abs()).fold(0.0, f64::max)
+    }
+
+    #[test]
+    fn test_blocked_matches_naive_on_ragged_tiles() {
+        // 各维度都不是块边长的整数倍
+        for &(m, k, n) in &[(1, 1, 1), (3, 130, 2), (65, 129, 63), (128, 64, 200)] {
+            let a = pseudo_random(m, k, 1);
+            let b = pseudo_random(k, n, 2);
+            let naive = a.multiply_naive(&b).unwrap();
+            assert!(max_abs_diff(&a.multiply_blocked(&b).unwrap(), &naive) < 1e-12);
+            assert!(max_abs_diff(&a.multiply(&b).unwrap(), &naive) < 1e-12);
+        }
+
+        let empty = Matrix2D::zeros(4, 0).multiply_blocked(&Matrix2D::zeros(0, 3)).unwrap();
+        assert_eq!(empty, Matrix2D::zeros(4, 3));
+        assert_eq!(
+            Matrix2D::zeros(2, 3).multiply_blocked(&Matrix2D::zeros(2, 3)),
+            Err(MatrixError::DimensionMismatch { left: (2, 3), right: (2, 3) })
+        );
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel_matches_blocked() {
+        let a = pseudo_random(301, 170, 3);
+        let b = pseudo_random(170, 257, 4);
+        assert_eq!(a.multiply_parallel(&b).unwrap(), a.multiply_blocked(&b).unwrap());
+        assert_eq!(Matrix2D::zeros(0, 5).multiply_parallel(&Matrix2D::zeros(5, 2)).unwrap().shape(), (0, 2));
+    }
+
+    /// 计时结果以 info 级别输出；未优化的 debug 构建不反映真实性能，只用较小的规模校验结果，
+    /// 比较性能时请使用 `cargo test --release [--features parallel] gemm -- --nocapture`。
+    #[test]
+    fn test_gemm_benchmark_against_naive() {
+        let n = if cfg!(debug_assertions) { 200 } else { 512 };
+        let a = pseudo_random(n, n, 5);
+        let b = pseudo_random(n, n, 6);
+
+        let start = Instant::now();
+        let naive = a.multiply_naive(&b).unwrap();
+        let naive_time = start.elapsed();
+
+        let start = Instant::now();
+        let blocked = a.multiply_blocked(&b).unwrap();
+        let blocked_time = start.elapsed();
+        info!("{}x{} GEMM: naive {:?}, blocked {:?}", n, n, naive_time, blocked_time);
+        assert!(max_abs_diff(&blocked, &naive) < 1e-10);
+
+        #[cfg(feature = "parallel")]
+        {
+            let start = Instant::now();
+            let parallel = a.multiply_parallel(&b).unwrap();
+            info!("{}x{} GEMM: parallel {:?}", n, n, start.elapsed());
+            assert_eq!(parallel, blocked);
+        }
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    /// 检查 A * v = λ * v 在复数意义下成立。
+    fn assert_eigenpair(a: &Matrix2D, re: f64, im: f64, vr: &[f64], vi: &[f64]) {
+        let avr = a.multiply_by_vector(vr).unwrap();
+        let avi = a.multiply_by_vector(vi).unwrap();
+        for i in 0..a.rows() {
+            let expected_re = re * vr[i] - im * vi[i];
+            let expected_im = re * vi[i] + im * vr[i];
+            assert!((avr[(i, 0)] - expected_re).abs() < 1e-9);
+            assert!((avi[(i, 0)] - expected_im).abs() < 1e-9);
+        }
+        assert!(vr.iter().chain(vi).any(|x| x.abs() > 1e-6));
+    }
+
+    #[test]
+    fn test_hessenberg_reduction() {
+        let a = Matrix2D::new(vec![
+            vec![4.0, 1.0, 2.0, 3.0],
+            vec![2.0, -1.0, 0.5, 1.0],
+            vec![1.0, 3.0, 2.0, -2.0],
+            vec![0.5, 1.0, 1.0, 5.0],
+        ]).unwrap();
+        let hess = a.hessenberg().unwrap();
+        let rebuilt = hess.q.multiply(&hess.h).unwrap().multiply(&hess.q.transpose()).unwrap();
+        for i in 0..4 {
+            for j in 0..4 {
+                assert!((rebuilt[(i, j)] - a[(i, j)]).abs() < 1e-12);
+                if i > j + 1 {
+                    assert_eq!(hess.h[(i, j)], 0.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_general_eigen_real_spectrum() {
+        // 上三角矩阵的特征值即对角元
+        let a = Matrix2D::new(vec![
+            vec![2.0, 1.0, 3.0],
+            vec![0.0, -1.0, 4.0],
+            vec![0.0, 0.0, 5.0],
+        ]).unwrap();
+        let eigen = a.general_eigen().unwrap();
+        let mut values = eigen.real.clone();
+        values.sort_by(|x, y| x.partial_cmp(y).unwrap());
+        assert!((values[0] + 1.0).abs() < 1e-12);
+        assert!((values[1] - 2.0).abs() < 1e-12);
+        assert!((values[2] - 5.0).abs() < 1e-12);
+        assert!(eigen.imag.iter().all(|&im| im == 0.0));
+
+        for k in 0..3 {
+            let (vr, vi) = eigen.eigenvector(k);
+            assert_eigenpair(&a, eigen.real[k], eigen.imag[k], &vr, &vi);
+        }
+    }
+
+    #[test]
+    fn test_general_eigen_complex_pairs() {
+        // 三维旋转矩阵：绕 z 轴旋转 θ，特征值为 1 与 e^{±iθ}
+        let theta = 0.7_f64;
+        let a = Matrix2D::new(vec![
+            vec![theta.cos(), -theta.sin(), 0.0],
+            vec![theta.sin(), theta.cos(), 0.0],
+            vec![0.0, 0.0, 1.0],
+        ]).unwrap();
+        let eigen = a.general_eigen().unwrap();
+
+        let complex: Vec<usize> = (0..3).filter(|&k| eigen.imag[k] != 0.0).collect();
+        assert_eq!(complex.len(), 2);
+        for &k in &complex {
+            assert!((eigen.real[k] - theta.cos()).abs() < 1e-12);
+            assert!((eigen.imag[k].abs() - theta.sin()).abs() < 1e-12);
+            let (vr, vi) = eigen.eigenvector(k);
+            assert_eigenpair(&a, eigen.real[k], eigen.imag[k], &vr, &vi);
+        }
+        // 共轭对中虚部为正的在前
+        assert!(eigen.imag[complex[0]] > 0.0);
+        assert_eq!(complex[1], complex[0] + 1);
+    }
+
+    #[test]
+    fn test_general_eigen_non_normal_matrix() {
+        let a = Matrix2D::new(vec![
+            vec![1.0, 2.0, 0.0, -1.0],
+            vec![-3.0, 0.5, 1.0, 2.0],
+            vec![0.0, 1.0, -2.0, 4.0],
+            vec![2.0, -1.0, 3.0, 1.0],
+        ]).unwrap();
+        let eigen = a.general_eigen().unwrap();
+        let values_only = a.general_eigenvalues().unwrap();
+        assert!(values_only.eigenvectors.is_none());
+
+        // 特征值之和等于迹，且与只求特征值的结果一致
+        let trace: f64 = eigen.real.iter().sum();
+        assert!((trace - 0.5).abs() < 1e-10);
+        for k in 0..4 {
+            assert!((eigen.real[k] - values_only.real[k]).abs() < 1e-10);
+            assert!((eigen.imag[k] - values_only.imag[k]).abs() < 1e-10);
+            let (vr, vi) = eigen.eigenvector(k);
+            assert_eigenpair(&a, eigen.real[k], eigen.imag[k], &vr, &vi);
+        }
+    }
+
+    #[test]
+    fn test_general_eigen_rejects_non_square() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        assert_eq!(a.general_eigen(), Err(MatrixError::NotSquare { rows: 1, cols: 3 }));
+    }
+}
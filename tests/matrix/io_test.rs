@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod tests {
+    use std::io::ErrorKind;
+
+    use rs_math::matrix::io::{
+        parse_delimited, parse_matrix_market, read_delimited, read_matrix_market, DelimitedOptions, MatrixMarketData,
+        ParseError,
+    };
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::matrix::sparse::coo::CooMatrix;
+
+    #[test]
+    fn test_matrix_market_variants() {
+        let pattern = "%%MatrixMarket matrix coordinate pattern general\n%comment\n\n2 3 2\n1 3\n2 1\n";
+        let MatrixMarketData::Sparse(csr) = parse_matrix_market(pattern).unwrap() else {
+            panic!("coordinate files should be sparse");
+        };
+        assert_eq!(csr.to_dense().to_nested(), vec![vec![0.0, 0.0, 1.0], vec![1.0, 0.0, 0.0]]);
+
+        let integer = "%%MatrixMarket matrix coordinate integer general\n2 2 3\n1 1 2\n2 2 -7\n1 1 3\n";
+        let data = parse_matrix_market(integer).unwrap();
+        assert_eq!(data.to_dense().to_nested(), vec![vec![5.0, 0.0], vec![0.0, -7.0]]);
+
+        // 对称数组只给出每列主对角线及以下的部分
+        let symmetric = "%%MatrixMarket matrix array real symmetric\n3 3\n1\n2\n3\n4\n5\n6\n";
+        let expected = Matrix2D::new(vec![vec![1.0, 2.0, 3.0], vec![2.0, 4.0, 5.0], vec![3.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(parse_matrix_market(symmetric).unwrap(), MatrixMarketData::Dense(expected));
+
+        let general = "%%MatrixMarket matrix array integer general\n2 3\n1 2\n3 4\n5 6\n";
+        assert_eq!(parse_matrix_market(general).unwrap().to_dense().row(1), &[2.0, 4.0, 6.0]);
+    }
+
+    #[test]
+    fn test_matrix_market_round_trip() {
+        let dense = Matrix2D::from_fn(3, 2, |i, j| (i as f64 - 1.5) * 10f64.powi(j as i32 * 20) / 3.0);
+        let mut buffer = Vec::new();
+        dense.write_matrix_market(&mut buffer).unwrap();
+        assert_eq!(read_matrix_market(buffer.as_slice()).unwrap(), MatrixMarketData::Dense(dense));
+
+        let csr = CooMatrix::from_triplets(4, 3, &[(0, 1, 0.25), (3, 2, -1e-9), (2, 0, 7.0)]).unwrap().to_csr();
+        let mut buffer = Vec::new();
+        csr.write_matrix_market(&mut buffer).unwrap();
+        assert_eq!(read_matrix_market(buffer.as_slice()).unwrap(), MatrixMarketData::Sparse(csr));
+    }
+
+    #[test]
+    fn test_parse_errors_report_position() {
+        let error = |text: &str| {
+            let error = parse_matrix_market(text).unwrap_err();
+            (error.line, error.column)
+        };
+        assert_eq!(error("3 3\n"), (1, 1));
+        assert_eq!(error("%%MatrixMarket matrix coordinate complex general\n"), (1, 34));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 3 1.0\n"), (3, 3));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n2 2 1\n1 1\n"), (3, 4));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real symmetric\n2 2 1\n1 2 1.0\n"), (3, 3));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n2 2 2\n1 1 1.0\n"), (4, 1));
+        assert_eq!(error("%%MatrixMarket matrix array real general\n1 1\n1\n  2\n"), (4, 3));
+
+        // 声明的条目数超过矩阵容量或尺寸溢出时，在尺寸行报错而不是预分配
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n2 2 5\n"), (2, 5));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n2 2 18446744073709551615\n"), (2, 5));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real symmetric\n2 2 9223372036854775808\n"), (2, 5));
+        assert_eq!(error("%%MatrixMarket matrix coordinate real general\n4294967296 4294967296 0\n"), (2, 1));
+        assert_eq!(error("%%MatrixMarket matrix array real general\n4294967296 4294967296\n"), (2, 1));
+        assert_eq!(error("%%MatrixMarket matrix array real symmetric\n2 2\n1 2 3 4\n"), (3, 7));
+
+        let error = parse_matrix_market("%%MatrixMarket matrix array integer general\n1 1\n1.5\n").unwrap_err();
+        assert_eq!(error, ParseError { line: 3, column: 1, message: "invalid integer value `1.5`".to_string() });
+        assert_eq!(error.to_string(), "line 3, column 1: invalid integer value `1.5`");
+
+        // 通过 Read 读取时，解析错误包装在 InvalidData 中
+        let io_error = read_matrix_market("%%MatrixMarket matrix array real general\n1 x\n".as_bytes()).unwrap_err();
+        assert_eq!(io_error.kind(), ErrorKind::InvalidData);
+        let inner = io_error.get_ref().unwrap().downcast_ref::<ParseError>().unwrap();
+        assert_eq!((inner.line, inner.column), (2, 3));
+    }
+
+    #[test]
+    fn test_delimited_text() {
+        let options = DelimitedOptions { has_header: true, ..DelimitedOptions::tsv() };
+        let table = read_delimited("\"a\"\tb\n1\t-2.5\n\n3e2\t4\n".as_bytes(), &options).unwrap();
+        assert_eq!(table.header, Some(vec!["a".to_string(), "b".to_string()]));
+        assert_eq!(table.matrix.to_nested(), vec![vec![1.0, -2.5], vec![300.0, 4.0]]);
+
+        let mut buffer = Vec::new();
+        table.matrix.write_delimited(&mut buffer, &options, Some(&["a", "b"])).unwrap();
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "a\tb\n1\t-2.5\n300\t4\n");
+        assert_eq!(parse_delimited(std::str::from_utf8(&buffer).unwrap(), &options).unwrap(), table);
+        let mismatch = table.matrix.write_delimited(Vec::new(), &options, Some(&["a"])).unwrap_err();
+        assert_eq!(mismatch.kind(), ErrorKind::InvalidInput);
+
+        let csv = DelimitedOptions::csv();
+        let error = |text: &str| {
+            let error = parse_delimited(text, &csv).unwrap_err();
+            (error.line, error.column)
+        };
+        assert_eq!(error("1,2\n3,4,5\n"), (2, 5));
+        assert_eq!(error("1,2,3\n4,5\n"), (2, 4));
+        assert_eq!(error("1, ,3\n"), (1, 3));
+        assert_eq!(error("1,2\n3, 4x\n"), (2, 4));
+        assert_eq!(parse_delimited("", &csv).unwrap().matrix.shape(), (0, 0));
+    }
+}
@@ -0,0 +1,126 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::krylov::{
+        bicgstab, conjugate_gradient, gmres, Ilu0Preconditioner, JacobiPreconditioner, KrylovOptions,
+        KrylovReport, LinearOperator,
+    };
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::matrix::sparse::coo::CooMatrix;
+    use rs_math::matrix::sparse::csr::CsrMatrix;
+
+    /// n×n 网格上的五点差分算子，`convection` 非零时加入一阶迎风项使矩阵非对称。
+    fn grid_operator(n: usize, convection: f64) -> CsrMatrix {
+        let size = n * n;
+        let mut coo = CooMatrix::new(size, size);
+        for i in 0..n {
+            for j in 0..n {
+                let row = i * n + j;
+                coo.push(row, row, 4.0 + convection).unwrap();
+                if i > 0 {
+                    coo.push(row, row - n, -1.0 - convection).unwrap();
+                }
+                if i + 1 < n {
+                    coo.push(row, row + n, -1.0).unwrap();
+                }
+                if j > 0 {
+                    coo.push(row, row - 1, -1.0).unwrap();
+                }
+                if j + 1 < n {
+                    coo.push(row, row + 1, -1.0).unwrap();
+                }
+            }
+        }
+        coo.to_csr()
+    }
+
+    fn assert_solves(a: &dyn LinearOperator, b: &[f64], report: &KrylovReport, tol: f64) {
+        assert!(report.converged, "did not converge: {:?}", report.residual_history.last());
+        assert_eq!(report.residual_history.len(), report.iterations + 1);
+        let residual: f64 = a
+            .apply(&report.solution)
+            .iter()
+            .zip(b)
+            .map(|(ax, b)| (ax - b).powi(2))
+            .sum::<f64>()
+            .sqrt();
+        let b_norm = b.iter().map(|x| x * x).sum::<f64>().sqrt();
+        assert!(residual / b_norm < tol, "relative residual {}", residual / b_norm);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_with_preconditioners() {
+        let a = grid_operator(12, 0.0);
+        let b: Vec<f64> = (0..144).map(|i| (i % 7) as f64 - 3.0).collect();
+        let options = KrylovOptions { tolerance: 1e-10, ..KrylovOptions::default() };
+
+        let plain = conjugate_gradient(&a, &b, &options, None).unwrap();
+        assert_solves(&a, &b, &plain, 1e-9);
+
+        let jacobi = JacobiPreconditioner::from_csr(&a).unwrap();
+        assert_solves(&a, &b, &conjugate_gradient(&a, &b, &options, Some(&jacobi)).unwrap(), 1e-9);
+
+        let ilu = Ilu0Preconditioner::new(&a).unwrap();
+        let preconditioned = conjugate_gradient(&a, &b, &options, Some(&ilu)).unwrap();
+        assert_solves(&a, &b, &preconditioned, 1e-9);
+        assert!(preconditioned.iterations < plain.iterations);
+    }
+
+    #[test]
+    fn test_nonsymmetric_solvers() {
+        let a = grid_operator(10, 1.5);
+        let b: Vec<f64> = (0..100).map(|i| ((i * 13) % 11) as f64 / 11.0).collect();
+        let options = KrylovOptions { restart: 20, ..KrylovOptions::default() };
+        let ilu = Ilu0Preconditioner::new(&a).unwrap();
+
+        assert_solves(&a, &b, &bicgstab(&a, &b, &options, None).unwrap(), 1e-9);
+        assert_solves(&a, &b, &bicgstab(&a, &b, &options, Some(&ilu)).unwrap(), 1e-9);
+        assert_solves(&a, &b, &gmres(&a, &b, &options, None).unwrap(), 1e-9);
+
+        let restarted = gmres(&a, &b, &KrylovOptions { restart: 5, ..options }, Some(&ilu)).unwrap();
+        assert_solves(&a, &b, &restarted, 1e-9);
+
+        let dense = a.to_dense();
+        let jacobi = JacobiPreconditioner::from_matrix(&dense).unwrap();
+        assert_solves(&dense, &b, &gmres(&dense, &b, &options, Some(&jacobi)).unwrap(), 1e-9);
+    }
+
+    #[test]
+    fn test_iteration_limit_and_errors() {
+        let a = grid_operator(8, 0.0);
+        let b = vec![1.0; 64];
+        let options = KrylovOptions { max_iterations: 3, ..KrylovOptions::default() };
+        let report = conjugate_gradient(&a, &b, &options, None).unwrap();
+        assert!(!report.converged);
+        assert_eq!(report.iterations, 3);
+        assert_eq!(report.residual_history.len(), 4);
+
+        let zero = gmres(&a, &[0.0; 64], &options, None).unwrap();
+        assert!(zero.converged && zero.iterations == 0 && zero.solution.iter().all(|&x| x == 0.0));
+
+        assert_eq!(
+            bicgstab(&a, &[1.0; 3], &options, None),
+            Err(MatrixError::DimensionMismatch { left: (64, 64), right: (3, 1) })
+        );
+        assert_eq!(
+            gmres(&Matrix2D::zeros(2, 3), &[1.0, 1.0], &options, None),
+            Err(MatrixError::NotSquare { rows: 2, cols: 3 })
+        );
+        let missing_diagonal = CsrMatrix::from(&Matrix2D::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap());
+        assert_eq!(Ilu0Preconditioner::new(&missing_diagonal), Err(MatrixError::Singular { pivot: 0 }));
+    }
+
+    #[test]
+    fn test_krylov_breakdown() {
+        let options = KrylovOptions::default();
+
+        // 旋转矩阵使 (r̂, v) = 0，BiCGSTAB 应停止而不是产生 NaN
+        let rotation = Matrix2D::new(vec![vec![0.0, 1.0], vec![-1.0, 0.0]]).unwrap();
+        let report = bicgstab(&rotation, &[1.0, 0.0], &options, None).unwrap();
+        assert!(!report.converged);
+        assert!(report.solution.iter().all(|x| x.is_finite()));
+
+        let singular = Matrix2D::new(vec![vec![1.0, 0.0], vec![0.0, 0.0]]).unwrap();
+        assert_eq!(gmres(&singular, &[0.0, 1.0], &options, None), Err(MatrixError::Singular { pivot: 0 }));
+    }
+}
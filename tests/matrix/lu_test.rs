@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    #[test]
+    fn test_lu_reconstructs_permuted_matrix() {
+        // 第一列首元素为零，必须换行才能分解
+        let a = Matrix2D::new(vec![
+            vec![0.0, 2.0, 1.0],
+            vec![1.0, 1.0, 0.0],
+            vec![3.0, 0.0, 1.0],
+        ]).unwrap();
+        let lu = a.lu().unwrap();
+
+        assert_matrix_eq(&lu.l().multiply(&lu.u()).unwrap(), &lu.p().multiply(&a).unwrap(), 1e-12);
+        assert_eq!(lu.permutation()[0], 2);
+    }
+
+    #[test]
+    fn test_lu_determinant_and_inverse() {
+        let a = Matrix2D::new(vec![
+            vec![2.0, -1.0, 0.0],
+            vec![-1.0, 2.0, -1.0],
+            vec![0.0, -1.0, 2.0],
+        ]).unwrap();
+        let lu = a.lu().unwrap();
+        assert!((lu.determinant() - 4.0).abs() < 1e-12);
+        assert!((a.determinant().unwrap() - 4.0).abs() < 1e-12);
+
+        let inv = lu.inverse().unwrap();
+        assert_matrix_eq(&a.multiply(&inv).unwrap(), &Matrix2D::eye(3), 1e-12);
+        assert_matrix_eq(&a.inverse().unwrap(), &inv, 1e-12);
+    }
+
+    #[test]
+    fn test_lu_solve_reuses_factorization() {
+        let a = Matrix2D::new(vec![
+            vec![4.0, -2.0, 1.0],
+            vec![-2.0, 4.0, -2.0],
+            vec![1.0, -2.0, 4.0],
+        ]).unwrap();
+        let lu = a.lu().unwrap();
+
+        for b in [vec![11.0, -16.0, 17.0], vec![1.0, 0.0, 0.0], vec![3.0, 0.0, 3.0]] {
+            let x = lu.solve(&b).unwrap();
+            let ax = a.multiply_by_vector(&x).unwrap();
+            for (row, expected) in ax.as_slice().iter().zip(&b) {
+                assert!((row - expected).abs() < 1e-12);
+            }
+        }
+
+        let x = lu.solve(&[11.0, -16.0, 17.0]).unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-12);
+        assert!((x[1] + 2.0).abs() < 1e-12);
+        assert!((x[2] - 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_reports_singular_matrix() {
+        let a = Matrix2D::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ]).unwrap();
+        let lu = a.lu().unwrap();
+
+        assert!(lu.is_singular());
+        assert_eq!(lu.solve(&[1.0, 2.0, 3.0]), Err(MatrixError::Singular { pivot: 2 }));
+        assert!(lu.inverse().is_err());
+        assert_eq!(a.inverse(), Err(MatrixError::Singular { pivot: 2 }));
+        assert!(a.determinant().unwrap().abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_lu_rejects_non_square_matrix() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        assert_eq!(a.lu(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+        assert_eq!(a.determinant(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+    }
+}
@@ -1,6 +1,7 @@
 // 单元测试模块
 #[cfg(test)]
 mod tests {
+    use rs_math::matrix::error::MatrixError;
     use rs_math::matrix::matrix_2d::Matrix2D;
 
     #[test]
@@ -11,13 +12,162 @@ mod tests {
             vec![4.0, 5.0, 6.0],
         ];
 
-        let matrix = Matrix2D::new(matrix_data.clone());
+        let matrix = Matrix2D::new(matrix_data.clone()).unwrap();
 
         // 验证矩阵的行数和列数是否正确
-        assert_eq!(matrix.rows, 2);
-        assert_eq!(matrix.cols, 3);
+        assert_eq!(matrix.rows(), 2);
+        assert_eq!(matrix.cols(), 3);
 
         // 验证矩阵的数据是否正确
-        assert_eq!(matrix.data, matrix_data);
+        assert_eq!(matrix.to_nested(), matrix_data);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_checked_constructors() {
+        let matrix = Matrix2D::from_vec(2, 3, vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0]).unwrap();
+        assert_eq!(matrix.shape(), (2, 3));
+        assert_eq!(matrix[(1, 0)], 4.0);
+        assert_eq!(matrix.as_slice(), &[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]);
+
+        assert_eq!(
+            Matrix2D::from_vec(2, 2, vec![1.0, 2.0, 3.0]),
+            Err(MatrixError::DataLength { expected: 4, found: 3 })
+        );
+        assert_eq!(
+            Matrix2D::from_rows(vec![vec![1.0, 2.0], vec![3.0]]),
+            Err(MatrixError::RaggedRows { row: 1, expected: 2, found: 1 })
+        );
+
+        let from_fn = Matrix2D::from_fn(2, 3, |i, j| (i * 3 + j + 1) as f64);
+        assert_eq!(from_fn, matrix);
+    }
+
+    #[test]
+    fn test_index_and_conversions() {
+        let mut matrix = Matrix2D::try_from(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        matrix[(0, 1)] = 5.0;
+        assert_eq!(matrix.column(1), vec![5.0, 4.0]);
+
+        let nested: Vec<Vec<f64>> = matrix.clone().into();
+        assert_eq!(nested, vec![vec![1.0, 5.0], vec![3.0, 4.0]]);
+        assert_eq!(matrix.into_vec(), vec![1.0, 5.0, 3.0, 4.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix index out of bounds.")]
+    fn test_index_out_of_bounds() {
+        let matrix = Matrix2D::zeros(2, 2);
+        let _ = matrix[(0, 2)];
+    }
+
+    #[test]
+    fn test_fallible_operations_report_errors() {
+        let square = Matrix2D::eye(2);
+        let wide = Matrix2D::zeros(2, 3);
+
+        assert_eq!(
+            square.add(&wide),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (2, 3) })
+        );
+        assert!(square.subtract(&wide).is_err());
+        assert_eq!(square.multiply(&wide).unwrap().shape(), (2, 3));
+        assert_eq!(
+            wide.multiply(&square),
+            Err(MatrixError::DimensionMismatch { left: (2, 3), right: (2, 2) })
+        );
+        assert_eq!(
+            wide.multiply_by_vector(&[1.0, 2.0]),
+            Err(MatrixError::DimensionMismatch { left: (2, 3), right: (2, 1) })
+        );
+        assert_eq!(wide.eigen(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+        assert_eq!(
+            Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 6.0]]).unwrap().inverse(),
+            Err(MatrixError::Singular { pivot: 1 })
+        );
+    }
+
+    #[test]
+    fn test_norms_and_trace() {
+        let a = Matrix2D::new(vec![vec![1.0, -2.0, 3.0], vec![-4.0, 5.0, -6.0]]).unwrap();
+        assert_eq!(a.norm_1(), 9.0);
+        assert_eq!(a.norm_inf(), 15.0);
+        assert!((a.norm_frobenius() - 91.0_f64.sqrt()).abs() < 1e-12);
+        // ‖A‖₂² 是 AAᵀ 的最大特征值
+        let gram = &a * &a.transpose();
+        let (eigenvalues, _) = gram.eigen().unwrap();
+        assert!((a.norm_2().unwrap() - eigenvalues[1].sqrt()).abs() < 1e-10);
+
+        assert_eq!(a.trace(), Err(MatrixError::NotSquare { rows: 2, cols: 3 }));
+        assert_eq!(gram.trace().unwrap(), 91.0);
+    }
+
+    #[test]
+    fn test_kronecker_and_hadamard() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix2D::new(vec![vec![0.0, 5.0], vec![6.0, 7.0]]).unwrap();
+        let expected = vec![
+            vec![0.0, 5.0, 0.0, 10.0],
+            vec![6.0, 7.0, 12.0, 14.0],
+            vec![0.0, 15.0, 0.0, 20.0],
+            vec![18.0, 21.0, 24.0, 28.0],
+        ];
+        assert_eq!(a.kronecker(&b).to_nested(), expected);
+        assert_eq!(a.kronecker(&Matrix2D::eye(1)), a);
+
+        assert_eq!(a.hadamard(&b).unwrap().to_nested(), vec![vec![0.0, 10.0], vec![18.0, 28.0]]);
+        let quotient = b.hadamard_divide(&a).unwrap();
+        assert_eq!(quotient.to_nested(), vec![vec![0.0, 2.5], vec![2.0, 1.75]]);
+        assert_eq!(
+            a.hadamard(&Matrix2D::zeros(2, 3)),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (2, 3) })
+        );
+    }
+
+    #[test]
+    fn test_structural_predicates() {
+        let upper = Matrix2D::new(vec![vec![1.0, 2.0], vec![0.0, 3.0]]).unwrap();
+        assert!(upper.is_upper_triangular() && !upper.is_lower_triangular() && !upper.is_diagonal());
+        assert!(upper.transpose().is_lower_triangular());
+        assert!(Matrix2D::eye(3).is_diagonal() && Matrix2D::eye(3).is_orthogonal(0.0));
+        assert!(!Matrix2D::zeros(2, 3).is_diagonal());
+
+        let symmetric = Matrix2D::new(vec![vec![4.0, 1.0 + 1e-10], vec![1.0, 3.0]]).unwrap();
+        assert!(symmetric.is_symmetric(1e-8) && !symmetric.is_symmetric(1e-12));
+        assert!(!Matrix2D::zeros(2, 3).is_symmetric(1.0));
+
+        // 对称但不定，以及非对称但下三角部分可做 Cholesky 分解
+        assert!(Matrix2D::new(vec![vec![4.0, 1.0], vec![1.0, 3.0]]).unwrap().is_positive_definite());
+        assert!(!Matrix2D::new(vec![vec![1.0, 3.0], vec![3.0, 1.0]]).unwrap().is_positive_definite());
+        assert!(!Matrix2D::new(vec![vec![4.0, 9.0], vec![1.0, 3.0]]).unwrap().is_positive_definite());
+
+        let reflection = Matrix2D::new(vec![vec![0.6, 0.8], vec![0.8, -0.6]]).unwrap();
+        assert!(reflection.is_orthogonal(1e-12) && !upper.is_orthogonal(1e-12));
+    }
+
+    #[test]
+    fn test_block_concatenation() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix2D::new(vec![vec![5.0], vec![6.0]]).unwrap();
+        let c = Matrix2D::new(vec![vec![7.0, 8.0]]).unwrap();
+
+        let h = Matrix2D::hstack(&[a.clone(), b.clone()]).unwrap();
+        assert_eq!(h.to_nested(), vec![vec![1.0, 2.0, 5.0], vec![3.0, 4.0, 6.0]]);
+        let v = Matrix2D::vstack(&[a.clone(), c.clone()]).unwrap();
+        assert_eq!(v.to_nested(), vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![7.0, 8.0]]);
+        assert_eq!(
+            Matrix2D::hstack(&[a.clone(), c.clone()]),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (1, 2) })
+        );
+        assert_eq!(
+            Matrix2D::vstack(&[a.clone(), b.clone()]),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (2, 1) })
+        );
+        assert_eq!(Matrix2D::vstack(&[]).unwrap().shape(), (0, 0));
+
+        let d = Matrix2D::block_diag(&[a, c]);
+        assert_eq!(d.shape(), (3, 4));
+        assert_eq!(d.to_nested()[2], vec![0.0, 0.0, 7.0, 8.0]);
+        assert_eq!(d[(1, 1)], 4.0);
+        assert_eq!(d[(0, 2)], 0.0);
+    }
+}
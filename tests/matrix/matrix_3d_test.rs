@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::matrix::matrix_3d::Matrix3D;
+
+    fn sample() -> Matrix3D {
+        Matrix3D::from_fn([2, 3, 4], |i, j, k| (100 * i + 10 * j + k) as f64)
+    }
+
+    #[test]
+    fn test_construction_and_slicing() {
+        let t = sample();
+        assert_eq!(t.shape(), [2, 3, 4]);
+        assert_eq!(t[(1, 2, 3)], 123.0);
+        assert_eq!(t.slice(0, 1).shape(), (3, 4));
+        assert_eq!(t.slice(0, 1)[(2, 3)], 123.0);
+        assert_eq!(t.slice(1, 0).column(0), vec![0.0, 100.0]);
+        assert_eq!(t.slice(2, 1).to_nested(), vec![vec![1.0, 11.0, 21.0], vec![101.0, 111.0, 121.0]]);
+
+        assert_eq!(
+            Matrix3D::from_vec([2, 2, 2], vec![0.0; 7]),
+            Err(MatrixError::DataLength { expected: 8, found: 7 })
+        );
+        assert_eq!(
+            Matrix3D::stack(&[Matrix2D::eye(2), Matrix2D::eye(3)]),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (3, 3) })
+        );
+    }
+
+    #[test]
+    fn test_broadcasting_arithmetic() {
+        let t = sample();
+        let row_offsets = Matrix3D::from_fn([1, 3, 1], |_, j, _| -10.0 * j as f64);
+        let shifted = t.add(&row_offsets).unwrap();
+        assert_eq!(shifted.shape(), [2, 3, 4]);
+        assert_eq!(shifted[(1, 2, 3)], 103.0);
+
+        let halves = t.mul_elementwise(&Matrix3D::from_vec([1, 1, 1], vec![0.5]).unwrap()).unwrap();
+        assert_eq!(halves, t.map(|x| x / 2.0));
+        assert_eq!(t.subtract(&t).unwrap().sum(), 0.0);
+        assert_eq!(t.div_elementwise(&t.map(|x| x + 1.0)).unwrap()[(0, 0, 0)], 0.0);
+
+        assert_eq!(
+            t.add(&Matrix3D::zeros([2, 2, 4])),
+            Err(MatrixError::BroadcastMismatch { left: [2, 3, 4], right: [2, 2, 4] })
+        );
+    }
+
+    #[test]
+    fn test_axis_reductions() {
+        let t = sample();
+        assert_eq!(t.sum_axis(0)[(0, 0)], 100.0);
+        assert_eq!(t.mean_axis(1).to_nested()[1], vec![110.0, 111.0, 112.0, 113.0]);
+        assert_eq!(t.max_axis(2).to_nested(), vec![vec![3.0, 13.0, 23.0], vec![103.0, 113.0, 123.0]]);
+        assert_eq!(t.sum(), t.sum_axis(2).as_slice().iter().sum::<f64>());
+    }
+
+    #[test]
+    fn test_batch_multiply() {
+        let a = Matrix3D::stack(&[
+            Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap(),
+            Matrix2D::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap(),
+        ])
+        .unwrap();
+        let b = Matrix3D::from(Matrix2D::new(vec![vec![1.0, 0.0, 2.0], vec![0.0, 1.0, 3.0]]).unwrap());
+
+        let product = a.batch_multiply(&b).unwrap();
+        assert_eq!(product.shape(), [2, 2, 3]);
+        for i in 0..2 {
+            assert_eq!(product.matrix(i), a.matrix(i).multiply(&b.matrix(0)).unwrap());
+        }
+
+        assert_eq!(
+            b.batch_multiply(&a),
+            Err(MatrixError::DimensionMismatch { left: (2, 3), right: (2, 2) })
+        );
+        assert_eq!(
+            a.batch_multiply(&Matrix3D::zeros([3, 2, 2])),
+            Err(MatrixError::BroadcastMismatch { left: [2, 2, 2], right: [3, 2, 2] })
+        );
+    }
+}
@@ -1 +1,37 @@
-pub mod matrix_2d_test;
\ No newline at end of file
+pub mod matrix_2d_test;
+pub mod lu_test;
+pub mod qr_test;
+pub mod eigen_test;
+pub mod general_eigen_test;
+pub mod svd_test;
+pub mod svd_analysis_test;
+pub mod cholesky_test;
+pub mod ops_test;
+pub mod smatrix_test;
+pub mod matrix_3d_test;
+pub mod sparse_test;
+pub mod krylov_test;
+pub mod banded_test;
+pub mod functions_test;
+pub mod view_test;
+pub mod elimination_test;
+pub mod gemm_test;
+pub mod io_test;
+pub mod format_test;
+pub mod complex_test;
+
+use rs_math::matrix::matrix_2d::Matrix2D;
+
+/// 断言两个矩阵形状相同，且对应元素之差的绝对值都小于 `tol`。
+pub fn assert_matrix_eq(actual: &Matrix2D, expected: &Matrix2D, tol: f64) {
+    assert_eq!(actual.shape(), expected.shape());
+    for i in 0..actual.rows() {
+        for j in 0..actual.cols() {
+            assert!(
+                (actual[(i, j)] - expected[(i, j)]).abs() < tol,
+                "mismatch at ({}, {}): {} != {}",
+                i, j, actual[(i, j)], expected[(i, j)]
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    fn a() -> Matrix2D {
+        Matrix2D::new(vec![vec![1.0, 2.0], vec![3.0, 4.0]]).unwrap()
+    }
+
+    fn b() -> Matrix2D {
+        Matrix2D::new(vec![vec![0.0, 1.0], vec![1.0, 0.0]]).unwrap()
+    }
+
+    #[test]
+    fn test_binary_operators() {
+        assert_eq!(&a() + &b(), Matrix2D::new(vec![vec![1.0, 3.0], vec![4.0, 4.0]]).unwrap());
+        assert_eq!(a() - b(), Matrix2D::new(vec![vec![1.0, 1.0], vec![2.0, 4.0]]).unwrap());
+        assert_eq!(&a() * b(), Matrix2D::new(vec![vec![2.0, 1.0], vec![4.0, 3.0]]).unwrap());
+        assert_eq!(a() * &b(), a().multiply(&b()).unwrap());
+        assert_eq!(-a(), Matrix2D::new(vec![vec![-1.0, -2.0], vec![-3.0, -4.0]]).unwrap());
+        assert_eq!(2.0 * &a(), &a() * 2.0);
+        assert_eq!(&a() * &[1.0, -1.0][..], vec![-1.0, -1.0]);
+        assert_eq!(a() * vec![1.0, 0.0], vec![1.0, 3.0]);
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let mut m = a();
+        m += &b();
+        m -= b();
+        assert_eq!(m, a());
+        m *= b();
+        assert_eq!(m, a().multiply(&b()).unwrap());
+        m *= 0.5;
+        assert_eq!(m, Matrix2D::new(vec![vec![1.0, 0.5], vec![2.0, 1.5]]).unwrap());
+    }
+
+    #[test]
+    fn test_checked_operations() {
+        let row = Matrix2D::new(vec![vec![1.0, 2.0, 3.0]]).unwrap();
+        assert_eq!(
+            a().try_add(&row),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (1, 3) })
+        );
+        assert!(a().try_sub(&row).is_err());
+        assert_eq!(
+            a().try_mul(&row),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (1, 3) })
+        );
+        assert_eq!(
+            a().try_mul_vector(&[1.0]),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (1, 1) })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "dimension mismatch")]
+    fn test_operator_panics_on_mismatch() {
+        let _ = a() + Matrix2D::zeros(3, 3);
+    }
+}
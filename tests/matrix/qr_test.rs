@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    #[test]
+    fn test_qr_reconstructs_tall_matrix() {
+        let a = Matrix2D::new(vec![
+            vec![12.0, -51.0, 4.0],
+            vec![6.0, 167.0, -68.0],
+            vec![-4.0, 24.0, -41.0],
+            vec![1.0, 1.0, 1.0],
+        ]).unwrap();
+        let qr = a.qr();
+        let q = qr.q();
+        let r = qr.r();
+
+        assert_matrix_eq(&q.multiply(&r).unwrap(), &a, 1e-10);
+        assert_matrix_eq(&q.transpose().multiply(&q).unwrap(), &Matrix2D::eye(4), 1e-12);
+        for i in 0..r.rows() {
+            for j in 0..i.min(r.cols()) {
+                assert_eq!(r[(i, j)], 0.0);
+            }
+        }
+
+        let thin = qr.q_thin().multiply(&qr.r_thin()).unwrap();
+        assert_matrix_eq(&thin, &a, 1e-10);
+    }
+
+    #[test]
+    fn test_qr_wide_matrix() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]).unwrap();
+        let qr = a.qr();
+        assert_matrix_eq(&qr.q().multiply(&qr.r()).unwrap(), &a, 1e-12);
+        assert_eq!(a.least_squares(&[1.0, 2.0]), Err(MatrixError::Singular { pivot: 2 }));
+    }
+
+    #[test]
+    fn test_least_squares_fits_noisy_line() {
+        // y = 2x + 1 加上对称扰动，最小二乘解应恰好还原系数
+        let xs = [0.0, 1.0, 2.0, 3.0];
+        let ys = [1.1, 2.9, 5.1, 6.9];
+        let a = Matrix2D::new(xs.iter().map(|&x| vec![1.0, x]).collect()).unwrap();
+        let coefficients = a.least_squares(&ys).unwrap();
+
+        assert!((coefficients[0] - 1.06).abs() < 1e-12);
+        assert!((coefficients[1] - 1.96).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_least_squares_rank_deficient() {
+        let a = Matrix2D::new(vec![vec![1.0, 2.0], vec![2.0, 4.0], vec![3.0, 6.0]]).unwrap();
+        assert_eq!(a.least_squares(&[1.0, 2.0, 3.0]), Err(MatrixError::Singular { pivot: 1 }));
+    }
+}
@@ -0,0 +1,62 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::matrix::smatrix::{SMatrix, SMatrix2, SMatrix3, SMatrix4};
+
+    fn assert_identity<const N: usize>(m: &SMatrix<N, N>, tol: f64) {
+        for i in 0..N {
+            for j in 0..N {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((m[(i, j)] - expected).abs() < tol, "mismatch at ({}, {})", i, j);
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiplication_shapes() {
+        let a: SMatrix<2, 3> = SMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let b: SMatrix<3, 1> = SMatrix::new([[1.0], [0.0], [-1.0]]);
+        let product: SMatrix<2, 1> = a * b;
+        assert_eq!(product, SMatrix::new([[-2.0], [-2.0]]));
+        assert_eq!(a * [1.0, 0.0, -1.0], [-2.0, -2.0]);
+        assert_eq!(a.transpose().shape(), (3, 2));
+        assert_eq!((a + a - a) * 2.0, -(a * -2.0));
+    }
+
+    #[test]
+    fn test_closed_form_determinant_and_inverse() {
+        let m2 = SMatrix2::new([[4.0, 7.0], [2.0, 6.0]]);
+        assert!((m2.determinant() - 10.0).abs() < 1e-12);
+        assert_identity(&(m2 * m2.inverse().unwrap()), 1e-12);
+
+        let m3 = SMatrix3::new([[2.0, -1.0, 0.0], [-1.0, 2.0, -1.0], [0.0, -1.0, 2.0]]);
+        assert!((m3.determinant() - 4.0).abs() < 1e-12);
+        assert_identity(&(m3 * m3.inverse().unwrap()), 1e-12);
+
+        let m4 = SMatrix4::new([
+            [4.0, 3.0, 2.0, 1.0],
+            [0.0, 1.0, -1.0, 2.0],
+            [1.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, 0.0, 5.0],
+        ]);
+        let dynamic = Matrix2D::from(m4);
+        assert!((m4.determinant() - dynamic.determinant().unwrap()).abs() < 1e-10);
+        assert_identity(&(m4.inverse().unwrap() * m4), 1e-12);
+
+        let singular = SMatrix3::new([[1.0, 2.0, 3.0], [2.0, 4.0, 6.0], [0.0, 1.0, 1.0]]);
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular { pivot: 0 }));
+    }
+
+    #[test]
+    fn test_matrix2d_conversion() {
+        let m = SMatrix::new([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        let dynamic = Matrix2D::from(m);
+        assert_eq!(dynamic.to_nested(), vec![vec![1.0, 2.0, 3.0], vec![4.0, 5.0, 6.0]]);
+        assert_eq!(SMatrix::<2, 3>::try_from(&dynamic), Ok(m));
+        assert_eq!(
+            SMatrix::<3, 2>::try_from(dynamic),
+            Err(MatrixError::DimensionMismatch { left: (2, 3), right: (3, 2) })
+        );
+    }
+}
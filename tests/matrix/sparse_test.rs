@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::matrix::sparse::coo::CooMatrix;
+    use rs_math::matrix::sparse::csc::CscMatrix;
+    use rs_math::matrix::sparse::csr::CsrMatrix;
+
+    fn assembled() -> CooMatrix {
+        // 故意打乱顺序并包含重复坐标
+        CooMatrix::from_triplets(
+            3,
+            4,
+            &[(2, 3, 1.0), (0, 1, 2.0), (1, 0, 3.0), (0, 1, 0.5), (2, 0, -1.0), (0, 3, 4.0), (2, 3, 1.0)],
+        )
+        .unwrap()
+    }
+
+    fn dense() -> Matrix2D {
+        Matrix2D::new(vec![
+            vec![0.0, 2.5, 0.0, 4.0],
+            vec![3.0, 0.0, 0.0, 0.0],
+            vec![-1.0, 0.0, 0.0, 2.0],
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_coo_assembly_sums_duplicates() {
+        let coo = assembled();
+        assert_eq!(coo.nnz(), 7);
+        assert_eq!(coo.to_dense(), dense());
+
+        let csr = coo.to_csr();
+        assert_eq!(csr.nnz(), 5);
+        assert_eq!(csr.indptr(), &[0, 2, 3, 5]);
+        assert_eq!(csr.indices(), &[1, 3, 0, 0, 3]);
+        assert_eq!(csr.get(0, 1), 2.5);
+        assert_eq!(csr.get(1, 1), 0.0);
+
+        let csc = coo.to_csc();
+        assert_eq!(csc.indptr(), &[0, 2, 3, 3, 5]);
+        assert_eq!(csc.get(2, 3), 2.0);
+
+        assert_eq!(
+            CooMatrix::new(2, 2).push(2, 0, 1.0),
+            Err(MatrixError::IndexOutOfBounds { index: (2, 0), shape: (2, 2) })
+        );
+    }
+
+    #[test]
+    fn test_format_conversions_round_trip() {
+        let a = dense();
+        let csr = CsrMatrix::from(&a);
+        let csc = CscMatrix::from(&a);
+        assert_eq!(csr.to_dense(), a);
+        assert_eq!(csc.to_dense(), a);
+        assert_eq!(csr.to_csc(), csc);
+        assert_eq!(csc.to_csr(), csr);
+        assert_eq!(Matrix2D::from(&csr.to_coo()), a);
+        assert_eq!(csr.transpose().to_dense(), a.transpose());
+        assert_eq!(csc.transpose().to_dense(), a.transpose());
+        assert_eq!(assembled().transpose().to_csr().to_dense(), a.transpose());
+    }
+
+    #[test]
+    fn test_sparse_products() {
+        let a = dense();
+        let x = [1.0, -1.0, 2.0, 0.5];
+        let expected = a.try_mul_vector(&x).unwrap();
+        assert_eq!(CsrMatrix::from(&a).mul_vector(&x).unwrap(), expected);
+        assert_eq!(CscMatrix::from(&a).mul_vector(&x).unwrap(), expected);
+
+        let b = Matrix2D::from_fn(4, 2, |i, j| (i + 2 * j) as f64);
+        let expected = a.multiply(&b).unwrap();
+        assert_eq!(CsrMatrix::from(&a).mul_dense(&b).unwrap(), expected);
+        assert_eq!(CscMatrix::from(&a).mul_dense(&b).unwrap(), expected);
+
+        assert_eq!(
+            CsrMatrix::from(&a).mul_vector(&[1.0]),
+            Err(MatrixError::DimensionMismatch { left: (3, 4), right: (1, 1) })
+        );
+        assert!(CscMatrix::from(&a).mul_dense(&Matrix2D::eye(3)).is_err());
+    }
+}
@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    fn rank_two_matrix() -> Matrix2D {
+        // 第三列等于前两列之和
+        Matrix2D::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 9.0],
+            vec![7.0, 8.0, 15.0],
+            vec![1.0, 0.0, 1.0],
+        ]).unwrap()
+    }
+
+    #[test]
+    fn test_pinv_satisfies_penrose_conditions() {
+        let a = rank_two_matrix();
+        let p = a.pinv(None).unwrap();
+        assert_eq!((p.rows(), p.cols()), (3, 4));
+
+        assert_matrix_eq(&a.multiply(&p).unwrap().multiply(&a).unwrap(), &a, 1e-10);
+        assert_matrix_eq(&p.multiply(&a).unwrap().multiply(&p).unwrap(), &p, 1e-10);
+        let ap = a.multiply(&p).unwrap();
+        assert_matrix_eq(&ap, &ap.transpose(), 1e-10);
+        let pa = p.multiply(&a).unwrap();
+        assert_matrix_eq(&pa, &pa.transpose(), 1e-10);
+    }
+
+    #[test]
+    fn test_pinv_of_invertible_matrix_is_inverse() {
+        let a = Matrix2D::new(vec![vec![4.0, 7.0], vec![2.0, 6.0]]).unwrap();
+        assert_matrix_eq(&a.pinv(None).unwrap(), &a.inverse().unwrap(), 1e-12);
+    }
+
+    #[test]
+    fn test_rank_and_spaces() {
+        let a = rank_two_matrix();
+        assert_eq!(a.rank(None).unwrap(), 2);
+        assert_eq!(a.rank(Some(1e3)).unwrap(), 0);
+
+        let null = a.null_space().unwrap();
+        assert_eq!((null.rows(), null.cols()), (3, 1));
+        let product = a.multiply(&null).unwrap();
+        assert!(product.as_slice().iter().all(|x| x.abs() < 1e-10));
+
+        let range = a.column_space().unwrap();
+        assert_eq!((range.rows(), range.cols()), (4, 2));
+        // 列空间的投影保持 A 的每一列不变
+        let projector = range.multiply(&range.transpose()).unwrap();
+        assert_matrix_eq(&projector.multiply(&a).unwrap(), &a, 1e-10);
+
+        let full_rank = Matrix2D::eye(3);
+        assert_eq!(full_rank.null_space().unwrap().cols(), 0);
+    }
+
+    #[test]
+    fn test_condition_number() {
+        let a = Matrix2D::new(vec![vec![1.0, 0.0], vec![0.0, 1e-3]]).unwrap();
+        assert!((a.cond().unwrap() - 1e3).abs() < 1e-9);
+
+        let singular = Matrix2D::new(vec![vec![1.0, 1.0], vec![1.0, 1.0]]).unwrap();
+        assert!(singular.cond().unwrap() > 1e15);
+    }
+}
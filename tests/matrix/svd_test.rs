@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    use crate::matrix::assert_matrix_eq;
+
+    fn assert_orthonormal_columns(m: &Matrix2D) {
+        assert_matrix_eq(&m.transpose().multiply(m).unwrap(), &Matrix2D::eye(m.cols()), 1e-12);
+    }
+
+    fn sample_matrix(rows: usize, cols: usize) -> Matrix2D {
+        Matrix2D::new(
+            (0..rows)
+                .map(|i| (0..cols).map(|j| ((i * 7 + j * 3) % 11) as f64 - 5.0 + 0.1 * j as f64).collect())
+                .collect(),
+        ).unwrap()
+    }
+
+    #[test]
+    fn test_svd_thin_tall_matrix() {
+        let a = sample_matrix(5, 3);
+        let svd = a.svd().unwrap();
+
+        assert_eq!((svd.u.rows(), svd.u.cols()), (5, 3));
+        assert_eq!((svd.vt.rows(), svd.vt.cols()), (3, 3));
+        assert!(svd.singular_values.windows(2).all(|w| w[0] >= w[1]));
+        assert_orthonormal_columns(&svd.u);
+        assert_orthonormal_columns(&svd.vt.transpose());
+        assert_matrix_eq(&svd.u.multiply(&svd.sigma()).unwrap().multiply(&svd.vt).unwrap(), &a, 1e-10);
+    }
+
+    #[test]
+    fn test_svd_wide_matrix_full_and_thin() {
+        let a = sample_matrix(3, 5);
+
+        let thin = a.svd().unwrap();
+        assert_eq!((thin.u.rows(), thin.u.cols()), (3, 3));
+        assert_eq!((thin.vt.rows(), thin.vt.cols()), (3, 5));
+        assert_matrix_eq(&thin.u.multiply(&thin.sigma()).unwrap().multiply(&thin.vt).unwrap(), &a, 1e-10);
+
+        let full = a.svd_full().unwrap();
+        assert_eq!((full.vt.rows(), full.vt.cols()), (5, 5));
+        assert_orthonormal_columns(&full.u);
+        assert_orthonormal_columns(&full.vt.transpose());
+        assert_matrix_eq(&full.u.multiply(&full.sigma()).unwrap().multiply(&full.vt).unwrap(), &a, 1e-10);
+    }
+
+    #[test]
+    fn test_svd_rank_deficient_matrix() {
+        // 第三列等于前两列之和，秩为 2
+        let a = Matrix2D::new(vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 9.0],
+            vec![7.0, 8.0, 15.0],
+            vec![1.0, 0.0, 1.0],
+        ]).unwrap();
+        let svd = a.svd_full().unwrap();
+
+        assert!(svd.singular_values[2] < 1e-12);
+        assert_orthonormal_columns(&svd.u);
+        assert_matrix_eq(&svd.u.multiply(&svd.sigma()).unwrap().multiply(&svd.vt).unwrap(), &a, 1e-10);
+    }
+
+    #[test]
+    fn test_svd_known_singular_values() {
+        // [[1, 1], [0, 1]] 的奇异值为黄金比例 φ 与 1/φ
+        let phi = (1.0 + 5.0_f64.sqrt()) / 2.0;
+        let a = Matrix2D::new(vec![vec![1.0, 1.0], vec![0.0, 1.0]]).unwrap();
+        let svd = a.svd().unwrap();
+        assert!((svd.singular_values[0] - phi).abs() < 1e-12);
+        assert!((svd.singular_values[1] - 1.0 / phi).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_svd_zero_matrix() {
+        let a = Matrix2D::zeros(3, 2);
+        let svd = a.svd_full().unwrap();
+        assert_eq!(svd.singular_values, vec![0.0, 0.0]);
+        assert_orthonormal_columns(&svd.u);
+        assert_orthonormal_columns(&svd.vt);
+    }
+}
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+
+    fn sample() -> Matrix2D {
+        Matrix2D::from_fn(4, 5, |i, j| (10 * i + j) as f64)
+    }
+
+    #[test]
+    fn test_view_borrows_sub_block() {
+        let m = sample();
+        let block = m.view(1, 2, 3, 2).unwrap();
+        assert_eq!(block.shape(), (3, 2));
+        assert_eq!(block.to_matrix().to_nested(), vec![vec![12.0, 13.0], vec![22.0, 23.0], vec![32.0, 33.0]]);
+
+        // 视图的子视图仍指向原矩阵
+        let inner = block.view(1, 1, 2, 1).unwrap();
+        assert_eq!(inner.iter().copied().collect::<Vec<_>>(), vec![23.0, 33.0]);
+        assert_eq!(block.view(0, 1, 3, 2).unwrap_err(), MatrixError::IndexOutOfBounds { index: (3, 3), shape: (3, 2) });
+
+        assert_eq!(m.view(0, 4, 4, 2).unwrap_err(), MatrixError::IndexOutOfBounds { index: (4, 6), shape: (4, 5) });
+        assert_eq!(m.view(4, 5, 0, 0).unwrap().to_matrix().shape(), (0, 0));
+    }
+
+    #[test]
+    fn test_zero_column_views() {
+        let mut m = sample();
+        let empty = m.view(1, 3, 3, 0).unwrap();
+        assert_eq!(empty.shape(), (3, 0));
+        assert!(empty.row(2).is_empty());
+        assert_eq!(empty.row_iter().map(|row| row.len()).collect::<Vec<_>>(), vec![0, 0, 0]);
+        assert_eq!(empty.iter().count(), 0);
+        assert_eq!(empty.to_matrix().shape(), (3, 0));
+        assert_eq!(empty.view(1, 0, 2, 0).unwrap().to_matrix().shape(), (2, 0));
+
+        let mut empty_mut = m.view_mut(0, 5, 4, 0).unwrap();
+        assert!(empty_mut.row_mut(3).is_empty());
+        assert_eq!(empty_mut.row_iter_mut().count(), 4);
+        assert_eq!(empty_mut.to_matrix().shape(), (4, 0));
+    }
+
+    #[test]
+    fn test_row_and_column_iteration() {
+        let m = sample();
+        let row_sums: Vec<f64> = m.row_iter().map(|row| row.iter().sum()).collect();
+        assert_eq!(row_sums, vec![10.0, 60.0, 110.0, 160.0]);
+        assert_eq!(m.row(2), &[20.0, 21.0, 22.0, 23.0, 24.0]);
+
+        let columns: Vec<Vec<f64>> = m.column_iter().map(|c| c.iter().copied().collect()).collect();
+        assert_eq!(columns.len(), 5);
+        for (j, column) in columns.iter().enumerate() {
+            assert_eq!(column, &m.column(j));
+        }
+
+        let block = m.view(1, 1, 2, 3).unwrap();
+        assert_eq!(block.column_iter().map(|c| c[(1, 0)]).collect::<Vec<_>>(), vec![21.0, 22.0, 23.0]);
+    }
+
+    #[test]
+    fn test_mutation_through_views() {
+        let mut m = sample();
+        m.row_mut(0).iter_mut().for_each(|x| *x = -1.0);
+        assert_eq!(m.row(0), &[-1.0; 5]);
+
+        {
+            let mut block = m.view_mut(1, 1, 2, 2).unwrap();
+            block[(0, 0)] = 100.0;
+            block.row_mut(1)[1] = 200.0;
+            let mut corner = block.view_mut(0, 1, 1, 1).unwrap();
+            corner.fill(300.0);
+        }
+        assert_eq!(m.row(1), &[10.0, 100.0, 300.0, 13.0, 14.0]);
+        assert_eq!(m.row(2), &[20.0, 21.0, 200.0, 23.0, 24.0]);
+
+        m.set_block(2, 3, &Matrix2D::eye(2)).unwrap();
+        assert_eq!(m.view(2, 3, 2, 2).unwrap().to_matrix(), Matrix2D::eye(2));
+        assert_eq!(m[(2, 2)], 200.0);
+        assert_eq!(
+            m.set_block(3, 4, &Matrix2D::eye(2)),
+            Err(MatrixError::IndexOutOfBounds { index: (5, 6), shape: (4, 5) })
+        );
+
+        let mut view = m.view_mut(0, 0, 2, 2).unwrap();
+        assert_eq!(
+            view.copy_from(&Matrix2D::zeros(3, 2)),
+            Err(MatrixError::DimensionMismatch { left: (2, 2), right: (3, 2) })
+        );
+    }
+
+    #[test]
+    fn test_swap_rows_mutates() {
+        let mut m = sample();
+        m.swap_rows(3, 0);
+        assert_eq!(m.row(0), &[30.0, 31.0, 32.0, 33.0, 34.0]);
+        assert_eq!(m.row(3), &[0.0, 1.0, 2.0, 3.0, 4.0]);
+        m.swap_rows(1, 1);
+        assert_eq!(m.row(1), &[10.0, 11.0, 12.0, 13.0, 14.0]);
+    }
+}
@@ -0,0 +1,141 @@
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use rs_math::number::complex::Complex;
+
+    fn assert_close(actual: Complex, expected: Complex) {
+        assert!((actual - expected).norm() < 1e-12, "{} != {}", actual, expected);
+    }
+
+    #[test]
+    fn test_complex_arithmetic_and_polar_form() {
+        let a = Complex::new(1.0, 2.0);
+        let b = Complex::new(3.0, -4.0);
+        assert_eq!(a + b, Complex::new(4.0, -2.0));
+        assert_eq!(a - b, Complex::new(-2.0, 6.0));
+        assert_eq!(a * b, Complex::new(11.0, 2.0));
+        assert_close(a / b, Complex::new(-0.2, 0.4));
+        assert_close((a / b) * b, a);
+        assert_eq!(2.0 * a, a + a);
+        assert_eq!(a * 2.0, Complex::new(2.0, 4.0));
+        assert_eq!(1.0 - a, -a + 1.0);
+        assert_close(a.recip() * a, Complex::new(1.0, 0.0));
+        assert!((Complex::new(1.0, 1.0) / Complex::new(1e300, 1e300)).is_finite());
+        assert!(!(a / Complex::new(0.0, 0.0)).is_finite());
+
+        let mut c = a;
+        c *= b;
+        c -= 1.0;
+        assert_eq!(c, Complex::new(10.0, 2.0));
+
+        let (r, theta) = Complex::new(-1.0, 1.0).to_polar();
+        assert!((r - 2f64.sqrt()).abs() < 1e-15 && (theta - 3.0 * PI / 4.0).abs() < 1e-15);
+        assert_close(Complex::from_polar(r, theta), Complex::new(-1.0, 1.0));
+        assert_eq!(b.norm(), 5.0);
+        assert_eq!(b.norm_sqr(), 25.0);
+    }
+
+    #[test]
+    fn test_complex_exp_ln_sqrt_pow() {
+        let i = Complex::i();
+        assert_close((i * PI).exp(), Complex::new(-1.0, 0.0));
+        let z = Complex::new(0.3, -1.7);
+        assert_close(z.ln().exp(), z);
+        assert_close(z.exp().ln(), z);
+        assert_close(z.sqrt() * z.sqrt(), z);
+        assert!(z.sqrt().re > 0.0);
+        assert_close(z.powi(3), z * z * z);
+        assert_close(z.powi(-2), (z * z).recip());
+        assert_close(z.powf(0.5), z.sqrt());
+        assert_close(i.powc(i), Complex::new((-FRAC_PI_2).exp(), 0.0));
+        assert_eq!(Complex::new(0.0, 0.0).powc(i), Complex::new(0.0, 0.0));
+        assert_eq!(Complex::new(0.0, 0.0).powf(0.0), Complex::new(1.0, 0.0));
+    }
+
+    #[test]
+    fn test_complex_trig_inverses() {
+        for z in [Complex::new(0.4, 0.3), Complex::new(-0.7, 1.2), Complex::new(1.5, -0.8)] {
+            assert_close(z.asin().sin(), z);
+            assert_close(z.acos().cos(), z);
+            assert_close(z.atan().tan(), z);
+            assert_close(z.asinh().sinh(), z);
+            assert_close(z.acosh().cosh(), z);
+            assert_close(z.atanh().tanh(), z);
+            let s = z.sin();
+            let c = z.cos();
+            assert_close(s * s + c * c, Complex::new(1.0, 0.0));
+        }
+        assert_close(Complex::new(0.5, 0.0).asin(), Complex::new(0.5f64.asin(), 0.0));
+        assert_close(Complex::new(2.0, 0.0).acosh(), Complex::new(2f64.acosh(), 0.0));
+    }
+
+    #[test]
+    fn test_complex_branch_cuts() {
+        let eps = 1e-15;
+        let acosh_2 = 2f64.acosh();
+        // asin、acos、atanh 的切割线位于实轴 |x| > 1，asin 在上方取正虚部
+        assert_close(Complex::new(2.0, eps).asin(), Complex::new(FRAC_PI_2, acosh_2));
+        assert_close(Complex::new(2.0, -eps).asin(), Complex::new(FRAC_PI_2, -acosh_2));
+        assert_close(Complex::new(2.0, eps).acos(), Complex::new(0.0, -acosh_2));
+        assert_close(Complex::new(2.0, -eps).acos(), Complex::new(0.0, acosh_2));
+        assert!((Complex::new(2.0, eps).atanh().im - FRAC_PI_2).abs() < 1e-12);
+        assert!((Complex::new(2.0, -eps).atanh().im + FRAC_PI_2).abs() < 1e-12);
+        // atan、asinh 的切割线位于虚轴 |y| > 1
+        assert!((Complex::new(eps, 2.0).atan().re - FRAC_PI_2).abs() < 1e-12);
+        assert!((Complex::new(-eps, 2.0).atan().re + FRAC_PI_2).abs() < 1e-12);
+        assert_close(Complex::new(eps, 2.0).asinh(), Complex::new(acosh_2, FRAC_PI_2));
+        assert_close(Complex::new(-eps, 2.0).asinh(), Complex::new(-acosh_2, FRAC_PI_2));
+        // ln 与 sqrt 的切割线位于负实轴，由虚部零的符号决定取哪一侧
+        assert_eq!(Complex::new(-4.0, 0.0).sqrt(), Complex::new(0.0, 2.0));
+        assert_eq!(Complex::new(-4.0, -0.0).sqrt(), Complex::new(0.0, -2.0));
+        assert_eq!(Complex::new(-1.0, -0.0).ln().im, -PI);
+        assert!(Complex::new(-2.0, 0.0).acosh().re > 0.0);
+    }
+
+    #[test]
+    fn test_complex_signed_zero_on_branch_cuts() {
+        let (acosh_2, acosh_3) = (2f64.acosh(), 3f64.acosh());
+        // 切割线上的点由零分量的符号决定取哪一侧，与从该侧逼近的极限一致
+        assert_close(Complex::new(2.0, 0.0).asin(), Complex::new(FRAC_PI_2, acosh_2));
+        assert_close(Complex::new(2.0, -0.0).asin(), Complex::new(FRAC_PI_2, -acosh_2));
+        assert_close(Complex::new(-2.0, 0.0).asin(), Complex::new(-FRAC_PI_2, acosh_2));
+        assert_close(Complex::new(-2.0, -0.0).asin(), Complex::new(-FRAC_PI_2, -acosh_2));
+        assert_close(Complex::new(2.0, 0.0).acos(), Complex::new(0.0, -acosh_2));
+        assert_close(Complex::new(2.0, -0.0).acos(), Complex::new(0.0, acosh_2));
+        assert_close(Complex::new(-3.0, 0.0).acosh(), Complex::new(acosh_3, PI));
+        assert_close(Complex::new(-3.0, -0.0).acosh(), Complex::new(acosh_3, -PI));
+        assert_eq!(Complex::new(2.0, 0.0).atanh().im, FRAC_PI_2);
+        assert_eq!(Complex::new(2.0, -0.0).atanh().im, -FRAC_PI_2);
+        assert_eq!(Complex::new(0.0, 2.0).atan().re, FRAC_PI_2);
+        assert_eq!(Complex::new(-0.0, 2.0).atan().re, -FRAC_PI_2);
+        assert_close(Complex::new(0.0, 2.0).asinh(), Complex::new(acosh_2, FRAC_PI_2));
+        assert_close(Complex::new(-0.0, 2.0).asinh(), Complex::new(-acosh_2, FRAC_PI_2));
+
+        // 结果为零的分量同样保留符号
+        assert!(Complex::new(0.5f64, -0.0).asin().im.is_sign_negative());
+        assert!(Complex::new(-0.0f64, 0.5).atanh().re.is_sign_negative());
+        assert!(Complex::new(1.0f64, -0.0).tan().im.is_sign_negative());
+    }
+
+    #[test]
+    fn test_complex_large_arguments() {
+        let ln_2e200 = 2f64.ln() + 200.0 * 10f64.ln();
+        assert_close(Complex::new(1e200, 0.0).asin(), Complex::new(FRAC_PI_2, ln_2e200));
+        assert_close(Complex::new(1e200, 0.0).acosh(), Complex::new(ln_2e200, 0.0));
+        assert_close(Complex::new(0.0, 1e200).asinh(), Complex::new(ln_2e200, FRAC_PI_2));
+        assert_close(Complex::new(1e200, 1.0).atanh(), Complex::new(0.0, FRAC_PI_2));
+        assert_close(Complex::new(1.0, 400.0).tan(), Complex::i());
+        assert_close(Complex::new(-400.0, 1.0).tanh(), Complex::new(-1.0, 0.0));
+        assert!(Complex::new(0.5, 1e300).tanh().is_finite());
+    }
+
+    #[test]
+    fn test_complex_display() {
+        assert_eq!(Complex::new(1.5, -2.0).to_string(), "1.5-2i");
+        assert_eq!(Complex::new(0.0, 1.0).to_string(), "0+1i");
+        assert_eq!(Complex::new(1.0, -0.0).to_string(), "1-0i");
+        assert_eq!(format!("{:.2}", Complex::new(1.0, 1.0 / 3.0)), "1.00+0.33i");
+        assert_eq!(Complex::<f32>::new(0.5, 0.25).to_string(), "0.5+0.25i");
+    }
+}
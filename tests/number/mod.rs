@@ -0,0 +1,3 @@
+pub mod complex_test;
+pub mod rational_test;
+pub mod scalar_test;
@@ -0,0 +1,56 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::number::field::Field;
+    use rs_math::number::rational::Rational;
+
+    #[test]
+    fn test_rational_is_normalized() {
+        let a = Rational::new(6, -8);
+        assert_eq!((a.numer(), a.denom()), (-3, 4));
+        assert_eq!(Rational::new(0, -5), Rational::zero());
+        assert_eq!(Rational::new(0, -5).denom(), 1);
+        assert_eq!(Rational::new(-10, -5), Rational::from_integer(2));
+        assert!(Rational::new(-10, -5).is_integer());
+        assert_eq!(Rational::new(3, 4).to_string(), "3/4");
+        assert_eq!(Rational::new(-8, 4).to_string(), "-2");
+    }
+
+    #[test]
+    fn test_rational_arithmetic_and_ordering() {
+        let a = Rational::new(2, 3);
+        let b = Rational::new(-5, 4);
+        assert_eq!(a + b, Rational::new(-7, 12));
+        assert_eq!(a - b, Rational::new(23, 12));
+        assert_eq!(a * b, Rational::new(-5, 6));
+        assert_eq!(a / b, Rational::new(-8, 15));
+        assert_eq!(-b, Rational::new(5, 4));
+        assert_eq!(b.recip(), Rational::new(-4, 5));
+        assert_eq!(b.abs(), Rational::new(5, 4));
+
+        let mut c = a;
+        c += Rational::one();
+        c *= Rational::from(3);
+        assert_eq!(c, Rational::from_integer(5));
+
+        assert!(b < a && a < Rational::one());
+        let mut values = vec![Rational::new(1, 2), Rational::new(-1, 3), Rational::new(1, 3)];
+        values.sort();
+        assert_eq!(values, vec![Rational::new(-1, 3), Rational::new(1, 3), Rational::new(1, 2)]);
+
+        // 中间结果超出 i64 但约分后可以表示
+        let big = Rational::new(i64::MAX, 2);
+        assert_eq!(big * Rational::new(2, i64::MAX), Rational::one());
+    }
+
+    #[test]
+    #[should_panic(expected = "Division by zero.")]
+    fn test_rational_division_by_zero_panics() {
+        let _ = Rational::one() / Rational::zero();
+    }
+
+    #[test]
+    #[should_panic(expected = "Rational overflow")]
+    fn test_rational_abs_of_min_panics() {
+        let _ = Rational::from_integer(i64::MIN).abs();
+    }
+}
@@ -0,0 +1,73 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::matrix::error::MatrixError;
+    use rs_math::matrix::lu::LuDecomposition;
+    use rs_math::matrix::matrix_2d::Matrix2D;
+    use rs_math::number::scalar::{RealField, Scalar};
+
+    fn rms<T: RealField>(values: &[T]) -> T {
+        let sum = values.iter().fold(T::zero(), |sum, &x| sum + x * x);
+        (sum / T::from_f64(values.len() as f64)).sqrt()
+    }
+
+    #[test]
+    fn test_real_field_is_generic_over_precision() {
+        assert_eq!(rms(&[3.0f32, 4.0, 0.0, 0.0]), 2.5f32);
+        assert_eq!(rms(&[3.0f64, 4.0, 0.0, 0.0]), 2.5f64);
+        assert_eq!(<f32 as RealField>::epsilon(), f32::EPSILON);
+        assert_eq!(<f64 as RealField>::pi(), std::f64::consts::PI);
+        assert_eq!(f32::from_f64(0.1).to_f64(), 0.1f32 as f64);
+        assert!(!RealField::is_finite(f32::from_f64(1e300)));
+    }
+
+    #[test]
+    fn test_f32_matrix_arithmetic() {
+        let a = Matrix2D::new(vec![vec![1.0f32, 2.0], vec![3.0, 4.0]]).unwrap();
+        let b = Matrix2D::<f32>::identity(2) * 2.0;
+        assert_eq!((&a * &b).to_nested(), vec![vec![2.0f32, 4.0], vec![6.0, 8.0]]);
+        assert_eq!((&a + &b - &a), b);
+        assert_eq!(2.0f32 * &a, &a + &a);
+        assert_eq!(-&a * 1.0, a.map(|x| -x));
+        assert_eq!(a.trace().unwrap(), 5.0f32);
+        assert_eq!(&a * &[1.0f32, 1.0][..], vec![3.0f32, 7.0]);
+        assert_eq!(a.norm_1(), 6.0f32);
+        assert_eq!(a.norm_inf(), 7.0f32);
+        assert!((a.norm_frobenius() - 30.0f32.sqrt()).abs() <= f32::EPSILON * 8.0);
+    }
+
+    #[test]
+    fn test_f32_matrix_structure() {
+        let a = Matrix2D::from_fn(2, 2, |i, j| (i * 2 + j) as f32);
+        let d = Matrix2D::block_diagonal(&[a.clone(), Matrix2D::filled(1, 1, 9.0f32)]);
+        assert_eq!(d.shape(), (3, 3));
+        assert_eq!(d[(2, 2)], 9.0f32);
+        assert!(!d.is_diagonal() && d.is_symmetric(2.0f32) && !d.is_symmetric(0.5f32));
+        assert_eq!(Matrix2D::concat_horizontal(&[a.clone(), a.clone()]).unwrap().shape(), (2, 4));
+        assert!(Matrix2D::<f32>::identity(3).is_orthogonal(0.0));
+        assert_eq!(a.kronecker(&Matrix2D::identity(2)).shape(), (4, 4));
+    }
+
+    #[test]
+    fn test_f32_blocked_multiply_matches_naive() {
+        let a = Matrix2D::from_fn(70, 90, |i, j| ((i + 2 * j) % 7) as f32);
+        let b = Matrix2D::from_fn(90, 80, |i, j| ((3 * i + j) % 5) as f32 - 2.0);
+        let naive = a.multiply_naive(&b).unwrap();
+        assert_eq!(a.multiply_blocked(&b).unwrap(), naive);
+        assert_eq!(a.multiply(&b).unwrap(), naive);
+    }
+
+    #[test]
+    fn test_f32_lu_determinant_and_inverse() {
+        let a = Matrix2D::new(vec![vec![2.0f32, 1.0, 1.0], vec![4.0, -6.0, 0.0], vec![-2.0, 7.0, 2.0]]).unwrap();
+        let lu = a.lu().unwrap();
+        let x = lu.solve(&[5.0f32, -2.0, 9.0]).unwrap();
+        assert!(x.iter().zip([1.0f32, 1.0, 2.0]).all(|(x, e)| (x - e).abs() < 1e-5));
+        assert!((a.determinant().unwrap() + 16.0f32).abs() < 1e-4);
+
+        let product = &a * &a.inverse().unwrap();
+        assert!((product - Matrix2D::identity(3)).norm_inf() < 1e-5);
+
+        let singular = Matrix2D::new(vec![vec![1.0f32, 2.0], vec![2.0, 4.0]]).unwrap();
+        assert_eq!(singular.inverse(), Err(MatrixError::Singular { pivot: 1 }));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod vector_2d_test;
+pub mod vector_3d_test;
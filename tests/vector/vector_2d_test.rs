@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::{FRAC_PI_2, PI};
+
+    use rs_math::graphical::point_2d::Point2D;
+    use rs_math::vector::error::VectorError;
+    use rs_math::vector::vector_2d::Vector2D;
+
+    fn assert_close(actual: Vector2D, expected: Vector2D) {
+        assert!((actual - expected).magnitude() < 1e-12, "{:?} != {:?}", actual, expected);
+    }
+
+    #[test]
+    fn test_vector_2d_defaults_to_f64() {
+        let v = Vector2D::new(3.0, 4.0);
+        let magnitude: f64 = v.magnitude();
+        assert_eq!(magnitude, 5.0);
+        assert_eq!(Vector2D { x: 1.0, y: 2.0 }.dot_product(Vector2D::new(3.0, 4.0)), 11.0);
+    }
+
+    #[test]
+    fn test_vector_2d_f32() {
+        let a: Vector2D<f32> = Vector2D::new(1.0, 2.0);
+        let b = Vector2D::new(3.0f32, -1.0);
+        assert_eq!(Vector2D::new(1.0f32, 2.0).cross_product(Vector2D::new(3.0, -1.0)), -7.0f32);
+        let sum = a.add(b);
+        assert_eq!((sum.x, sum.y), (4.0f32, 1.0f32));
+        let difference = sum.subtract(Vector2D::new(4.0, 1.0));
+        assert_eq!(difference.magnitude(), 0.0f32);
+    }
+
+    #[test]
+    fn test_vector_2d_operators() {
+        let a = Vector2D::new(1.0, 2.0);
+        let b = Vector2D::new(3.0, -1.0);
+        assert_eq!(a + b, Vector2D::new(4.0, 1.0));
+        assert_eq!(a - b, Vector2D::new(-2.0, 3.0));
+        assert_eq!(a * 2.0, Vector2D::new(2.0, 4.0));
+        assert_eq!(2.0 * a, a * 2.0);
+        assert_eq!(b / 2.0, Vector2D::new(1.5, -0.5));
+        assert_eq!(-a, Vector2D::new(-1.0, -2.0));
+        assert_eq!(a.add(b), a + b);
+        assert_eq!(Vector2D::<f64>::default(), Vector2D::new(0.0, 0.0));
+        let copy = a;
+        assert_eq!(copy.clone(), a);
+        assert_eq!(0.5f32 * Vector2D::new(2.0f32, 4.0), Vector2D::new(1.0f32, 2.0));
+    }
+
+    #[test]
+    fn test_vector_2d_normalize_and_projections() {
+        let v = Vector2D::new(3.0, 4.0);
+        assert_close(v.normalize().unwrap(), Vector2D::new(0.6, 0.8));
+        assert_eq!(Vector2D::new(0.0, 0.0).normalize(), Err(VectorError::ZeroLength));
+        assert_eq!(VectorError::ZeroLength.to_string(), "vector has zero length");
+        assert_close(Vector2D::new(1e-200, 0.0).normalize().unwrap(), Vector2D::new(1.0, 0.0));
+        let half_sqrt2 = std::f64::consts::FRAC_1_SQRT_2;
+        assert_close(Vector2D::new(1e200, 1e200).normalize().unwrap(), Vector2D::new(half_sqrt2, half_sqrt2));
+
+        assert_eq!(v.perpendicular(), Vector2D::new(-4.0, 3.0));
+        assert_eq!(v.dot_product(v.perpendicular()), 0.0);
+
+        let direction = Vector2D::new(1.0, 1.0);
+        let projection = v.project_onto(direction).unwrap();
+        let rejection = v.reject_from(direction).unwrap();
+        assert_close(projection, Vector2D::new(3.5, 3.5));
+        assert_close(projection + rejection, v);
+        assert!(rejection.dot_product(direction).abs() < 1e-12);
+        assert!(v.project_onto(Vector2D::new(0.0, 0.0)).is_err());
+        assert!(v.reject_from(Vector2D::new(0.0, 0.0)).is_err());
+
+        let reflected = v.reflect(Vector2D::new(-2.0, 0.0)).unwrap();
+        assert_close(reflected, Vector2D::new(-3.0, 4.0));
+        assert!((reflected.magnitude() - v.magnitude()).abs() < 1e-12);
+        assert_eq!(v.reflect(Vector2D::new(0.0, 0.0)), Err(VectorError::ZeroLength));
+    }
+
+    #[test]
+    fn test_vector_2d_angles_rotation_and_lerp() {
+        let x = Vector2D::new(2.0, 0.0);
+        assert!((x.angle_to(Vector2D::new(0.0, 1.0)) - FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_to(Vector2D::new(0.0, -3.0)) + FRAC_PI_2).abs() < 1e-12);
+        assert!((x.angle_to(Vector2D::new(-1.0, 0.0)) - PI).abs() < 1e-12);
+        assert_eq!(x.angle_to(Vector2D::new(0.0, 0.0)), 0.0);
+
+        let v: Vector2D = Vector2D::new(1.0, 2.0);
+        for angle in [0.3, -1.2, 2.9] {
+            let rotated = v.rotate(angle);
+            assert!((rotated.magnitude() - v.magnitude()).abs() < 1e-12);
+            assert!((v.angle_to(rotated) - angle).abs() < 1e-12);
+        }
+        assert_close(v.rotate(FRAC_PI_2), v.perpendicular());
+
+        let w = Vector2D::new(5.0, -2.0);
+        assert_eq!(v.lerp(w, 0.0), v);
+        assert_eq!(v.lerp(w, 1.0), w);
+        assert_eq!(v.lerp(w, 0.5), Vector2D::new(3.0, 0.0));
+        assert_eq!(v.lerp(w, 2.0), Vector2D::new(9.0, -6.0));
+    }
+
+    #[test]
+    fn test_vector_2d_point_conversions() {
+        let point = Point2D { x: 1.5, y: -2.0 };
+        let vector = Vector2D::from(point.clone());
+        assert_eq!(vector, Vector2D::new(1.5, -2.0));
+        let moved: Point2D = (vector + Vector2D::new(0.5, 2.0)).into();
+        assert_eq!(moved, Point2D { x: 2.0, y: 0.0 });
+        assert_eq!(Point2D::from(Vector2D::from(point.clone())), point);
+    }
+}
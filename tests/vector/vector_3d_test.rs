@@ -0,0 +1,23 @@
+#[cfg(test)]
+mod tests {
+    use rs_math::vector::vector_3d::Vector3D;
+
+    #[test]
+    fn test_vector_3d_defaults_to_f64() {
+        let a = Vector3D { x: 1.0, y: 0.0, z: 0.0 };
+        let b = Vector3D { x: 0.0, y: 1.0, z: 0.0 };
+        let c = a.cross_product(&b);
+        let z: f64 = c.z;
+        assert_eq!((c.x, c.y, z), (0.0, 0.0, 1.0));
+        assert_eq!(a.add(&b).dot_product(&c), 0.0);
+    }
+
+    #[test]
+    fn test_vector_3d_f32() {
+        let v: Vector3D<f32> = Vector3D { x: 2.0, y: 3.0, z: 6.0 };
+        assert_eq!(v.magnitude(), 7.0f32);
+        let w = v.scalar_multiply(0.5).subtract(&Vector3D { x: 1.0, y: 1.5, z: 3.0 });
+        assert_eq!(w.magnitude(), 0.0f32);
+        assert_eq!(v.dot_product(&v), 49.0f32);
+    }
+}